@@ -0,0 +1,83 @@
+//! This module contains lazy enumeration of combinations.
+
+use alloc::vec::Vec;
+
+/// Iterator over the k-item combinations of a slice.
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+
+        let current = self.indices.iter().map(|&index| self.items[index].clone()).collect();
+
+        // The rightmost index that can still move right is advanced, the following ones are packed after it.
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rposition(|i| self.indices[i] < n - k + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(current)
+    }
+}
+
+/// Enumerates the ways to choose k items keeping their order in the slice.
+///
+/// Note: combinations follow the lexicographic order of the chosen positions, so a sorted
+/// slice gives lexicographically sorted combinations. Items are not compared, repeated
+/// items give repeated combinations.
+///
+/// Arguments:
+///
+/// * `items`: The items to choose from.
+/// * `k`: The number of items in every combination, nothing is produced when it exceeds the length.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations { items, indices: (0..k).collect(), done: k > items.len() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combinations_should_be_listed_in_order() {
+        let listed: Vec<Vec<char>> = combinations(&['a', 'b', 'c', 'd'], 2).collect();
+
+        assert_eq!(
+            listed,
+            vec![vec!['a', 'b'], vec!['a', 'c'], vec!['a', 'd'], vec!['b', 'c'], vec!['b', 'd'], vec!['c', 'd']]
+        );
+    }
+
+    #[test]
+    fn combination_counts_should_be_binomial_coefficients() {
+        let items: Vec<u32> = (0..10).collect();
+        let binomial = |k: usize| (0..k).fold(1, |acc, i| acc * (10 - i) / (i + 1));
+
+        for k in 0..=10 {
+            assert_eq!(combinations(&items, k).count(), binomial(k));
+        }
+    }
+
+    #[test]
+    fn edge_sizes_should_be_supported() {
+        assert_eq!(combinations(&[1, 2, 3], 0).collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+        assert_eq!(combinations(&[1, 2, 3], 3).collect::<Vec<_>>(), vec![vec![1, 2, 3]]);
+        assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+    }
+}
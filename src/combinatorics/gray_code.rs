@@ -0,0 +1,95 @@
+//! This module contains the binary reflected Gray code.
+
+/// Returns the Gray code of a number, which differs from the code of its successor in one bit.
+pub fn to_gray(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
+
+/// Returns the number whose Gray code is the given one.
+pub fn from_gray(code: u64) -> u64 {
+    let mut value = code;
+    let mut shift = 1;
+    while shift < u64::BITS {
+        value ^= value >> shift;
+        shift *= 2;
+    }
+
+    value
+}
+
+/// Iterator over the Gray codes of a given width.
+#[derive(Debug, Clone)]
+pub struct GrayCode {
+    next: u64,
+    end: u64,
+}
+
+impl GrayCode {
+    /// Returns the bit that the next code flips, `None` before the first code or after the last one.
+    pub fn next_flip(&self) -> Option<u32> {
+        (self.next > 0 && self.next < self.end).then(|| self.next.trailing_zeros())
+    }
+}
+
+impl Iterator for GrayCode {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.next == self.end {
+            return None;
+        }
+
+        self.next += 1;
+        Some(to_gray(self.next - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.end - self.next).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+/// Enumerates all 2^bits codes of the given width, consecutive codes differ in exactly one bit.
+///
+/// Note: the enumeration starts at 0, and the bit flipped between the i-th and the next
+/// code is the number of trailing zeros of i + 1.
+///
+/// Panics when the width is 64 or more.
+pub fn gray_code(bits: u32) -> GrayCode {
+    assert!(bits < u64::BITS, "Gray codes are limited to 63 bits");
+
+    GrayCode { next: 0, end: 1 << bits }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_should_be_listed_in_reflected_order() {
+        assert_eq!(gray_code(3).collect::<Vec<_>>(), vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+        assert_eq!(gray_code(0).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn consecutive_codes_should_differ_in_one_bit() {
+        let mut codes = gray_code(10);
+        let mut previous = codes.next().unwrap();
+
+        while let Some(expected_flip) = codes.next_flip() {
+            let code = codes.next().unwrap();
+            assert_eq!(previous ^ code, 1 << expected_flip);
+            previous = code;
+        }
+
+        assert_eq!(codes.next(), None);
+        assert_eq!(previous, 1 << 9);
+    }
+
+    #[test]
+    fn gray_code_should_be_inverted() {
+        for value in (0..1000).chain([u64::MAX, u64::MAX / 3, 1 << 63]) {
+            assert_eq!(from_gray(to_gray(value)), value);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod permutations;
+pub mod combinations;
+pub mod gray_code;
+pub mod subsets;
+
+pub use permutations::{heap_permutations, next_permutation, permutations, prev_permutation, HeapPermutations, Permutations};
+pub use combinations::{combinations, Combinations};
+pub use gray_code::{from_gray, gray_code, to_gray, GrayCode};
+pub use subsets::{masks_with_ones, power_set, submasks, MasksWithOnes, PowerSet, Submasks};
@@ -0,0 +1,182 @@
+//! This module contains lazy enumeration of permutations.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Rearranges the items into the next permutation in lexicographic order.
+///
+/// Note: repeated items are handled, each distinct arrangement is produced once.
+///
+/// Returns:
+///
+/// False when the items were in the last, non-increasing order, which is then
+/// turned into the first, sorted order.
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    // The pivot is the last item smaller than its successor, everything after it is non-increasing.
+    let Some(pivot) = items.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        items.reverse();
+        return false;
+    };
+
+    let successor = items.iter().rposition(|item| *item > items[pivot]).unwrap();
+    items.swap(pivot, successor);
+    items[pivot + 1..].reverse();
+    true
+}
+
+/// Rearranges the items into the previous permutation in lexicographic order.
+///
+/// Returns:
+///
+/// False when the items were in the first, sorted order, which is then turned into
+/// the last, non-increasing order.
+pub fn prev_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let Some(pivot) = items.windows(2).rposition(|pair| pair[0] > pair[1]) else {
+        items.reverse();
+        return false;
+    };
+
+    let predecessor = items.iter().rposition(|item| *item < items[pivot]).unwrap();
+    items.swap(pivot, predecessor);
+    items[pivot + 1..].reverse();
+    true
+}
+
+/// Iterator over the distinct permutations of a slice in lexicographic order.
+#[derive(Debug, Clone)]
+pub struct Permutations<T> {
+    next: Option<Vec<T>>,
+}
+
+impl<T: Ord + Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let current = self.next.take()?;
+
+        let mut following = current.clone();
+        if next_permutation(&mut following) {
+            self.next = Some(following);
+        }
+
+        Some(current)
+    }
+}
+
+/// Enumerates the distinct permutations of the items in lexicographic order.
+///
+/// Note: enumeration starts from the sorted order whatever the order of the items, and
+/// every step takes amortized O(1) swaps besides cloning the permutation.
+pub fn permutations<T: Ord + Clone>(items: &[T]) -> Permutations<T> {
+    let mut first = items.to_vec();
+    first.sort();
+
+    Permutations { next: Some(first) }
+}
+
+/// Iterator over all permutations of a slice with Heap's algorithm.
+#[derive(Debug, Clone)]
+pub struct HeapPermutations<T> {
+    items: Vec<T>,
+    counters: Vec<usize>,
+    level: usize,
+    started: bool,
+}
+
+impl<T: Clone> Iterator for HeapPermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+
+        while self.level < self.items.len() {
+            let level = self.level;
+            if self.counters[level] < level {
+                let other = if level.is_multiple_of(2) { 0 } else { self.counters[level] };
+                self.items.swap(other, level);
+                self.counters[level] += 1;
+                self.level = 1;
+                return Some(self.items.clone());
+            }
+
+            self.counters[level] = 0;
+            self.level += 1;
+        }
+
+        None
+    }
+}
+
+/// Enumerates all n! permutations of the items, each obtained from the previous one by a single swap.
+///
+/// Note: the items need no ordering, and repeated items give repeated permutations. The
+/// first permutation is the items as given.
+pub fn heap_permutations<T: Clone>(items: &[T]) -> HeapPermutations<T> {
+    HeapPermutations { items: items.to_vec(), counters: vec![0; items.len()], level: 1, started: false }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn next_permutation_should_advance_lexicographically() {
+        let mut items = [1, 3, 2];
+
+        assert!(next_permutation(&mut items));
+        assert_eq!(items, [2, 1, 3]);
+        assert!(prev_permutation(&mut items));
+        assert_eq!(items, [1, 3, 2]);
+    }
+
+    #[test]
+    fn last_permutation_should_wrap_around() {
+        let mut items = [3, 2, 1];
+
+        assert!(!next_permutation(&mut items));
+        assert_eq!(items, [1, 2, 3]);
+        assert!(!prev_permutation(&mut items));
+        assert_eq!(items, [3, 2, 1]);
+    }
+
+    #[test]
+    fn permutations_should_be_listed_in_order() {
+        let listed: Vec<Vec<char>> = permutations(&['c', 'a', 'b']).collect();
+
+        assert_eq!(listed.len(), 6);
+        assert_eq!(listed[0], vec!['a', 'b', 'c']);
+        assert_eq!(listed[5], vec!['c', 'b', 'a']);
+        assert!(listed.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn repeated_items_should_give_distinct_permutations() {
+        let listed: Vec<Vec<u8>> = permutations(&[1, 1, 2, 2]).collect();
+
+        assert_eq!(listed.len(), 6);
+        assert_eq!(listed.iter().collect::<HashSet<_>>().len(), 6);
+    }
+
+    #[test]
+    fn heap_permutations_should_differ_by_one_swap() {
+        let listed: Vec<Vec<usize>> = heap_permutations(&[0, 1, 2, 3, 4]).collect();
+
+        assert_eq!(listed.len(), 120);
+        assert_eq!(listed.iter().collect::<HashSet<_>>().len(), 120);
+        assert_eq!(listed[0], vec![0, 1, 2, 3, 4]);
+        for pair in listed.windows(2) {
+            let differences = (0..5).filter(|&i| pair[0][i] != pair[1][i]).count();
+            assert_eq!(differences, 2);
+        }
+    }
+
+    #[test]
+    fn empty_slice_should_have_one_permutation() {
+        assert_eq!(permutations::<u8>(&[]).count(), 1);
+        assert_eq!(heap_permutations::<u8>(&[]).count(), 1);
+    }
+}
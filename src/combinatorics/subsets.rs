@@ -0,0 +1,139 @@
+//! This module contains enumeration of subsets, as item lists and as bitmasks.
+
+use alloc::vec::Vec;
+
+/// Iterator over the submasks of a bitmask.
+#[derive(Debug, Clone)]
+pub struct Submasks {
+    mask: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for Submasks {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.next?;
+
+        // Subtracting one clears the lowest set bit and sets all bits below it, the mask keeps those allowed.
+        self.next = (current != 0).then(|| (current - 1) & self.mask);
+        Some(current)
+    }
+}
+
+/// Enumerates every submask of the mask in decreasing order, from the mask itself down to 0.
+///
+/// Note: this is the `sub = (sub - 1) & mask` idiom, iterating the submasks of every mask
+/// of n bits takes O(3^n) in total.
+pub fn submasks(mask: u64) -> Submasks {
+    Submasks { mask, next: Some(mask) }
+}
+
+/// Iterator over the n-bit masks with exactly k bits set.
+#[derive(Debug, Clone)]
+pub struct MasksWithOnes {
+    next: Option<u64>,
+    end: u64,
+}
+
+impl Iterator for MasksWithOnes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.next?;
+        if current == 0 {
+            self.next = None;
+            return Some(0);
+        }
+
+        // Gosper's hack: the lowest block of ones moves its top bit up, the rest go to the bottom.
+        let lowest = current & current.wrapping_neg();
+        let carried = current + lowest;
+        let following = (((current ^ carried) >> 2) / lowest) | carried;
+        self.next = (following < self.end).then_some(following);
+        Some(current)
+    }
+}
+
+/// Enumerates the masks of the given width with exactly k bits set, in increasing order.
+///
+/// Panics when the width is 64 or more.
+pub fn masks_with_ones(bits: u32, k: u32) -> MasksWithOnes {
+    assert!(bits < u64::BITS, "Masks are limited to 63 bits");
+
+    let first = (k <= bits).then(|| (1u64 << k) - 1);
+    MasksWithOnes { next: first, end: 1 << bits }
+}
+
+/// Iterator over all subsets of a slice.
+#[derive(Debug, Clone)]
+pub struct PowerSet<'a, T> {
+    items: &'a [T],
+    next: u64,
+    end: u64,
+}
+
+impl<T: Clone> Iterator for PowerSet<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.next == self.end {
+            return None;
+        }
+
+        let mask = self.next;
+        self.next += 1;
+        Some(self.items.iter().enumerate().filter(|&(i, _)| mask >> i & 1 == 1).map(|(_, item)| item.clone()).collect())
+    }
+}
+
+/// Enumerates all 2^n subsets of the items, keeping their order in the slice.
+///
+/// Note: the i-th subset holds the items at the set bits of i, so subsets come in the order
+/// of their bitmasks, starting with the empty one.
+///
+/// Panics when there are 64 items or more.
+pub fn power_set<T: Clone>(items: &[T]) -> PowerSet<'_, T> {
+    assert!(items.len() < u64::BITS as usize, "Power sets are limited to 63 items");
+
+    PowerSet { items, next: 0, end: 1 << items.len() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submasks_should_be_listed_in_decreasing_order() {
+        assert_eq!(submasks(0b1011).collect::<Vec<_>>(), vec![0b1011, 0b1010, 0b1001, 0b1000, 0b0011, 0b0010, 0b0001, 0]);
+        assert_eq!(submasks(0).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn submasks_of_all_masks_should_total_three_to_the_n() {
+        let total: usize = (0..1u64 << 8).map(|mask| submasks(mask).count()).sum();
+
+        assert_eq!(total, 3usize.pow(8));
+    }
+
+    #[test]
+    fn masks_with_ones_should_match_filtered_range() {
+        for bits in 0..8 {
+            for k in 0..=bits + 1 {
+                let expected: Vec<u64> = (0..1u64 << bits).filter(|mask| mask.count_ones() == k).collect();
+
+                assert_eq!(masks_with_ones(bits, k).collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn power_set_should_follow_bitmask_order() {
+        let subsets: Vec<Vec<char>> = power_set(&['a', 'b', 'c']).collect();
+
+        assert_eq!(
+            subsets,
+            vec![vec![], vec!['a'], vec!['b'], vec!['a', 'b'], vec!['c'], vec!['a', 'c'], vec!['b', 'c'], vec!['a', 'b', 'c']]
+        );
+    }
+}
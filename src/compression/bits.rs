@@ -0,0 +1,114 @@
+//! This module contains bit-level writers and readers used by the compression algorithms.
+//!
+//! Bits are packed most significant first.
+
+use alloc::vec::Vec;
+
+use crate::compression::DecodeError;
+
+/// Accumulates bits into a vector of bytes.
+#[derive(Debug, Clone, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    pending: u8,
+    pending_count: u8,
+}
+
+impl BitWriter {
+    /// Creates an empty writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single bit.
+    pub fn write_bit(&mut self, bit: bool) {
+        self.pending = (self.pending << 1) | u8::from(bit);
+        self.pending_count += 1;
+
+        if self.pending_count == 8 {
+            self.bytes.push(self.pending);
+            self.pending = 0;
+            self.pending_count = 0;
+        }
+    }
+
+    /// Appends the lowest `count` bits of the value, the most significant one first.
+    pub fn write_bits(&mut self, value: u128, count: u8) {
+        for shift in (0..count).rev() {
+            self.write_bit((value >> shift) & 1 == 1);
+        }
+    }
+
+    /// Returns the written bytes, padding the last one with zero bits.
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.pending_count > 0 {
+            self.bytes.push(self.pending << (8 - self.pending_count));
+        }
+
+        self.bytes
+    }
+}
+
+/// Reads bits from a slice of bytes.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a reader positioned at the first bit of the slice.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, position: 0 }
+    }
+
+    /// Returns the number of bits left to read.
+    pub fn remaining_bits(&self) -> usize {
+        (self.bytes.len() * 8).saturating_sub(self.position)
+    }
+
+    /// Reads a single bit.
+    pub fn read_bit(&mut self) -> Result<bool, DecodeError> {
+        let byte = self.bytes.get(self.position / 8).ok_or(DecodeError::UnexpectedEnd)?;
+        let bit = (byte >> (7 - self.position % 8)) & 1 == 1;
+        self.position += 1;
+
+        Ok(bit)
+    }
+
+    /// Reads `count` bits, the most significant one first.
+    pub fn read_bits(&mut self, count: u8) -> Result<u128, DecodeError> {
+        (0..count).try_fold(0u128, |value, _| Ok((value << 1) | u128::from(self.read_bit()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn written_bits_should_be_read_back() {
+        let mut writer = BitWriter::new();
+        writer.write_bit(true);
+        writer.write_bits(0b0110, 4);
+        writer.write_bits(0x1ff, 9);
+
+        let bytes = writer.finish();
+        let mut reader = BitReader::new(&bytes);
+
+        assert_eq!(bytes.len(), 2);
+        assert!(reader.read_bit().unwrap());
+        assert_eq!(reader.read_bits(4).unwrap(), 0b0110);
+        assert_eq!(reader.read_bits(9).unwrap(), 0x1ff);
+    }
+
+    #[test]
+    fn reading_past_the_end_should_fail() {
+        let mut reader = BitReader::new(&[0xff]);
+
+        assert_eq!(reader.read_bits(3).unwrap(), 0b111);
+        assert_eq!(reader.remaining_bits(), 5);
+        assert_eq!(reader.read_bits(5).unwrap(), 0b11111);
+        assert_eq!(reader.read_bit(), Err(DecodeError::UnexpectedEnd));
+    }
+}
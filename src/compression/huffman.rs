@@ -0,0 +1,322 @@
+//! This module contains canonical Huffman coding of bytes.
+//!
+//! The encoded format is the code table (one code length per byte value), followed by
+//! the number of encoded bytes as a little-endian u64, followed by the packed codes.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::compression::bits::{BitReader, BitWriter};
+use crate::compression::DecodeError;
+
+/// Number of distinct symbols.
+const ALPHABET_SIZE: usize = 256;
+
+/// Longest code length that fits into the code representation.
+pub const MAX_CODE_LENGTH: u8 = 127;
+
+/// Size of the serialized code table in bytes.
+pub const TABLE_SIZE: usize = ALPHABET_SIZE;
+
+/// Canonical Huffman code for byte values.
+///
+/// Note:
+///     A canonical code is fully determined by its code lengths: codes of the same length
+///     are consecutive integers assigned in increasing symbol order. This is what makes
+///     the serialized table just one length per symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HuffmanCode {
+    lengths: [u8; ALPHABET_SIZE],
+    codes: [u128; ALPHABET_SIZE],
+}
+
+impl HuffmanCode {
+    /// Builds an optimal code for the given frequency of every byte value.
+    ///
+    /// Note: symbols with zero frequency get no code. A lone symbol gets a one-bit code.
+    pub fn from_frequencies(frequencies: &[u64; ALPHABET_SIZE]) -> Self {
+        let mut lengths = [0u8; ALPHABET_SIZE];
+
+        // Leaves occupy the first nodes, every merge appends an internal node.
+        let mut parents: Vec<usize> = vec![usize::MAX; ALPHABET_SIZE];
+        let mut heap: BinaryHeap<Reverse<(u128, usize)>> = frequencies
+            .iter()
+            .enumerate()
+            .filter(|&(_, &frequency)| frequency > 0)
+            .map(|(symbol, &frequency)| Reverse((frequency as u128, symbol)))
+            .collect();
+
+        if heap.len() == 1 {
+            let Reverse((_, symbol)) = heap.pop().unwrap();
+            lengths[symbol] = 1;
+        }
+
+        while heap.len() > 1 {
+            let Reverse((first_weight, first)) = heap.pop().unwrap();
+            let Reverse((second_weight, second)) = heap.pop().unwrap();
+
+            let merged = parents.len();
+            parents.push(usize::MAX);
+            parents[first] = merged;
+            parents[second] = merged;
+            heap.push(Reverse((first_weight + second_weight, merged)));
+        }
+
+        for symbol in 0..ALPHABET_SIZE {
+            if frequencies[symbol] == 0 || lengths[symbol] > 0 {
+                continue;
+            }
+
+            let mut node = symbol;
+            while parents[node] != usize::MAX {
+                node = parents[node];
+                lengths[symbol] += 1;
+            }
+        }
+
+        Self::from_lengths(lengths).expect("Huffman tree produced an invalid code")
+    }
+
+    /// Builds an optimal code for the given data.
+    pub fn from_data(data: &[u8]) -> Self {
+        let mut frequencies = [0u64; ALPHABET_SIZE];
+        for &byte in data {
+            frequencies[byte as usize] += 1;
+        }
+
+        Self::from_frequencies(&frequencies)
+    }
+
+    /// Builds a canonical code from code lengths, zero meaning no code.
+    ///
+    /// Returns:
+    ///
+    /// An error when the lengths do not describe a prefix code.
+    pub fn from_lengths(lengths: [u8; ALPHABET_SIZE]) -> Result<Self, DecodeError> {
+        if lengths.iter().any(|&length| length > MAX_CODE_LENGTH) {
+            return Err(DecodeError::InvalidData);
+        }
+
+        let mut codes = [0u128; ALPHABET_SIZE];
+        let mut next_code = 0u128;
+        for length in 1..=MAX_CODE_LENGTH {
+            for symbol in 0..ALPHABET_SIZE {
+                if lengths[symbol] == length {
+                    if next_code >> length != 0 {
+                        return Err(DecodeError::InvalidData);
+                    }
+
+                    codes[symbol] = next_code;
+                    next_code += 1;
+                }
+            }
+
+            next_code <<= 1;
+        }
+
+        Ok(HuffmanCode { lengths, codes })
+    }
+
+    /// Returns the code of the byte and its length in bits, if the byte has one.
+    pub fn code(&self, byte: u8) -> Option<(u128, u8)> {
+        let length = self.lengths[byte as usize];
+        (length > 0).then(|| (self.codes[byte as usize], length))
+    }
+
+    /// Serializes the code table.
+    pub fn serialize(&self) -> Vec<u8> {
+        self.lengths.to_vec()
+    }
+
+    /// Restores a code table written by `serialize`.
+    pub fn deserialize(table: &[u8]) -> Result<Self, DecodeError> {
+        let lengths: [u8; ALPHABET_SIZE] = table.get(..TABLE_SIZE).ok_or(DecodeError::UnexpectedEnd)?.try_into().unwrap();
+
+        Self::from_lengths(lengths)
+    }
+
+    /// Writes the codes of every byte of the data.
+    ///
+    /// Panics when some byte has no code.
+    pub fn encode_into(&self, data: &[u8], writer: &mut BitWriter) {
+        for &byte in data {
+            let (code, length) = self.code(byte).unwrap_or_else(|| panic!("Byte {} has no Huffman code", byte));
+            writer.write_bits(code, length);
+        }
+    }
+
+    /// Reads `count` bytes encoded with this code.
+    pub fn decode_from(&self, reader: &mut BitReader, count: usize) -> Result<Vec<u8>, DecodeError> {
+        // Symbols ordered by code, and for every length the first code and the index of its symbol.
+        let mut symbols: Vec<u8> = (0..=u8::MAX).filter(|&byte| self.lengths[byte as usize] > 0).collect();
+        symbols.sort_by_key(|&byte| (self.lengths[byte as usize], byte));
+
+        let mut counts = [0usize; MAX_CODE_LENGTH as usize + 1];
+        for &byte in &symbols {
+            counts[self.lengths[byte as usize] as usize] += 1;
+        }
+
+        // Every code takes at least one bit, so a count from untrusted input cannot reserve more than that.
+        let mut decoded = Vec::with_capacity(count.min(reader.remaining_bits()));
+        while decoded.len() < count {
+            let mut code = 0u128;
+            let mut first_code = 0u128;
+            let mut first_index = 0usize;
+            let mut length = 0;
+
+            loop {
+                length += 1;
+                if length > MAX_CODE_LENGTH as usize {
+                    return Err(DecodeError::InvalidData);
+                }
+
+                code = (code << 1) | u128::from(reader.read_bit()?);
+                if code - first_code < counts[length] as u128 {
+                    decoded.push(symbols[first_index + (code - first_code) as usize]);
+                    break;
+                }
+
+                first_index += counts[length];
+                first_code = (first_code + counts[length] as u128) << 1;
+            }
+        }
+
+        Ok(decoded)
+    }
+}
+
+/// Compresses bytes with a Huffman code built for them.
+pub fn encode(data: &[u8]) -> Vec<u8> {
+    let code = HuffmanCode::from_data(data);
+
+    let mut writer = BitWriter::new();
+    code.encode_into(data, &mut writer);
+
+    let mut encoded = code.serialize();
+    encoded.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    encoded.extend(writer.finish());
+    encoded
+}
+
+/// Decompresses bytes produced by `encode`.
+pub fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let code = HuffmanCode::deserialize(encoded)?;
+
+    let count_bytes = encoded.get(TABLE_SIZE..TABLE_SIZE + 8).ok_or(DecodeError::UnexpectedEnd)?;
+    let count = u64::from_le_bytes(count_bytes.try_into().unwrap()) as usize;
+
+    code.decode_from(&mut BitReader::new(&encoded[TABLE_SIZE + 8..]), count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn frequent_bytes_should_get_shorter_codes() {
+        let code = HuffmanCode::from_data("aaaaaaaabbbbccd".as_bytes());
+
+        let length = |byte: u8| code.code(byte).unwrap().1;
+
+        assert_eq!(length(b'a'), 1);
+        assert_eq!(length(b'b'), 2);
+        assert_eq!(length(b'c'), 3);
+        assert_eq!(length(b'd'), 3);
+        assert_eq!(code.code(b'e'), None);
+    }
+
+    #[test]
+    fn codes_should_be_canonical() {
+        let code = HuffmanCode::from_data("aaaaaaaabbbbccd".as_bytes());
+
+        assert_eq!(code.code(b'a'), Some((0b0, 1)));
+        assert_eq!(code.code(b'b'), Some((0b10, 2)));
+        assert_eq!(code.code(b'c'), Some((0b110, 3)));
+        assert_eq!(code.code(b'd'), Some((0b111, 3)));
+    }
+
+    #[test]
+    fn serialized_table_should_be_restored() {
+        let code = HuffmanCode::from_data("hello huffman".as_bytes());
+
+        assert_eq!(HuffmanCode::deserialize(&code.serialize()), Ok(code));
+    }
+
+    #[test]
+    fn oversubscribed_table_should_be_rejected() {
+        let mut lengths = [0u8; ALPHABET_SIZE];
+        lengths[..3].copy_from_slice(&[1, 1, 1]);
+
+        assert_eq!(HuffmanCode::from_lengths(lengths), Err(DecodeError::InvalidData));
+    }
+
+    #[test]
+    fn text_should_be_compressed_and_restored() {
+        let text = "it was the best of times, it was the worst of times".repeat(10);
+
+        let encoded = encode(text.as_bytes());
+
+        assert!(encoded.len() < text.len());
+        assert_eq!(decode(&encoded).unwrap(), text.as_bytes());
+    }
+
+    #[test]
+    fn single_repeated_byte_should_be_restored() {
+        let data = vec![42u8; 100];
+
+        assert_eq!(decode(&encode(&data)).unwrap(), data);
+        assert_eq!(decode(&encode(&[])).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn truncated_data_should_fail_to_decode() {
+        let encoded = encode("some text to truncate".as_bytes());
+
+        assert_eq!(decode(&encoded[..encoded.len() - 2]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&encoded[..100]), Err(DecodeError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn huge_byte_count_should_fail_to_decode() {
+        let mut encoded = encode("abc".as_bytes());
+        encoded[TABLE_SIZE..TABLE_SIZE + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        assert_eq!(decode(&encoded), Err(DecodeError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn random_data_should_round_trip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let alphabet_size = rng.gen_range(1..=255u8);
+            let data: Vec<u8> = (0..rng.gen_range(0..500)).map(|_| rng.gen_range(0..=alphabet_size)).collect();
+
+            assert_eq!(decode(&encode(&data)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn skewed_frequencies_should_round_trip() {
+        // Fibonacci frequencies produce the deepest possible tree.
+        let mut frequencies = [0u64; ALPHABET_SIZE];
+        let (mut a, mut b) = (1u64, 1u64);
+        for frequency in frequencies.iter_mut().take(40) {
+            *frequency = a;
+            (a, b) = (b, a + b);
+        }
+
+        let code = HuffmanCode::from_frequencies(&frequencies);
+        let data: Vec<u8> = (0..40).collect();
+
+        let mut writer = BitWriter::new();
+        code.encode_into(&data, &mut writer);
+        let bytes = writer.finish();
+
+        assert_eq!(code.code(0).unwrap().1, 39);
+        assert_eq!(code.decode_from(&mut BitReader::new(&bytes), data.len()).unwrap(), data);
+    }
+}
@@ -0,0 +1,178 @@
+//! This module contains LZ77 compression with a sliding window.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::compression::DecodeError;
+
+/// Window size used by `encode`.
+pub const DEFAULT_WINDOW_SIZE: usize = 4096;
+
+/// Longest match emitted by `encode`.
+pub const DEFAULT_MAX_MATCH_LENGTH: usize = 258;
+
+/// Shortest match the hash chains can find, shorter repeats are emitted as literals.
+const MIN_MATCH_LENGTH: usize = 3;
+
+const HASH_SIZE: usize = 1 << 16;
+
+/// A single LZ77 triple.
+///
+/// Decoding copies `length` bytes starting `offset` bytes back, then appends `literal`.
+/// The copied region may overlap the bytes being produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub length: usize,
+    pub literal: u8,
+}
+
+/// Compresses bytes with the default window size and match length.
+pub fn encode(data: &[u8]) -> Vec<Token> {
+    encode_with(data, DEFAULT_WINDOW_SIZE, DEFAULT_MAX_MATCH_LENGTH)
+}
+
+/// Compresses bytes into LZ77 triples.
+///
+/// Note:
+///     Candidate matches are found through hash chains over three-byte prefixes, so only
+///     positions sharing a prefix with the current one are compared.
+///
+/// Arguments:
+///
+/// * `data`: The bytes to compress.
+/// * `window_size`: How far back matches may start.
+/// * `max_match_length`: The longest match of a single triple.
+pub fn encode_with(data: &[u8], window_size: usize, max_match_length: usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+
+    // head[h] is the latest position with hash h, previous[i] the position before i with the same hash.
+    let mut head = vec![usize::MAX; HASH_SIZE];
+    let mut previous = vec![usize::MAX; data.len()];
+
+    let mut position = 0;
+    while position < data.len() {
+        // The last byte is always left for the literal.
+        let longest_possible = max_match_length.min(data.len() - position - 1);
+
+        let mut best = (0, 0);
+        if longest_possible >= MIN_MATCH_LENGTH {
+            let mut candidate = head[prefix_hash(&data[position..])];
+            while candidate != usize::MAX && position - candidate <= window_size {
+                let length = (0..longest_possible).take_while(|&i| data[candidate + i] == data[position + i]).count();
+                if length > best.1 {
+                    best = (position - candidate, length);
+                }
+
+                if length == longest_possible {
+                    break;
+                }
+
+                candidate = previous[candidate];
+            }
+        }
+
+        let (offset, length) = if best.1 >= MIN_MATCH_LENGTH { best } else { (0, 0) };
+        tokens.push(Token { offset, length, literal: data[position + length] });
+
+        for covered in (position..=position + length).filter(|&covered| covered + MIN_MATCH_LENGTH <= data.len()) {
+            let hash = prefix_hash(&data[covered..]);
+            previous[covered] = head[hash];
+            head[hash] = covered;
+        }
+
+        position += length + 1;
+    }
+
+    tokens
+}
+
+fn prefix_hash(bytes: &[u8]) -> usize {
+    let value = (bytes[0] as usize) << 16 | (bytes[1] as usize) << 8 | bytes[2] as usize;
+    (value.wrapping_mul(2654435761) >> 8) % HASH_SIZE
+}
+
+/// Restores bytes from LZ77 triples.
+///
+/// Returns:
+///
+/// An error when some triple refers to bytes before the beginning of the data.
+pub fn decode(tokens: &[Token]) -> Result<Vec<u8>, DecodeError> {
+    let mut data = Vec::new();
+
+    for token in tokens {
+        if token.length > 0 && (token.offset == 0 || token.offset > data.len()) {
+            return Err(DecodeError::InvalidData);
+        }
+
+        let start = data.len() - if token.length > 0 { token.offset } else { 0 };
+        for i in 0..token.length {
+            data.push(data[start + i]);
+        }
+
+        data.push(token.literal);
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn repeated_text_should_be_encoded_with_back_references() {
+        let tokens = encode("abcabcabcabcx".as_bytes());
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token { offset: 0, length: 0, literal: b'a' },
+                Token { offset: 0, length: 0, literal: b'b' },
+                Token { offset: 0, length: 0, literal: b'c' },
+                Token { offset: 3, length: 9, literal: b'x' },
+            ]
+        );
+    }
+
+    #[test]
+    fn text_should_round_trip() {
+        let text = "to be or not to be, that is the question; ".repeat(50);
+
+        let tokens = encode(text.as_bytes());
+
+        assert!(tokens.len() < text.len() / 10);
+        assert_eq!(decode(&tokens).unwrap(), text.as_bytes());
+    }
+
+    #[test]
+    fn binary_data_should_round_trip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let mut data: Vec<u8> = (0..rng.gen_range(0..2000)).map(|_| rng.gen_range(0..4)).collect();
+            data.extend((0..rng.gen_range(0..500)).map(|_| rng.gen::<u8>()));
+
+            assert_eq!(decode(&encode_with(&data, 64, 16)).unwrap(), data);
+            assert_eq!(decode(&encode(&data)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn matches_should_stay_within_the_window() {
+        let data: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+
+        let tokens = encode_with(&data, 100, 32);
+
+        assert!(tokens.iter().all(|token| token.offset <= 100 && token.length <= 32));
+        assert_eq!(decode(&tokens).unwrap(), data);
+    }
+
+    #[test]
+    fn reference_before_the_beginning_should_fail_to_decode() {
+        let tokens = [Token { offset: 2, length: 1, literal: b'a' }];
+
+        assert_eq!(decode(&tokens), Err(DecodeError::InvalidData));
+    }
+}
@@ -0,0 +1,181 @@
+//! This module contains LZW compression with a resettable dictionary.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::compression::DecodeError;
+
+/// Code that tells the decoder to reset the dictionary.
+pub const CLEAR_CODE: u32 = 256;
+
+/// First code assigned to a dictionary entry.
+const FIRST_ENTRY_CODE: u32 = 257;
+
+/// Dictionary size used by `encode` and `decode`, the same as 12-bit codes.
+pub const DEFAULT_DICTIONARY_SIZE: u32 = 4096;
+
+/// Compresses bytes with the default dictionary size.
+pub fn encode(data: &[u8]) -> Vec<u32> {
+    encode_with(data, DEFAULT_DICTIONARY_SIZE)
+}
+
+/// Compresses bytes into LZW codes.
+///
+/// Note:
+///     Codes below 256 stand for single bytes. Once the dictionary holds
+///     `dictionary_size` codes, `CLEAR_CODE` is emitted and the dictionary starts over,
+///     so the encoder keeps adapting to the data.
+///
+/// Panics when the dictionary size leaves no room for entries.
+pub fn encode_with(data: &[u8], dictionary_size: u32) -> Vec<u32> {
+    assert!(dictionary_size > FIRST_ENTRY_CODE, "Dictionary size {} leaves no room for entries", dictionary_size);
+
+    let mut codes = Vec::new();
+    let mut dictionary: BTreeMap<(u32, u8), u32> = BTreeMap::new();
+    let mut next_code = FIRST_ENTRY_CODE;
+
+    let mut bytes = data.iter();
+    let mut current = match bytes.next() {
+        Some(&byte) => byte as u32,
+        None => return codes,
+    };
+
+    for &byte in bytes {
+        if let Some(&code) = dictionary.get(&(current, byte)) {
+            current = code;
+            continue;
+        }
+
+        codes.push(current);
+        if next_code < dictionary_size {
+            dictionary.insert((current, byte), next_code);
+            next_code += 1;
+        } else {
+            codes.push(CLEAR_CODE);
+            dictionary.clear();
+            next_code = FIRST_ENTRY_CODE;
+        }
+
+        current = byte as u32;
+    }
+
+    codes.push(current);
+    codes
+}
+
+/// Restores bytes from codes produced by `encode`.
+pub fn decode(codes: &[u32]) -> Result<Vec<u8>, DecodeError> {
+    decode_with(codes, DEFAULT_DICTIONARY_SIZE)
+}
+
+/// Restores bytes from codes produced by `encode_with` with the same dictionary size.
+pub fn decode_with(codes: &[u32], dictionary_size: u32) -> Result<Vec<u8>, DecodeError> {
+    let mut data = Vec::new();
+
+    // Entries are indexed by their code minus the first entry code.
+    let mut entries: Vec<Vec<u8>> = Vec::new();
+    let mut previous: Option<Vec<u8>> = None;
+
+    for &code in codes {
+        if code == CLEAR_CODE {
+            entries.clear();
+            previous = None;
+            continue;
+        }
+
+        let next_code = FIRST_ENTRY_CODE + entries.len() as u32;
+        let entry = if code < CLEAR_CODE {
+            vec![code as u8]
+        } else if code < next_code {
+            entries[(code - FIRST_ENTRY_CODE) as usize].clone()
+        } else if code == next_code {
+            // The encoder used the entry it had just added: previous plus its own first byte.
+            let mut entry = previous.clone().ok_or(DecodeError::InvalidData)?;
+            entry.push(entry[0]);
+            entry
+        } else {
+            return Err(DecodeError::InvalidData);
+        };
+
+        if let Some(mut new_entry) = previous.take() {
+            if next_code < dictionary_size {
+                new_entry.push(entry[0]);
+                entries.push(new_entry);
+            }
+        }
+
+        data.extend_from_slice(&entry);
+        previous = Some(entry);
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn tobeornot_should_be_encoded_correctly() {
+        let codes = encode("TOBEORNOTTOBEORTOBEORNOT".as_bytes());
+
+        let expected: Vec<u32> = vec![
+            'T' as u32, 'O' as u32, 'B' as u32, 'E' as u32, 'O' as u32, 'R' as u32, 'N' as u32, 'O' as u32, 'T' as u32,
+            257, 259, 261, 266, 260, 262, 264,
+        ];
+        assert_eq!(codes, expected);
+    }
+
+    #[test]
+    fn repeated_pattern_should_use_the_entry_being_defined() {
+        let data = "aaaaaaa".as_bytes();
+
+        let codes = encode(data);
+
+        assert_eq!(codes, vec![97, 257, 258, 97]);
+        assert_eq!(decode(&codes).unwrap(), data);
+    }
+
+    #[test]
+    fn text_should_round_trip() {
+        let text = "she sells sea shells by the sea shore. ".repeat(100);
+
+        let codes = encode(text.as_bytes());
+
+        assert!(codes.len() < text.len() / 4);
+        assert_eq!(decode(&codes).unwrap(), text.as_bytes());
+    }
+
+    #[test]
+    fn small_dictionary_should_reset_and_round_trip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let data: Vec<u8> = (0..rng.gen_range(0..3000)).map(|_| rng.gen_range(0..8)).collect();
+
+            let codes = encode_with(&data, 300);
+
+            assert!(codes.iter().all(|&code| code < 300));
+            assert_eq!(decode_with(&codes, 300).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn binary_data_should_round_trip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let data: Vec<u8> = (0..rng.gen_range(0..3000)).map(|_| rng.gen()).collect();
+
+            assert_eq!(decode(&encode(&data)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn unknown_code_should_fail_to_decode() {
+        assert_eq!(decode(&[97, 300]), Err(DecodeError::InvalidData));
+        assert_eq!(decode(&[257]), Err(DecodeError::InvalidData));
+    }
+}
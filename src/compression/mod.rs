@@ -0,0 +1,27 @@
+pub mod bits;
+pub mod huffman;
+pub mod lz77;
+pub mod lzw;
+pub mod rle;
+
+use core::fmt;
+
+/// Error produced when decoding malformed compressed data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data ended before decoding was complete.
+    UnexpectedEnd,
+    /// The data contains a value that the encoder never produces.
+    InvalidData,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "compressed data ended unexpectedly"),
+            DecodeError::InvalidData => write!(f, "compressed data is malformed"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
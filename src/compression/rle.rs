@@ -0,0 +1,114 @@
+//! This module contains run-length encoding of bytes with an escape byte.
+//!
+//! Bytes are copied as they are, except for runs, which are written as the escape byte,
+//! the run length (1 to 255) and the repeated byte. Runs longer than 255 are split.
+//! The escape byte itself is always written as a run, so it never appears as a literal.
+
+use alloc::vec::Vec;
+
+use crate::compression::DecodeError;
+
+/// Byte that introduces a run.
+pub const ESCAPE: u8 = 0xf5;
+
+/// Shortest run of an ordinary byte worth encoding, a run costs three bytes.
+const MIN_RUN_LENGTH: usize = 4;
+
+/// Longest run a single escape sequence describes.
+const MAX_RUN_LENGTH: usize = u8::MAX as usize;
+
+/// Compresses bytes with run-length encoding.
+pub fn encode(data: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(data.len());
+
+    let mut position = 0;
+    while position < data.len() {
+        let byte = data[position];
+        let run_length = data[position..].iter().take(MAX_RUN_LENGTH).take_while(|&&other| other == byte).count();
+
+        if run_length >= MIN_RUN_LENGTH || byte == ESCAPE {
+            encoded.extend_from_slice(&[ESCAPE, run_length as u8, byte]);
+        } else {
+            encoded.extend(core::iter::repeat_n(byte, run_length));
+        }
+
+        position += run_length;
+    }
+
+    encoded
+}
+
+/// Restores bytes produced by `encode`.
+pub fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut data = Vec::with_capacity(encoded.len());
+
+    let mut bytes = encoded.iter();
+    while let Some(&byte) = bytes.next() {
+        if byte != ESCAPE {
+            data.push(byte);
+            continue;
+        }
+
+        let run_length = *bytes.next().ok_or(DecodeError::UnexpectedEnd)? as usize;
+        let repeated = *bytes.next().ok_or(DecodeError::UnexpectedEnd)?;
+        if run_length == 0 {
+            return Err(DecodeError::InvalidData);
+        }
+
+        data.extend(core::iter::repeat_n(repeated, run_length));
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn runs_should_be_encoded_and_literals_copied() {
+        let encoded = encode(&[1, 2, 2, 3, 3, 3, 3, 3, 4]);
+
+        assert_eq!(encoded, vec![1, 2, 2, ESCAPE, 5, 3, 4]);
+    }
+
+    #[test]
+    fn escape_byte_should_always_be_encoded_as_run() {
+        let encoded = encode(&[ESCAPE, 7]);
+
+        assert_eq!(encoded, vec![ESCAPE, 1, ESCAPE, 7]);
+        assert_eq!(decode(&encoded).unwrap(), vec![ESCAPE, 7]);
+    }
+
+    #[test]
+    fn long_runs_should_be_split() {
+        let data = vec![9u8; 600];
+
+        let encoded = encode(&data);
+
+        assert_eq!(encoded, vec![ESCAPE, 255, 9, ESCAPE, 255, 9, ESCAPE, 90, 9]);
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn malformed_data_should_fail_to_decode() {
+        assert_eq!(decode(&[1, ESCAPE, 3]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&[ESCAPE, 0, 3]), Err(DecodeError::InvalidData));
+    }
+
+    #[test]
+    fn random_data_should_round_trip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let mut data = Vec::new();
+            for _ in 0..rng.gen_range(0..50) {
+                let byte = if rng.gen_bool(0.2) { ESCAPE } else { rng.gen() };
+                data.extend(core::iter::repeat_n(byte, rng.gen_range(1..400)));
+            }
+
+            assert_eq!(decode(&encode(&data)).unwrap(), data);
+        }
+    }
+}
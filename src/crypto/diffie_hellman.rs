@@ -0,0 +1,145 @@
+//! This module contains the Diffie–Hellman key exchange modulo a safe prime of at most 64 bits.
+
+use rand::Rng;
+
+use crate::numbers::operations::mod_exp::ModExp;
+use crate::numbers::primes::{is_prime, random_prime};
+use crate::numbers::primitive_root::primitive_root;
+
+/// Public group both parties agree on before the exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameters {
+    /// A safe prime p, whose (p - 1) / 2 is prime as well.
+    pub prime: u64,
+    /// A primitive root modulo the prime, generating every nonzero residue.
+    pub generator: u64,
+}
+
+/// Generates a safe prime with exactly the given number of bits and its smallest primitive root.
+///
+/// Note: with p = 2q + 1 for a prime q, the group has no small subgroups besides {1, p - 1},
+/// which rules out the cheapest attacks on the exchange. Safe primes are rarer than primes,
+/// so about bits^2 candidates are tried on average.
+///
+/// Panics when the number of bits is not within 3..=64.
+pub fn generate_parameters(bits: u32, rng: &mut impl Rng) -> Parameters {
+    assert!((3..=64).contains(&bits), "Prime size must be within 3..=64 bits");
+
+    loop {
+        let half = random_prime(bits - 1, rng);
+        let prime = 2 * half + 1;
+        if is_prime(prime) {
+            return Parameters { prime, generator: primitive_root(prime).unwrap() };
+        }
+    }
+}
+
+impl Parameters {
+    /// Draws a private key, a secret exponent in 2..=p - 2 other than (p - 1) / 2.
+    ///
+    /// Note: g^((p - 1) / 2) = p - 1 for a primitive root g, which lies in the small subgroup
+    /// rejected by `shared_secret`, so that exponent is skipped.
+    pub fn private_key(&self, rng: &mut impl Rng) -> u64 {
+        let half = (self.prime - 1) / 2;
+        let key = rng.gen_range(2..=self.prime - 3);
+        if key >= half {
+            key + 1
+        } else {
+            key
+        }
+    }
+
+    /// Returns the public key g^a mod p sent to the other party.
+    pub fn public_key(&self, private_key: u64) -> u64 {
+        ModExp::mod_exp(self.generator as u128, private_key as u128, self.prime as u128) as u64
+    }
+
+    /// Derives the shared secret (g^b)^a = g^(ab) mod p from the other party's public key.
+    ///
+    /// Panics when the public key is not within 2..=p - 2. Keys from `private_key` always yield
+    /// public keys in that range, since only the exponents 0 and (p - 1) / 2 map outside it.
+    pub fn shared_secret(&self, private_key: u64, other_public_key: u64) -> u64 {
+        assert!((2..=self.prime - 2).contains(&other_public_key), "Public key must be within 2..=p - 2");
+        ModExp::mod_exp(other_public_key as u128, private_key as u128, self.prime as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn textbook_exchange_should_be_reproduced() {
+        let parameters = Parameters { prime: 23, generator: 5 };
+        let (alice, bob) = (6, 15);
+
+        let (alice_public, bob_public) = (parameters.public_key(alice), parameters.public_key(bob));
+
+        assert_eq!((alice_public, bob_public), (8, 19));
+        assert_eq!(parameters.shared_secret(alice, bob_public), 2);
+        assert_eq!(parameters.shared_secret(bob, alice_public), 2);
+    }
+
+    #[test]
+    fn generated_parameters_should_be_a_safe_prime_and_a_generator() {
+        let mut rng = StdRng::seed_from_u64(5);
+
+        for bits in [3, 16, 40, 64] {
+            let parameters = generate_parameters(bits, &mut rng);
+
+            assert_eq!(64 - parameters.prime.leading_zeros(), bits);
+            assert!(is_prime(parameters.prime) && is_prime((parameters.prime - 1) / 2));
+            assert_eq!(primitive_root(parameters.prime), Some(parameters.generator));
+        }
+    }
+
+    #[test]
+    fn both_parties_should_derive_the_same_secret() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let parameters = generate_parameters(62, &mut rng);
+
+        for _ in 0..20 {
+            let (alice, bob) = (parameters.private_key(&mut rng), parameters.private_key(&mut rng));
+
+            let alice_secret = parameters.shared_secret(alice, parameters.public_key(bob));
+            let bob_secret = parameters.shared_secret(bob, parameters.public_key(alice));
+
+            assert_eq!(alice_secret, bob_secret);
+        }
+    }
+
+    #[test]
+    fn private_keys_should_never_map_to_p_minus_one() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let parameters = Parameters { prime: 23, generator: 5 };
+        assert_eq!(parameters.public_key(11), 22);
+
+        for _ in 0..1000 {
+            let (alice, bob) = (parameters.private_key(&mut rng), parameters.private_key(&mut rng));
+
+            assert_ne!(alice, 11);
+            assert_eq!(
+                parameters.shared_secret(alice, parameters.public_key(bob)),
+                parameters.shared_secret(bob, parameters.public_key(alice))
+            );
+        }
+    }
+
+    #[test]
+    fn smallest_safe_prime_should_have_a_single_private_key() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let parameters = Parameters { prime: 5, generator: 2 };
+
+        assert!((0..100).all(|_| parameters.private_key(&mut rng) == 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn degenerate_public_key_should_panic() {
+        let parameters = Parameters { prime: 23, generator: 5 };
+
+        parameters.shared_secret(6, 1);
+    }
+}
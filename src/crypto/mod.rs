@@ -0,0 +1,6 @@
+//! This module contains textbook public-key schemes built on the number theory of
+//! `numbers`. They are meant for study only: the keys are tiny and nothing resists
+//! real attacks, so vetted cryptography libraries must be used to protect data.
+
+pub mod rsa;
+pub mod diffie_hellman;
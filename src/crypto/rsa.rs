@@ -0,0 +1,190 @@
+//! This module contains textbook RSA with moduli of at most 64 bits.
+
+use rand::Rng;
+
+use crate::numbers::operations::mod_exp::ModExp;
+use crate::numbers::operations::mod_inverse::mod_inverse;
+use crate::numbers::primes::random_prime;
+
+/// The usual public exponent, prime and with only two bits set for fast encryption.
+pub const PUBLIC_EXPONENT: u64 = 65537;
+
+/// Key anyone may use to encrypt messages for the owner of the private key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    pub modulus: u64,
+    pub exponent: u64,
+}
+
+/// Key decrypting messages encrypted with the matching public key.
+///
+/// Note: besides the private exponent it keeps the primes and the values that make
+/// decryption by the Chinese remainder theorem possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivateKey {
+    pub modulus: u64,
+    pub exponent: u64,
+    pub primes: (u64, u64),
+    /// The exponent reduced modulo p - 1 and modulo q - 1.
+    pub reduced_exponents: (u64, u64),
+    /// The inverse of q modulo p.
+    pub q_inverse: u64,
+}
+
+/// Matching public and private keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyPair {
+    pub public: PublicKey,
+    pub private: PrivateKey,
+}
+
+// Raises the base to the exponent modulo the modulus, with room for the products.
+fn power(base: u64, exponent: u64, modulus: u64) -> u64 {
+    ModExp::mod_exp(base as u128, exponent as u128, modulus as u128) as u64
+}
+
+impl KeyPair {
+    /// Derives the keys from two distinct primes and a public exponent.
+    ///
+    /// Returns:
+    ///
+    /// The keys, `None` when the exponent has no inverse modulo (p - 1)(q - 1).
+    ///
+    /// Panics when the primes are equal or their product does not fit into 64 bits.
+    pub fn from_primes(p: u64, q: u64, public_exponent: u64) -> Option<Self> {
+        assert_ne!(p, q, "Primes must be distinct");
+        let modulus = p.checked_mul(q).expect("Modulus must fit into 64 bits");
+
+        let totient = (p - 1) as i128 * (q - 1) as i128;
+        let exponent = mod_inverse(public_exponent as i128, totient)? as u64;
+        let q_inverse = mod_inverse(q as i128, p as i128)? as u64;
+
+        Some(KeyPair {
+            public: PublicKey { modulus, exponent: public_exponent },
+            private: PrivateKey { modulus, exponent, primes: (p, q), reduced_exponents: (exponent % (p - 1), exponent % (q - 1)), q_inverse },
+        })
+    }
+}
+
+/// Generates keys with a modulus of exactly the given number of bits.
+///
+/// Note: two random primes of half the size are drawn until their product has the
+/// requested size and `PUBLIC_EXPONENT` is invertible modulo (p - 1)(q - 1).
+///
+/// Panics when the number of bits is not within 32..=64.
+pub fn generate_keys(bits: u32, rng: &mut impl Rng) -> KeyPair {
+    assert!((32..=64).contains(&bits), "Modulus size must be within 32..=64 bits");
+
+    loop {
+        let (p, q) = (random_prime(bits - bits / 2, rng), random_prime(bits / 2, rng));
+        if p == q || (p as u128 * q as u128) >> (bits - 1) != 1 {
+            continue;
+        }
+
+        if let Some(keys) = KeyPair::from_primes(p, q, PUBLIC_EXPONENT) {
+            return keys;
+        }
+    }
+}
+
+impl PublicKey {
+    /// Encrypts a message m as m^e mod n.
+    ///
+    /// Note: textbook RSA is deterministic and multiplicative, so equal messages give equal
+    /// ciphertexts and products of ciphertexts decrypt to products of messages.
+    ///
+    /// Panics when the message is not smaller than the modulus.
+    pub fn encrypt(&self, message: u64) -> u64 {
+        assert!(message < self.modulus, "Message must be smaller than the modulus");
+        power(message, self.exponent, self.modulus)
+    }
+}
+
+impl PrivateKey {
+    /// Decrypts a ciphertext c as c^d mod n.
+    ///
+    /// Panics when the ciphertext is not smaller than the modulus.
+    pub fn decrypt(&self, ciphertext: u64) -> u64 {
+        assert!(ciphertext < self.modulus, "Ciphertext must be smaller than the modulus");
+        power(ciphertext, self.exponent, self.modulus)
+    }
+
+    /// Decrypts a ciphertext by the Chinese remainder theorem, giving the same result as `decrypt`.
+    ///
+    /// Note: Garner's formula joins the powers modulo p and modulo q, whose exponents and
+    /// moduli are half as long, which makes decryption about four times faster.
+    ///
+    /// Panics when the ciphertext is not smaller than the modulus.
+    pub fn decrypt_crt(&self, ciphertext: u64) -> u64 {
+        assert!(ciphertext < self.modulus, "Ciphertext must be smaller than the modulus");
+
+        let (p, q) = self.primes;
+        let modulo_p = power(ciphertext % p, self.reduced_exponents.0, p);
+        let modulo_q = power(ciphertext % q, self.reduced_exponents.1, q);
+
+        let difference = (modulo_p + p - modulo_q % p) % p;
+        let h = (self.q_inverse as u128 * difference as u128 % p as u128) as u64;
+        modulo_q + h * q
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn textbook_example_should_be_reproduced() {
+        let keys = KeyPair::from_primes(61, 53, 17).unwrap();
+
+        let ciphertext = keys.public.encrypt(65);
+
+        assert_eq!(keys.public.modulus, 3233);
+        assert_eq!(keys.private.exponent, 2753);
+        assert_eq!(ciphertext, 2790);
+        assert_eq!(keys.private.decrypt(ciphertext), 65);
+        assert_eq!(keys.private.decrypt_crt(ciphertext), 65);
+    }
+
+    #[test]
+    fn exponent_sharing_a_factor_with_totient_should_be_rejected() {
+        assert_eq!(KeyPair::from_primes(7, 11, 5), None);
+    }
+
+    #[test]
+    fn generated_keys_should_round_trip_messages() {
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        for bits in [32, 48, 63, 64] {
+            let keys = generate_keys(bits, &mut rng);
+            assert_eq!(64 - keys.public.modulus.leading_zeros(), bits);
+
+            for _ in 0..20 {
+                let message = rng.gen_range(0..keys.public.modulus);
+                let ciphertext = keys.public.encrypt(message);
+
+                assert_eq!(keys.private.decrypt(ciphertext), message);
+                assert_eq!(keys.private.decrypt_crt(ciphertext), message);
+            }
+        }
+    }
+
+    #[test]
+    fn ciphertexts_should_multiply_like_messages() {
+        let keys = generate_keys(40, &mut StdRng::seed_from_u64(1));
+        let n = keys.public.modulus as u128;
+
+        let product = (keys.public.encrypt(1234) as u128 * keys.public.encrypt(5678) as u128 % n) as u64;
+
+        assert_eq!(keys.private.decrypt(product), 1234 * 5678);
+    }
+
+    #[test]
+    #[should_panic]
+    fn message_not_below_modulus_should_panic() {
+        let keys = KeyPair::from_primes(61, 53, 17).unwrap();
+
+        keys.public.encrypt(3233);
+    }
+}
@@ -0,0 +1,148 @@
+//! This module contains the coin change problems: fewest coins and number of ways.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Pays the amount with the fewest coins, every denomination available in unlimited supply.
+///
+/// Note: unlike the greedy choice of the largest coin, this works for any set of
+/// denominations. Runs in O(amount * denominations).
+///
+/// Returns:
+///
+/// The coins used, largest first, or `None` when the amount cannot be paid.
+///
+/// Panics when some denomination is zero.
+pub fn min_coins(coins: &[usize], amount: usize) -> Option<Vec<usize>> {
+    assert!(coins.iter().all(|&coin| coin > 0), "Coins must have positive values");
+
+    // fewest[a] is the fewest coins paying a, and last[a] the coin paid last to reach it.
+    let mut fewest = vec![usize::MAX; amount + 1];
+    let mut last = vec![0; amount + 1];
+    fewest[0] = 0;
+
+    for value in 1..=amount {
+        for &coin in coins {
+            if coin <= value && fewest[value - coin] != usize::MAX && fewest[value - coin] + 1 < fewest[value] {
+                fewest[value] = fewest[value - coin] + 1;
+                last[value] = coin;
+            }
+        }
+    }
+
+    if fewest[amount] == usize::MAX {
+        return None;
+    }
+
+    let mut used = Vec::with_capacity(fewest[amount]);
+    let mut value = amount;
+    while value > 0 {
+        used.push(last[value]);
+        value -= last[value];
+    }
+
+    used.sort_unstable_by(|a, b| b.cmp(a));
+    Some(used)
+}
+
+/// Counts the ways to pay the amount, where ways differing only in coin order are the same.
+///
+/// Note: denominations are taken one at a time so every multiset of coins is counted once.
+/// Repeated denominations count as different coins. Runs in O(amount * denominations).
+///
+/// Panics when some denomination is zero or the count overflows `u128`, use `count_ways_mod`
+/// for large counts.
+pub fn count_ways(coins: &[usize], amount: usize) -> u128 {
+    ways(coins, amount, |a, b| a.checked_add(b).expect("Number of ways overflows u128"))
+}
+
+/// Counts the ways to pay the amount modulo the given modulus.
+///
+/// Panics when some denomination or the modulus is zero.
+pub fn count_ways_mod(coins: &[usize], amount: usize, modulus: u64) -> u64 {
+    assert!(modulus > 0, "Modulus must be positive");
+
+    let modulus = u128::from(modulus);
+    (ways(coins, amount, |a, b| (a + b) % modulus) % modulus) as u64
+}
+
+fn ways(coins: &[usize], amount: usize, add: impl Fn(u128, u128) -> u128) -> u128 {
+    assert!(coins.iter().all(|&coin| coin > 0), "Coins must have positive values");
+
+    let mut ways = vec![0u128; amount + 1];
+    ways[0] = 1;
+    for &coin in coins {
+        for value in coin..=amount {
+            ways[value] = add(ways[value], ways[value - coin]);
+        }
+    }
+
+    ways[amount]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn fewest_coins_should_beat_greedy_choice() {
+        assert_eq!(min_coins(&[1, 3, 4], 6), Some(vec![3, 3]));
+        assert_eq!(min_coins(&[25, 10, 5, 1], 63), Some(vec![25, 25, 10, 1, 1, 1]));
+    }
+
+    #[test]
+    fn unpayable_amount_should_give_none() {
+        assert_eq!(min_coins(&[4, 6], 7), None);
+        assert_eq!(min_coins(&[], 0), Some(Vec::new()));
+    }
+
+    #[test]
+    fn ways_should_be_counted() {
+        assert_eq!(count_ways(&[1, 2, 5], 5), 4);
+        assert_eq!(count_ways(&[2], 3), 0);
+        assert_eq!(count_ways(&[3], 0), 1);
+    }
+
+    #[test]
+    fn ways_should_be_counted_modulo() {
+        let coins: Vec<usize> = (1..=50).collect();
+
+        assert_eq!(count_ways_mod(&coins, 30, 1_000_000_007), count_ways(&coins, 30) as u64);
+        assert_eq!(count_ways_mod(&coins, 1000, 1_000_000_007), (count_ways(&coins, 1000) % 1_000_000_007) as u64);
+        assert_eq!(count_ways_mod(&coins, 20, 1), 0);
+        assert_eq!(count_ways_mod(&coins, 0, 1), 0);
+    }
+
+    #[test]
+    fn results_should_match_brute_force_on_random_coins() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let mut coins: Vec<usize> = (0..rng.gen_range(1..4)).map(|_| rng.gen_range(1..10)).collect();
+            coins.sort_unstable();
+            coins.dedup();
+            let amount = rng.gen_range(0..40);
+
+            // Every way is a number of copies of each coin, the last coin fills the rest.
+            fn search(coins: &[usize], amount: usize, used: usize, best: &mut Option<usize>) -> u128 {
+                match coins {
+                    [] => {
+                        if amount == 0 && best.is_none_or(|best| used < best) {
+                            *best = Some(used);
+                        }
+                        u128::from(amount == 0)
+                    }
+                    [coin, rest @ ..] => (0..=amount / coin).map(|copies| search(rest, amount - copies * coin, used + copies, best)).sum(),
+                }
+            }
+            let mut best = None;
+            let brute_force_ways = search(&coins, amount, 0, &mut best);
+
+            let paid = min_coins(&coins, amount);
+            assert_eq!(paid.as_ref().map(Vec::len), best);
+            assert!(paid.is_none_or(|paid| paid.iter().sum::<usize>() == amount));
+            assert_eq!(count_ways(&coins, amount), brute_force_ways);
+        }
+    }
+}
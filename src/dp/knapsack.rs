@@ -0,0 +1,357 @@
+//! This module contains knapsack solvers and subset sum feasibility.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use num::{PrimInt, Unsigned, Zero};
+
+use crate::structures::BitSet;
+
+/// Item that can be put into a knapsack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item<W, V> {
+    /// The weight counted against the capacity.
+    pub weight: W,
+    /// The value gained by packing the item.
+    pub value: V,
+}
+
+impl<W, V> Item<W, V> {
+    /// Creates an item with the given weight and value.
+    pub fn new(weight: W, value: V) -> Self {
+        Item { weight, value }
+    }
+}
+
+/// Best packing of a knapsack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Knapsack<W, V> {
+    /// The total value of the packed items.
+    pub value: V,
+    /// The total weight of the packed items.
+    pub weight: W,
+    /// The indices of the packed items in increasing order, an item packed several times is repeated.
+    pub items: Vec<usize>,
+}
+
+// Table positions are weights, so they must fit in memory.
+fn capacity_index<W: PrimInt>(capacity: W) -> usize {
+    capacity.to_usize().expect("Capacity must fit in the address space")
+}
+
+// An item heavier than any representable index cannot fit anyway.
+fn weight_index<W: PrimInt>(weight: W, capacity: usize) -> Option<usize> {
+    weight.to_usize().filter(|&weight| weight <= capacity)
+}
+
+fn packing<W, V>(items: &[Item<W, V>], value: V, mut indices: Vec<usize>) -> Knapsack<W, V>
+where
+    W: PrimInt + Unsigned,
+    V: Copy,
+{
+    indices.sort_unstable();
+    let weight = indices.iter().fold(W::zero(), |total, &index| total + items[index].weight);
+
+    Knapsack { value, weight, items: indices }
+}
+
+/// Packs the most valuable set of items, every item at most once, whose weight fits the capacity.
+///
+/// Note:
+///     The table of best values by capacity is filled item by item, with a flag for every
+///     item and capacity telling whether taking the item improved it. Runs in O(n * capacity)
+///     time and memory. Capacities can be of any unsigned type, but the table has one entry
+///     per unit of capacity.
+///
+/// Arguments:
+///
+/// * `items`: The weights and values of the items.
+/// * `capacity`: The largest total weight allowed.
+pub fn knapsack<W, V>(items: &[Item<W, V>], capacity: W) -> Knapsack<W, V>
+where
+    W: PrimInt + Unsigned,
+    V: Copy + Ord + Add<Output = V> + Zero,
+{
+    let capacity = capacity_index(capacity);
+    let mut best = vec![V::zero(); capacity + 1];
+    let mut taken = vec![Vec::new(); items.len()];
+
+    for (index, item) in items.iter().enumerate() {
+        let Some(weight) = weight_index(item.weight, capacity) else {
+            continue;
+        };
+
+        taken[index] = vec![false; capacity + 1];
+        for room in (weight..=capacity).rev() {
+            let candidate = best[room - weight] + item.value;
+            if candidate > best[room] {
+                best[room] = candidate;
+                taken[index][room] = true;
+            }
+        }
+    }
+
+    let mut room = capacity;
+    let mut chosen = Vec::new();
+    for index in (0..items.len()).rev() {
+        if taken[index].get(room) == Some(&true) {
+            chosen.push(index);
+            room -= items[index].weight.to_usize().unwrap();
+        }
+    }
+
+    packing(items, best[capacity], chosen)
+}
+
+/// Packs the most valuable multiset of items, every item any number of times, whose weight fits the capacity.
+///
+/// Note: runs in O(n * capacity) time and O(capacity) memory.
+///
+/// Panics when an item has zero weight.
+pub fn unbounded_knapsack<W, V>(items: &[Item<W, V>], capacity: W) -> Knapsack<W, V>
+where
+    W: PrimInt + Unsigned,
+    V: Copy + Ord + Add<Output = V> + Zero,
+{
+    assert!(items.iter().all(|item| !item.weight.is_zero()), "Items must have positive weights");
+
+    let capacity = capacity_index(capacity);
+    let weights: Vec<Option<usize>> = items.iter().map(|item| weight_index(item.weight, capacity)).collect();
+    let mut best = vec![V::zero(); capacity + 1];
+    // The item added last on the way to the best value of every capacity.
+    let mut last = vec![None; capacity + 1];
+
+    for room in 1..=capacity {
+        for (index, item) in items.iter().enumerate() {
+            if let Some(weight) = weights[index].filter(|&weight| weight <= room) {
+                let candidate = best[room - weight] + item.value;
+                if candidate > best[room] {
+                    best[room] = candidate;
+                    last[room] = Some(index);
+                }
+            }
+        }
+    }
+
+    let mut room = capacity;
+    let mut chosen = Vec::new();
+    while let Some(index) = last[room] {
+        chosen.push(index);
+        room -= weights[index].unwrap();
+    }
+
+    packing(items, best[capacity], chosen)
+}
+
+/// Packs the most valuable multiset of items, every item at most its count of times, whose weight fits the capacity.
+///
+/// Note:
+///     Every item is split into copies of 1, 2, 4, ... units and a remainder, which can add up
+///     to any count within the limit, and the copies are packed as a 0/1 knapsack. Runs in
+///     O(capacity * sum of log counts). Copies heavier than the capacity and items of negative
+///     value are left out, so the values of the copies never exceed the best value and cannot
+///     overflow when it fits into `V`.
+///
+/// Arguments:
+///
+/// * `items`: The weights and values of the items.
+/// * `counts`: The number of copies available of every item.
+/// * `capacity`: The largest total weight allowed.
+pub fn bounded_knapsack<W, V>(items: &[Item<W, V>], counts: &[usize], capacity: W) -> Knapsack<W, V>
+where
+    W: PrimInt + Unsigned,
+    V: Copy + Ord + Add<Output = V> + Zero,
+{
+    assert_eq!(items.len(), counts.len(), "Every item must have a count");
+
+    let mut bundles = Vec::new();
+    let mut owners = Vec::new();
+    for (index, (item, &count)) in items.iter().zip(counts).enumerate() {
+        if item.value < V::zero() {
+            continue;
+        }
+
+        let mut left = count;
+        let mut size: usize = 1;
+        while left > 0 {
+            let taken = size.min(left);
+            if let Some(bundle) = scaled(*item, taken, capacity) {
+                bundles.push(bundle);
+                owners.push((index, taken));
+            }
+            left -= taken;
+            size = size.saturating_mul(2);
+        }
+    }
+
+    let packed = knapsack(&bundles, capacity);
+    let chosen = packed.items.iter().flat_map(|&bundle| core::iter::repeat_n(owners[bundle].0, owners[bundle].1)).collect();
+
+    packing(items, packed.value, chosen)
+}
+
+// Returns the item standing for the given number of copies, none when they do not fit the capacity.
+fn scaled<W, V>(item: Item<W, V>, times: usize, capacity: W) -> Option<Item<W, V>>
+where
+    W: PrimInt + Unsigned,
+    V: Copy + Add<Output = V> + Zero,
+{
+    let weight = W::from(times).and_then(|times| item.weight.checked_mul(&times)).filter(|&weight| weight <= capacity)?;
+
+    // Doubling and adding only passes through values of fewer copies.
+    let mut value = V::zero();
+    for bit in (0..usize::BITS - times.leading_zeros()).rev() {
+        value = value + value;
+        if times >> bit & 1 == 1 {
+            value = value + item.value;
+        }
+    }
+
+    Some(Item::new(weight, value))
+}
+
+/// Finds every total weight up to the limit reachable by a subset of the weights.
+///
+/// Note:
+///     Reachable sums are kept as a bitset and every weight shifts it onto itself, handling
+///     64 sums per word operation. Runs in O(n * limit / 64).
+///
+/// Returns:
+///
+/// A flag for every sum from 0 to the limit telling whether some subset adds up to it.
+pub fn subset_sums<W: PrimInt + Unsigned>(weights: &[W], limit: W) -> Vec<bool> {
+    let limit = capacity_index(limit);
+    let mut reachable = BitSet::new(limit + 1);
+    reachable.set(0);
+
+    let mut shifted = BitSet::new(limit + 1);
+    for &weight in weights {
+        let Some(weight) = weight_index(weight, limit).filter(|&weight| weight > 0) else {
+            continue;
+        };
+
+        shifted.clone_from(&reachable);
+        shifted <<= weight;
+        reachable |= &shifted;
+    }
+
+    (0..=limit).map(|sum| reachable.test(sum)).collect()
+}
+
+/// Returns true when some subset of the weights adds up exactly to the target.
+pub fn subset_sum<W: PrimInt + Unsigned>(weights: &[W], target: W) -> bool {
+    *subset_sums(weights, target).last().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn sample() -> Vec<Item<u32, u32>> {
+        vec![Item::new(1, 1), Item::new(3, 4), Item::new(4, 5), Item::new(5, 7)]
+    }
+
+    #[test]
+    fn zero_one_knapsack_should_be_solved() {
+        let packed = knapsack(&sample(), 7u32);
+
+        assert_eq!(packed, Knapsack { value: 9, weight: 7, items: vec![1, 2] });
+    }
+
+    #[test]
+    fn unbounded_knapsack_should_reuse_items() {
+        let items = vec![Item::new(3u8, 5), Item::new(4, 7), Item::new(10, 16)];
+
+        let packed = unbounded_knapsack(&items, 11u8);
+
+        assert_eq!(packed.value, 19);
+        assert_eq!(packed.items, vec![0, 1, 1]);
+        assert_eq!(packed.weight, 11);
+    }
+
+    #[test]
+    fn bounded_knapsack_should_not_overflow_on_copies_that_do_not_fit() {
+        let items = [Item::new(1u32, 100u8), Item::new(1, 0), Item::new(0, 50)];
+
+        let packed = bounded_knapsack(&items, &[10, usize::MAX, 1], 2);
+
+        assert_eq!((packed.value, packed.weight), (250, 2));
+        assert_eq!(bounded_knapsack(&[Item::new(1u32, -100i8)], &[10], 10).value, 0);
+    }
+
+    #[test]
+    fn bounded_knapsack_should_respect_counts() {
+        let items = vec![Item::new(2u64, 3), Item::new(5, 9)];
+
+        let packed = bounded_knapsack(&items, &[3, 1], 13u64);
+
+        assert_eq!(packed, Knapsack { value: 18, weight: 11, items: vec![0, 0, 0, 1] });
+    }
+
+    #[test]
+    fn oversized_items_and_huge_capacity_types_should_be_handled() {
+        let items = vec![Item::new(u64::MAX, 100), Item::new(2, 1)];
+
+        assert_eq!(knapsack(&items, 10u64).items, vec![1]);
+        assert_eq!(unbounded_knapsack(&items, 10u64).items, vec![1; 5]);
+    }
+
+    #[test]
+    fn subset_sums_should_be_found() {
+        let reachable = subset_sums(&[3u32, 5, 9], 20);
+
+        let expected = [0, 3, 5, 8, 9, 12, 14, 17];
+        assert!((0..=20).all(|sum| reachable[sum] == expected.contains(&sum)));
+        assert!(subset_sum(&[3u32, 5, 9], 17));
+        assert!(!subset_sum(&[3u32, 5, 9], 16));
+    }
+
+    #[test]
+    fn solvers_should_match_brute_force_on_random_items() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let n = rng.gen_range(0..9);
+            let items: Vec<Item<usize, u32>> = (0..n).map(|_| Item::new(rng.gen_range(1..30), rng.gen_range(0..50))).collect();
+            let counts: Vec<usize> = (0..n).map(|_| rng.gen_range(0..3)).collect();
+            let capacity = rng.gen_range(0..100);
+
+            let mut best = 0;
+            let mut sums = [false; 256];
+            let mut best_bounded = 0;
+            for mask in 0..1usize << n {
+                let chosen: Vec<&Item<usize, u32>> = (0..n).filter(|i| mask >> i & 1 == 1).map(|i| &items[i]).collect();
+                let weight: usize = chosen.iter().map(|item| item.weight).sum();
+                let value: u32 = chosen.iter().map(|item| item.value).sum();
+                sums[weight] = true;
+                if weight <= capacity {
+                    best = best.max(value);
+                }
+            }
+            for code in 0..3usize.pow(n as u32) {
+                let copies: Vec<usize> = (0..n).map(|i| code / 3usize.pow(i as u32) % 3).collect();
+                if (0..n).all(|i| copies[i] <= counts[i]) {
+                    let weight: usize = (0..n).map(|i| copies[i] * items[i].weight).sum();
+                    if weight <= capacity {
+                        best_bounded = best_bounded.max((0..n).map(|i| copies[i] as u32 * items[i].value).sum());
+                    }
+                }
+            }
+
+            let packed = knapsack(&items, capacity);
+            assert_eq!(packed.value, best);
+            assert!(packed.weight <= capacity);
+            assert_eq!(packed.items.iter().map(|&i| items[i].value).sum::<u32>(), packed.value);
+
+            let bounded = bounded_knapsack(&items, &counts, capacity);
+            assert_eq!(bounded.value, best_bounded);
+            assert!(bounded.weight <= capacity);
+            assert!((0..n).all(|i| bounded.items.iter().filter(|&&item| item == i).count() <= counts[i]));
+
+            let weights: Vec<usize> = items.iter().map(|item| item.weight).collect();
+            assert_eq!(subset_sums(&weights, 150), sums[..=150].to_vec());
+        }
+    }
+}
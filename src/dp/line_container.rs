@@ -0,0 +1,401 @@
+//! This module contains containers of lines answering maximum queries, the convex hull trick
+//! and the Li Chao tree, which speed up dynamic programs whose transitions are linear.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Set of lines y = slope * x + intercept that finds the largest value at a point.
+///
+/// Note: values of the lines at queried points must fit into `i64`, other values are compared
+/// exactly without overflow. Negate slopes, intercepts and the result to get the smallest value instead.
+pub trait LineContainer {
+    /// Adds the line y = slope * x + intercept.
+    fn add_line(&mut self, slope: i64, intercept: i64);
+
+    /// Returns the largest value of the lines at x, none when there are no lines.
+    fn query_max(&self, x: i64) -> Option<i64>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Line {
+    slope: i64,
+    intercept: i64,
+}
+
+impl Line {
+    // Exact even where the value does not fit into i64, such as at the ends of a wide range.
+    fn at(self, x: i64) -> i128 {
+        self.slope as i128 * x as i128 + self.intercept as i128
+    }
+}
+
+fn to_result(value: i128) -> i64 {
+    i64::try_from(value).expect("Value at a queried point must fit into i64")
+}
+
+// Rounds down, with no overflow even for the widest range.
+fn midpoint(low: i64, high: i64) -> i64 {
+    (low as i128 + high as i128).div_euclid(2) as i64
+}
+
+/// Upper envelope of lines added in order of non-decreasing slope, the monotone convex hull trick.
+///
+/// Note:
+///     The envelope is kept as a stack: a new line removes the lines it uncovers from the top,
+///     so adding runs in amortized O(1). A query finds the line of the envelope taking the
+///     largest value with binary search in O(log n).
+#[derive(Debug, Clone, Default)]
+pub struct MonotoneHull {
+    // Lines of the envelope by increasing slope, each one largest on some interval.
+    lines: Vec<Line>,
+}
+
+impl MonotoneHull {
+    /// Creates an empty hull.
+    pub fn new() -> Self {
+        MonotoneHull { lines: Vec::new() }
+    }
+
+    /// Returns the number of lines on the envelope.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns true when the hull has no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+// Orders like the product of two differences of i64, which may not fit into i128 but its magnitude
+// always fits into u128.
+fn product_key(left: i128, right: i128) -> (bool, u128) {
+    let magnitude = left.unsigned_abs() * right.unsigned_abs();
+    if magnitude != 0 && (left < 0) != (right < 0) {
+        (false, u128::MAX - magnitude)
+    } else {
+        (true, magnitude)
+    }
+}
+
+// Tells whether the middle line is nowhere above both lines around it, given increasing slopes.
+fn is_covered(first: Line, middle: Line, last: Line) -> bool {
+    let (first_slope, first_intercept) = (first.slope as i128, first.intercept as i128);
+    // The first and the last line cross no later than the first and the middle one.
+    product_key(first_intercept - last.intercept as i128, middle.slope as i128 - first_slope)
+        <= product_key(first_intercept - middle.intercept as i128, last.slope as i128 - first_slope)
+}
+
+impl LineContainer for MonotoneHull {
+    /// Adds the line y = slope * x + intercept.
+    ///
+    /// Panics when the slope is smaller than the slope of a line added before.
+    fn add_line(&mut self, slope: i64, intercept: i64) {
+        let line = Line { slope, intercept };
+
+        if let Some(&last) = self.lines.last() {
+            assert!(slope >= last.slope, "Slopes must be added in non-decreasing order");
+            if slope == last.slope {
+                if intercept <= last.intercept {
+                    return;
+                }
+                self.lines.pop();
+            }
+        }
+
+        while let [.., first, middle] = self.lines[..] {
+            if !is_covered(first, middle, line) {
+                break;
+            }
+            self.lines.pop();
+        }
+
+        self.lines.push(line);
+    }
+
+    fn query_max(&self, x: i64) -> Option<i64> {
+        // Values at x increase along the envelope up to the largest one, then decrease.
+        let (mut low, mut high) = (0, self.lines.len().checked_sub(1)?);
+        while low < high {
+            let middle = (low + high) / 2;
+            if self.lines[middle].at(x) < self.lines[middle + 1].at(x) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        Some(to_result(self.lines[low].at(x)))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LiChaoNode {
+    line: Line,
+    children: [usize; 2],
+}
+
+const NO_CHILD: usize = usize::MAX;
+
+/// Li Chao tree over the integers of a range, taking lines in any order.
+///
+/// Note:
+///     Every node of a segment tree over the range keeps the line largest at its middle among
+///     the lines that reached it. The other line can be larger on one half only and goes down
+///     there. Adding and querying run in O(log w) for the width w of the range, and nodes are
+///     created as lines reach them, so any range of `i64`, up to the whole of it, is fine.
+#[derive(Debug, Clone)]
+pub struct LiChaoTree {
+    low: i64,
+    high: i64,
+    // Node 0 is the root, when there is one.
+    nodes: Vec<LiChaoNode>,
+}
+
+impl LiChaoTree {
+    /// Creates an empty tree for queries at integers of the inclusive range from low to high.
+    ///
+    /// Panics when the range is empty.
+    pub fn new(low: i64, high: i64) -> Self {
+        assert!(low <= high, "Range [{}, {}] is empty", low, high);
+        LiChaoTree { low, high, nodes: Vec::new() }
+    }
+
+    fn new_node(&mut self, line: Line) -> usize {
+        self.nodes.push(LiChaoNode { line, children: [NO_CHILD; 2] });
+        self.nodes.len() - 1
+    }
+}
+
+impl LineContainer for LiChaoTree {
+    fn add_line(&mut self, slope: i64, intercept: i64) {
+        let mut line = Line { slope, intercept };
+        if self.nodes.is_empty() {
+            self.new_node(line);
+            return;
+        }
+
+        let (mut node, mut low, mut high) = (0, self.low, self.high);
+        loop {
+            let middle = midpoint(low, high);
+            let kept = &mut self.nodes[node].line;
+            if line.at(middle) > kept.at(middle) {
+                core::mem::swap(&mut line, kept);
+            }
+            let kept = *kept;
+
+            // The line is below the kept one at the middle, so it can only win on one side.
+            let side = if line.at(low) > kept.at(low) {
+                high = middle;
+                0
+            } else if line.at(high) > kept.at(high) {
+                low = middle + 1;
+                1
+            } else {
+                return;
+            };
+
+            match self.nodes[node].children[side] {
+                NO_CHILD => {
+                    let child = self.new_node(line);
+                    self.nodes[node].children[side] = child;
+                    return;
+                }
+                child => node = child,
+            }
+        }
+    }
+
+    /// Returns the largest value of the lines at x, none when there are no lines.
+    ///
+    /// Panics when x is outside the range of the tree.
+    fn query_max(&self, x: i64) -> Option<i64> {
+        assert!(self.low <= x && x <= self.high, "Point {} is outside the range [{}, {}]", x, self.low, self.high);
+
+        let mut best = None;
+        let (mut node, mut low, mut high) = (0, self.low, self.high);
+        while node < self.nodes.len() {
+            let value = self.nodes[node].line.at(x);
+            best = best.max(Some(value));
+
+            let middle = midpoint(low, high);
+            let side = if x <= middle {
+                high = middle;
+                0
+            } else {
+                low = middle + 1;
+                1
+            };
+            node = self.nodes[node].children[side];
+        }
+
+        best.map(to_result)
+    }
+}
+
+/// Solves the frog problem with the convex hull trick: jumping from stone j to a higher stone i
+/// costs the squared height difference plus a constant, and the cheapest way to stone n - 1 is wanted.
+///
+/// Note: the cost of reaching stone i is a minimum over lines given by the earlier stones,
+/// with slopes growing with their heights, so the whole run takes O(n log n).
+///
+/// Returns:
+///
+/// The smallest total cost, zero for fewer than two stones.
+///
+/// Panics when the heights are not strictly increasing.
+pub fn frog_jumps(heights: &[i64], jump_cost: i64) -> i64 {
+    assert!(heights.windows(2).all(|pair| pair[0] < pair[1]), "Heights must be strictly increasing");
+
+    let mut costs = vec![0; heights.len()];
+    let mut hull = MonotoneHull::new();
+    for (i, &height) in heights.iter().enumerate() {
+        if i > 0 {
+            // cost[j] + (h[i] - h[j])^2 = h[i]^2 - max(2 h[j] h[i] - cost[j] - h[j]^2).
+            costs[i] = height * height + jump_cost - hull.query_max(height).unwrap();
+        }
+        hull.add_line(2 * height, -costs[i] - height * height);
+    }
+
+    costs.last().copied().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn naive_max(lines: &[(i64, i64)], x: i64) -> Option<i64> {
+        lines.iter().map(|&(slope, intercept)| slope * x + intercept).max()
+    }
+
+    #[test]
+    fn monotone_hull_should_match_naive_maximum() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let mut lines: Vec<(i64, i64)> =
+                (0..rng.gen_range(0..20)).map(|_| (rng.gen_range(-10..10), rng.gen_range(-50..50))).collect();
+            lines.sort_unstable();
+            let mut hull = MonotoneHull::new();
+
+            for &(slope, intercept) in &lines {
+                hull.add_line(slope, intercept);
+            }
+
+            for x in -30..30 {
+                assert_eq!(hull.query_max(x), naive_max(&lines, x), "lines {:?} at {}", lines, x);
+            }
+        }
+    }
+
+    #[test]
+    fn li_chao_tree_should_match_naive_maximum() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let lines: Vec<(i64, i64)> =
+                (0..rng.gen_range(0..20)).map(|_| (rng.gen_range(-10..10), rng.gen_range(-50..50))).collect();
+            let mut tree = LiChaoTree::new(-30, 30);
+
+            for &(slope, intercept) in &lines {
+                tree.add_line(slope, intercept);
+            }
+
+            for x in -30..=30 {
+                assert_eq!(tree.query_max(x), naive_max(&lines, x), "lines {:?} at {}", lines, x);
+            }
+        }
+    }
+
+    #[test]
+    fn li_chao_tree_should_handle_wide_ranges() {
+        let mut tree = LiChaoTree::new(-1_000_000_000, 1_000_000_000);
+
+        tree.add_line(1, 0);
+        tree.add_line(-1, 0);
+        tree.add_line(0, 500_000_000);
+
+        assert_eq!(tree.query_max(-1_000_000_000), Some(1_000_000_000));
+        assert_eq!(tree.query_max(7), Some(500_000_000));
+        assert_eq!(tree.query_max(999_999_999), Some(999_999_999));
+    }
+
+    #[test]
+    fn li_chao_tree_should_handle_the_full_range() {
+        let mut tree = LiChaoTree::new(i64::MIN, i64::MAX);
+
+        tree.add_line(1, 0);
+        tree.add_line(-1, -1);
+        tree.add_line(3, 5);
+        tree.add_line(0, 1 << 40);
+
+        assert_eq!(tree.query_max(i64::MIN), Some(i64::MAX));
+        assert_eq!(tree.query_max((i64::MAX - 5) / 3), Some(i64::MAX - 2));
+        assert_eq!(tree.query_max(0), Some(1 << 40));
+        assert_eq!(tree.query_max(1 << 40), Some(3 * (1 << 40) + 5));
+        assert_eq!(tree.query_max(-(1 << 41)), Some((1 << 41) - 1));
+    }
+
+    #[test]
+    fn monotone_hull_should_handle_extreme_lines() {
+        let mut hull = MonotoneHull::new();
+
+        hull.add_line(i64::MIN, i64::MAX);
+        hull.add_line(i64::MAX - 1, 0);
+        hull.add_line(i64::MAX, i64::MIN);
+
+        assert_eq!(hull.len(), 3);
+        assert_eq!(hull.query_max(0), Some(i64::MAX));
+        assert_eq!(hull.query_max(1), Some(i64::MAX - 1));
+    }
+
+    #[test]
+    fn product_key_should_order_like_the_exact_product() {
+        let (low, high) = (i64::MIN as i128 - i64::MAX as i128, u64::MAX as i128);
+        let values = [low, i64::MIN as i128, -3, -1, 0, 1, 2, i64::MAX as i128, high];
+        let pairs: Vec<(i128, i128)> = values.iter().flat_map(|&a| values.iter().map(move |&b| (a, b))).collect();
+
+        for &(a, b) in &pairs {
+            for &(c, d) in &pairs {
+                if let (Some(left), Some(right)) = (a.checked_mul(b), c.checked_mul(d)) {
+                    assert_eq!(product_key(a, b).cmp(&product_key(c, d)), left.cmp(&right));
+                }
+            }
+        }
+        assert!(product_key(low, high) < product_key(low, i64::MAX as i128));
+        assert!(product_key(low, i64::MAX as i128) < product_key(i64::MIN as i128, 1));
+        assert!(product_key(high, high) > product_key(high, i64::MAX as i128));
+        assert_eq!(product_key(low, low), product_key(high, high));
+        assert!(product_key(low, high) < product_key(high, high));
+    }
+
+    #[test]
+    #[should_panic]
+    fn decreasing_slope_should_panic() {
+        let mut hull = MonotoneHull::new();
+        hull.add_line(2, 0);
+        hull.add_line(1, 0);
+    }
+
+    #[test]
+    fn frog_jumps_should_match_quadratic_dynamic_program() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let mut heights: Vec<i64> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(0..1000)).collect();
+            heights.sort_unstable();
+            heights.dedup();
+            let jump_cost = rng.gen_range(0..5000);
+
+            let mut expected = vec![0; heights.len()];
+            for i in 1..heights.len() {
+                expected[i] =
+                    (0..i).map(|j| expected[j] + (heights[i] - heights[j]).pow(2) + jump_cost).min().unwrap();
+            }
+
+            assert_eq!(frog_jumps(&heights, jump_cost), expected.last().copied().unwrap_or(0));
+        }
+        assert_eq!(frog_jumps(&[1, 2, 3, 4, 5], 6), 20);
+    }
+}
@@ -0,0 +1,135 @@
+//! This module contains the longest increasing subsequence.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Computes a longest strictly increasing subsequence with patience sorting.
+///
+/// Note:
+///     For every length, the smallest item ending an increasing subsequence of that length
+///     is kept, and these tails are searched with binary search. Every item remembers the
+///     previous one of its subsequence. Runs in O(n log n).
+///
+/// Returns:
+///
+/// The length of the longest increasing subsequence and one such subsequence.
+pub fn lis<T: Ord + Clone>(items: &[T]) -> (usize, Vec<T>) {
+    collect(items, lis_indices(items, true))
+}
+
+/// Computes a longest non-decreasing subsequence, where equal items may follow each other.
+pub fn longest_non_decreasing<T: Ord + Clone>(items: &[T]) -> (usize, Vec<T>) {
+    collect(items, lis_indices(items, false))
+}
+
+/// Computes the positions of a longest increasing subsequence.
+///
+/// Arguments:
+///
+/// * `items`: The sequence to search.
+/// * `strict`: Whether equal items are forbidden to follow each other.
+///
+/// Returns:
+///
+/// The indices of the subsequence in increasing order.
+pub fn lis_indices<T: Ord>(items: &[T], strict: bool) -> Vec<usize> {
+    if strict {
+        patience(items, |tail, item| tail < item)
+    } else {
+        patience(items, |tail, item| tail <= item)
+    }
+}
+
+fn collect<T: Clone>(items: &[T], indices: Vec<usize>) -> (usize, Vec<T>) {
+    (indices.len(), indices.into_iter().map(|index| items[index].clone()).collect())
+}
+
+// `extends(tail, item)` tells whether the item can follow the tail in a subsequence.
+fn patience<T: Ord>(items: &[T], extends: impl Fn(&T, &T) -> bool) -> Vec<usize> {
+    // tails[length - 1] is the index of the smallest item ending a subsequence of that length.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![usize::MAX; items.len()];
+
+    for (index, item) in items.iter().enumerate() {
+        let length = tails.partition_point(|&tail| extends(&items[tail], item));
+        if length > 0 {
+            previous[index] = tails[length - 1];
+        }
+
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+
+    let mut subsequence = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        subsequence.push(index);
+        current = (previous[index] != usize::MAX).then(|| previous[index]);
+    }
+
+    subsequence.reverse();
+    subsequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn increasing_subsequence_should_be_found() {
+        let items = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9];
+
+        let (length, subsequence) = lis(&items);
+
+        assert_eq!(length, 6);
+        assert_eq!(subsequence, vec![1, 2, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn repeated_items_should_count_only_when_non_strict() {
+        let items = [2, 2, 2, 1, 3, 3];
+
+        assert_eq!(lis(&items), (2, vec![1, 3]));
+        assert_eq!(longest_non_decreasing(&items), (5, vec![2, 2, 2, 3, 3]));
+        assert_eq!(lis_indices(&items, false), vec![0, 1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn trivial_inputs_should_be_supported() {
+        assert_eq!(lis::<u8>(&[]), (0, Vec::new()));
+        assert_eq!(lis(&[5, 4, 3]), (1, vec![3]));
+    }
+
+    #[test]
+    fn lengths_should_match_quadratic_dp_on_random_input() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let items: Vec<u8> = (0..rng.gen_range(0..40)).map(|_| rng.gen_range(0..10)).collect();
+
+            let quadratic = |strict: bool| {
+                let mut best = vec![1; items.len()];
+                for i in 0..items.len() {
+                    for j in 0..i {
+                        if items[j] < items[i] || (!strict && items[j] == items[i]) {
+                            best[i] = best[i].max(best[j] + 1);
+                        }
+                    }
+                }
+                best.into_iter().max().unwrap_or(0)
+            };
+
+            let strict = lis_indices(&items, true);
+            let non_strict = lis_indices(&items, false);
+
+            assert_eq!(strict.len(), quadratic(true));
+            assert_eq!(non_strict.len(), quadratic(false));
+            assert!(strict.windows(2).all(|pair| pair[0] < pair[1] && items[pair[0]] < items[pair[1]]));
+            assert!(non_strict.windows(2).all(|pair| pair[0] < pair[1] && items[pair[0]] <= items[pair[1]]));
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! This module contains the optimal multiplication order of a chain of matrices.
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul};
+
+use num::Zero;
+
+use crate::numbers::matrix::Matrix;
+
+/// Order of multiplying a chain of matrices, as a binary tree of products.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Parenthesization {
+    /// The matrix at the given position of the chain.
+    Matrix(usize),
+    /// The product of two consecutive parts of the chain.
+    Product(Box<Parenthesization>, Box<Parenthesization>),
+}
+
+impl Parenthesization {
+    /// Multiplies the chain in this order.
+    ///
+    /// Panics when the matrices do not fit the order or their dimensions do not match.
+    pub fn multiply<T>(&self, matrices: &[Matrix<T>]) -> Matrix<T>
+    where
+        T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+    {
+        self.evaluate(matrices).into_owned()
+    }
+
+    // Leaves are borrowed, so only products create new matrices.
+    fn evaluate<'a, T>(&self, matrices: &'a [Matrix<T>]) -> Cow<'a, Matrix<T>>
+    where
+        T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+    {
+        match self {
+            Parenthesization::Matrix(index) => Cow::Borrowed(&matrices[*index]),
+            Parenthesization::Product(left, right) => {
+                Cow::Owned(left.evaluate(matrices).multiply(&right.evaluate(matrices)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Parenthesization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Parenthesization::Matrix(index) => write!(f, "A{index}"),
+            Parenthesization::Product(left, right) => write!(f, "({left}{right})"),
+        }
+    }
+}
+
+/// Cheapest way to multiply a chain of matrices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainOrder {
+    /// The number of scalar multiplications needed.
+    pub cost: usize,
+    /// The order of the products.
+    pub order: Parenthesization,
+}
+
+/// Finds the order of multiplying a chain of matrices with the fewest scalar multiplications.
+///
+/// Note:
+///     The cheapest cost of every subchain is found from shorter subchains by trying every
+///     split point. Multiplying a p x q matrix by a q x r one costs p * q * r. Runs in
+///     O(n^3) time and O(n^2) memory.
+///
+/// Arguments:
+///
+/// * `dims`: The dimensions of the chain, matrix i is `dims[i]` x `dims[i + 1]`.
+///
+/// Panics when there are fewer than two dimensions.
+pub fn matrix_chain_order(dims: &[usize]) -> ChainOrder {
+    assert!(dims.len() >= 2, "A chain needs at least one matrix");
+
+    let n = dims.len() - 1;
+    // cost[i][j] is the cheapest cost of multiplying matrices i..=j, split[i][j] the last matrix of its left part.
+    let mut cost = vec![vec![0; n]; n];
+    let mut split = vec![vec![0; n]; n];
+
+    for length in 2..=n {
+        for i in 0..=n - length {
+            let j = i + length - 1;
+            let (best_cost, best_split) = (i..j)
+                .map(|k| (cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1], k))
+                .min()
+                .unwrap();
+            cost[i][j] = best_cost;
+            split[i][j] = best_split;
+        }
+    }
+
+    ChainOrder { cost: cost[0][n - 1], order: build(&split, 0, n - 1) }
+}
+
+fn build(split: &[Vec<usize>], i: usize, j: usize) -> Parenthesization {
+    if i == j {
+        return Parenthesization::Matrix(i);
+    }
+
+    let k = split[i][j];
+    Parenthesization::Product(Box::new(build(split, i, k)), Box::new(build(split, k + 1, j)))
+}
+
+/// Multiplies a chain of matrices in the cheapest order.
+///
+/// Panics when the chain is empty or the dimensions of neighboring matrices do not match.
+pub fn multiply_chain<T>(matrices: &[Matrix<T>]) -> Matrix<T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    assert!(!matrices.is_empty(), "A chain needs at least one matrix");
+    assert!(
+        matrices.windows(2).all(|pair| pair[0].cols() == pair[1].rows()),
+        "Matrix dimensions do not match for multiplication"
+    );
+
+    let dims: Vec<usize> = core::iter::once(matrices[0].rows()).chain(matrices.iter().map(Matrix::cols)).collect();
+
+    matrix_chain_order(&dims).order.multiply(matrices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn brute_force(dims: &[usize]) -> usize {
+        if dims.len() <= 2 {
+            return 0;
+        }
+
+        (1..dims.len() - 1)
+            .map(|k| brute_force(&dims[..=k]) + brute_force(&dims[k..]) + dims[0] * dims[k] * dims[dims.len() - 1])
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn textbook_chain_should_be_ordered() {
+        let chain = matrix_chain_order(&[30, 35, 15, 5, 10, 20, 25]);
+
+        assert_eq!(chain.cost, 15125);
+        assert_eq!(chain.order.to_string(), "((A0(A1A2))((A3A4)A5))");
+    }
+
+    #[test]
+    fn single_matrix_should_cost_nothing() {
+        let chain = matrix_chain_order(&[4, 7]);
+
+        assert_eq!(chain, ChainOrder { cost: 0, order: Parenthesization::Matrix(0) });
+    }
+
+    #[test]
+    fn chain_should_be_multiplied_in_optimal_order() {
+        let mut rng = rand::thread_rng();
+        let dims = [3, 1, 4, 1, 5];
+        let matrices: Vec<Matrix<i64>> = dims
+            .windows(2)
+            .map(|pair| Matrix::new(pair[0], pair[1], (0..pair[0] * pair[1]).map(|_| rng.gen_range(-9..10)).collect()))
+            .collect();
+
+        let expected = matrices[1..].iter().fold(matrices[0].clone(), |product, matrix| &product * matrix);
+
+        assert_eq!(multiply_chain(&matrices), expected);
+    }
+
+    #[test]
+    fn cost_should_match_brute_force_on_random_chains() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let dims: Vec<usize> = (0..rng.gen_range(2..9)).map(|_| rng.gen_range(1..30)).collect();
+
+            assert_eq!(matrix_chain_order(&dims).cost, brute_force(&dims));
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod knapsack;
+pub mod lis;
+pub mod coin_change;
+pub mod matrix_chain;
+pub mod line_container;
+
+pub use knapsack::{bounded_knapsack, knapsack, subset_sum, subset_sums, unbounded_knapsack, Item, Knapsack};
+pub use lis::{lis, lis_indices, longest_non_decreasing};
+pub use coin_change::{count_ways, count_ways_mod, min_coins};
+pub use matrix_chain::{matrix_chain_order, multiply_chain, ChainOrder, Parenthesization};
+pub use line_container::{frog_jumps, LiChaoTree, LineContainer, MonotoneHull};
@@ -0,0 +1,271 @@
+//! This module contains the closest pair of points.
+
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use num::ToPrimitive;
+
+use crate::geometry::point::{Coordinate, Point};
+
+/// Two points at the smallest distance among a set of points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestPair<T> {
+    /// The indices of the points, the smaller one first.
+    pub indices: (usize, usize),
+    /// The squared distance between the points, exact for integer coordinates.
+    pub distance_squared: T,
+}
+
+impl<T: Coordinate + ToPrimitive> ClosestPair<T> {
+    /// Returns the distance between the points.
+    #[cfg(feature = "std")]
+    pub fn distance(&self) -> f64 {
+        self.distance_squared.to_f64().unwrap().sqrt()
+    }
+}
+
+impl<T: Coordinate> ClosestPair<T> {
+    fn between(points: &[Point<T>], i: usize, j: usize) -> Self {
+        ClosestPair { indices: (i.min(j), i.max(j)), distance_squared: points[i].distance_squared(points[j]) }
+    }
+
+    fn closer(self, other: Option<Self>) -> Self {
+        match other {
+            Some(other) if other.distance_squared < self.distance_squared => other,
+            _ => self,
+        }
+    }
+}
+
+fn by_x<T: Coordinate>(points: &[Point<T>]) -> impl Fn(&usize, &usize) -> Ordering + '_ {
+    |&i, &j| points[i].lexicographic_cmp(&points[j])
+}
+
+/// Finds the closest pair of points by divide and conquer.
+///
+/// Note:
+///     Points are split by x into halves solved recursively. Pairs across the split only
+///     matter within the best distance of it, and each point of that strip needs comparing
+///     with the few strip points just above it. Merging halves by y keeps every level
+///     linear, so the whole search runs in O(n log n).
+///
+/// Returns:
+///
+/// The closest pair, `None` for fewer than two points. Equal points are at distance zero.
+///
+/// Panics when a coordinate is NaN.
+pub fn closest_pair<T: Coordinate>(points: &[Point<T>]) -> Option<ClosestPair<T>> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(by_x(points));
+
+    let mut buffer = vec![0; points.len()];
+    Some(divide(points, &mut order, &mut buffer))
+}
+
+// Finds the closest pair among the indices sorted by x, leaving them sorted by y.
+fn divide<T: Coordinate>(points: &[Point<T>], indices: &mut [usize], buffer: &mut [usize]) -> ClosestPair<T> {
+    let by_y = |&i: &usize, &j: &usize| points[i].y.partial_cmp(&points[j].y).expect("Coordinates must be comparable");
+
+    if indices.len() <= 3 {
+        let mut best = ClosestPair::between(points, indices[0], indices[1]);
+        for a in 0..indices.len() {
+            for b in a + 1..indices.len() {
+                best = best.closer(Some(ClosestPair::between(points, indices[a], indices[b])));
+            }
+        }
+        indices.sort_by(by_y);
+        return best;
+    }
+
+    let middle = indices.len() / 2;
+    let split_x = points[indices[middle]].x;
+    let (left, right) = indices.split_at_mut(middle);
+    let mut best = divide(points, left, buffer).closer(Some(divide(points, right, buffer)));
+
+    // Merge the halves by y.
+    let (mut i, mut j) = (0, middle);
+    for slot in buffer[..indices.len()].iter_mut() {
+        let take_left = j == indices.len() || (i < middle && by_y(&indices[i], &indices[j]).is_le());
+        if take_left {
+            *slot = indices[i];
+            i += 1;
+        } else {
+            *slot = indices[j];
+            j += 1;
+        }
+    }
+    indices.copy_from_slice(&buffer[..indices.len()]);
+
+    let mut strip: Vec<usize> = Vec::new();
+    for &index in indices.iter() {
+        let dx = points[index].x - split_x;
+        if dx * dx >= best.distance_squared {
+            continue;
+        }
+
+        for &other in strip.iter().rev() {
+            let dy = points[index].y - points[other].y;
+            if dy * dy >= best.distance_squared {
+                break;
+            }
+            best = best.closer(Some(ClosestPair::between(points, index, other)));
+        }
+        strip.push(index);
+    }
+
+    best
+}
+
+// Orders the active points of the sweep by y, then by index.
+struct ByY<T> {
+    y: T,
+    index: usize,
+}
+
+impl<T: PartialOrd> PartialEq for ByY<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for ByY<T> {}
+
+impl<T: PartialOrd> PartialOrd for ByY<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for ByY<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.partial_cmp(&other.y).expect("Coordinates must be comparable").then(self.index.cmp(&other.index))
+    }
+}
+
+/// Finds the closest pair of points with a sweep line.
+///
+/// Note:
+///     Points are visited by x while the points closer in x than the best distance are
+///     kept ordered by y. Only a constant number of them around the y of the current point
+///     can beat the best distance. Runs in O(n log n).
+///
+/// Panics when a coordinate is NaN.
+pub fn closest_pair_sweep<T: Coordinate>(points: &[Point<T>]) -> Option<ClosestPair<T>> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(by_x(points));
+
+    let mut best: Option<ClosestPair<T>> = None;
+    let mut active: BTreeSet<ByY<T>> = BTreeSet::new();
+    let mut oldest = 0;
+
+    for (position, &index) in order.iter().enumerate() {
+        let point = points[index];
+
+        // Points farther in x than the best distance can no longer be part of a closer pair.
+        while let Some(current) = best.filter(|_| oldest < position) {
+            let leaving = points[order[oldest]];
+            let dx = point.x - leaving.x;
+            if dx * dx < current.distance_squared {
+                break;
+            }
+            active.remove(&ByY { y: leaving.y, index: order[oldest] });
+            oldest += 1;
+        }
+
+        let key = ByY { y: point.y, index };
+        for other in active.range(&key..) {
+            let dy = other.y - point.y;
+            if best.is_some_and(|best| dy * dy >= best.distance_squared) {
+                break;
+            }
+            best = Some(ClosestPair::between(points, index, other.index).closer(best));
+        }
+        for other in active.range(..&key).rev() {
+            let dy = point.y - other.y;
+            if best.is_some_and(|best| dy * dy >= best.distance_squared) {
+                break;
+            }
+            best = Some(ClosestPair::between(points, index, other.index).closer(best));
+        }
+
+        active.insert(key);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn brute_force<T: Coordinate>(points: &[Point<T>]) -> T {
+        let mut best = points[0].distance_squared(points[1]);
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                let distance = points[i].distance_squared(points[j]);
+                if distance < best {
+                    best = distance;
+                }
+            }
+        }
+
+        best
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn closest_pair_should_be_found() {
+        let points: Vec<Point<i64>> = [(2, 3), (12, 30), (40, 50), (5, 1), (12, 10), (3, 4)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+
+        let expected = ClosestPair { indices: (0, 5), distance_squared: 2 };
+
+        assert_eq!(closest_pair(&points), Some(expected));
+        assert_eq!(closest_pair_sweep(&points), Some(expected));
+        assert_eq!(expected.distance(), 2f64.sqrt());
+    }
+
+    #[test]
+    fn repeated_points_should_be_at_distance_zero() {
+        let points = [Point::new(0, 0), Point::new(5, 5), Point::new(9, 1), Point::new(5, 5)];
+
+        assert_eq!(closest_pair(&points).unwrap().indices, (1, 3));
+        assert_eq!(closest_pair_sweep(&points).unwrap().distance_squared, 0);
+    }
+
+    #[test]
+    fn fewer_than_two_points_should_have_no_pair() {
+        assert_eq!(closest_pair::<i64>(&[]), None);
+        assert_eq!(closest_pair_sweep(&[Point::new(1.0, 2.0)]), None);
+    }
+
+    #[test]
+    fn both_methods_should_match_brute_force_on_random_points() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let n = rng.gen_range(2..150);
+            let range = rng.gen_range(1..1000);
+            let integers: Vec<Point<i64>> =
+                (0..n).map(|_| Point::new(rng.gen_range(-range..range), rng.gen_range(-range..range))).collect();
+            let floats: Vec<Point<f64>> = (0..n).map(|_| Point::new(rng.gen(), rng.gen())).collect();
+
+            for closest in [closest_pair(&integers).unwrap(), closest_pair_sweep(&integers).unwrap()] {
+                assert_eq!(closest.distance_squared, brute_force(&integers));
+                let (i, j) = closest.indices;
+                assert_eq!(integers[i].distance_squared(integers[j]), closest.distance_squared);
+            }
+            assert_eq!(closest_pair(&floats).unwrap().distance_squared, brute_force(&floats));
+            assert_eq!(closest_pair_sweep(&floats).unwrap().distance_squared, brute_force(&floats));
+        }
+    }
+}
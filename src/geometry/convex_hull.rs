@@ -0,0 +1,109 @@
+//! This module contains the convex hull of a set of points.
+
+use alloc::vec::Vec;
+
+use crate::geometry::point::{orientation, Coordinate, Orientation, Point};
+
+/// Computes the convex hull with Andrew's monotone chain algorithm.
+///
+/// Note:
+///     Points are sorted by x, then the lower and the upper chains are built by dropping
+///     every point that does not make a left turn. Runs in O(n log n).
+///
+/// Returns:
+///
+/// The vertices of the hull in counterclockwise order, starting from the point with the
+/// smallest x and then y. Points on the hull edges are left out, and fewer than three
+/// vertices are returned when all points are collinear.
+///
+/// Panics when a coordinate is NaN.
+pub fn convex_hull<T: Coordinate>(points: &[Point<T>]) -> Vec<Point<T>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(Point::lexicographic_cmp);
+    sorted.dedup();
+
+    if sorted.len() <= 2 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Point<T>> = Vec::with_capacity(sorted.len() + 1);
+    for &point in &sorted {
+        while hull.len() >= 2 && !turns_left(&hull, point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // The upper chain goes back from the last point and must keep the whole lower chain.
+    let lower_len = hull.len() + 1;
+    for &point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(&hull, point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // The upper chain ends at the first point again.
+    hull.pop();
+    hull
+}
+
+fn turns_left<T: Coordinate>(chain: &[Point<T>], point: Point<T>) -> bool {
+    orientation(chain[chain.len() - 2], chain[chain.len() - 1], point) == Orientation::CounterClockwise
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn hull_should_skip_inner_and_edge_points() {
+        let points: Vec<Point<i64>> = [(0, 0), (2, 0), (4, 0), (4, 4), (2, 2), (1, 3), (0, 4), (0, 2), (3, 1)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+
+        let hull = convex_hull(&points);
+
+        assert_eq!(hull, vec![Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)]);
+    }
+
+    #[test]
+    fn degenerate_inputs_should_give_short_hulls() {
+        let collinear: Vec<Point<i64>> = (0..5).map(|i| Point::new(i, 2 * i)).rev().collect();
+
+        assert_eq!(convex_hull(&collinear), vec![Point::new(0, 0), Point::new(4, 8)]);
+        assert_eq!(convex_hull(&[Point::new(1, 1); 3]), vec![Point::new(1, 1)]);
+        assert_eq!(convex_hull::<i64>(&[]), Vec::new());
+    }
+
+    #[test]
+    fn float_hull_should_drop_nearly_collinear_points() {
+        let points = [Point::new(0.0, 0.0), Point::new(0.5, 1e-13), Point::new(1.0, 0.0), Point::new(0.5, 1.0)];
+
+        assert_eq!(convex_hull(&points), vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.5, 1.0)]);
+    }
+
+    #[test]
+    fn hull_should_contain_all_points_on_random_input() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let points: Vec<Point<i64>> =
+                (0..rng.gen_range(1..60)).map(|_| Point::new(rng.gen_range(-20..20), rng.gen_range(-20..20))).collect();
+
+            let hull = convex_hull(&points);
+
+            assert!(hull.iter().all(|vertex| points.contains(vertex)));
+            let n = hull.len();
+            if n >= 3 {
+                for i in 0..n {
+                    let (a, b) = (hull[i], hull[(i + 1) % n]);
+                    assert_eq!(orientation(a, b, hull[(i + 2) % n]), Orientation::CounterClockwise);
+                    assert!(points.iter().all(|&p| orientation(a, b, p) != Orientation::Clockwise));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,567 @@
+//! This module contains the Delaunay triangulation of a set of points and the Voronoi
+//! diagram derived from it as its dual.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use num::{Signed, ToPrimitive};
+
+use crate::geometry::point::{orientation, Coordinate, Orientation, Point, Vector};
+
+/// Triangulation of a set of points.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Triangulation {
+    /// The triangles as indices into the points, each listed counterclockwise.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+/// Region of the plane closer to one site than to any other.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VoronoiCell {
+    /// Indices of the Voronoi vertices around the site in counterclockwise order.
+    pub vertices: Vec<usize>,
+    /// For unbounded cells, the directions of the edges leaving the first and the last
+    /// vertex towards infinity.
+    pub rays: Option<(Vector<f64>, Vector<f64>)>,
+}
+
+/// Voronoi diagram of a set of sites.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VoronoiDiagram {
+    /// The Voronoi vertices, the circumcenters of the Delaunay triangles in the same order.
+    pub vertices: Vec<Point<f64>>,
+    /// The cell of every site, in the order of the sites.
+    pub cells: Vec<VoronoiCell>,
+}
+
+/// Tells whether d lies inside the circle through a, b and c, given counterclockwise.
+///
+/// Note: the result is exact for integer coordinates, computed in `Coordinate::Wide`. For
+/// floating point coordinates, points within the relative tolerance `EPSILON` of the circle
+/// count as on it.
+///
+/// Returns:
+///
+/// `Greater` inside the circle, `Equal` on it and `Less` outside. The signs swap when a, b
+/// and c are given clockwise.
+pub fn in_circle<T: Coordinate>(a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>) -> Ordering {
+    let offset = |p: Point<T>| ((p.x - d.x).widen(), (p.y - d.y).widen());
+    let ((ax, ay), (bx, by), (cx, cy)) = (offset(a), offset(b), offset(c));
+    let (a_lift, b_lift, c_lift) = (ax * ax + ay * ay, bx * bx + by * by, cx * cx + cy * cy);
+
+    let determinant = a_lift * (bx * cy - by * cx) + b_lift * (cx * ay - cy * ax) + c_lift * (ax * by - ay * bx);
+    let magnitude = || a_lift * ((bx * cy).abs() + (by * cx).abs()) + b_lift * ((cx * ay).abs() + (cy * ax).abs()) + c_lift * ((ax * by).abs() + (ay * bx).abs());
+
+    determinant.sign_within(magnitude)
+}
+
+// Returns the vertex of the triangle following the given one counterclockwise.
+fn after(triangle: [usize; 3], vertex: usize) -> usize {
+    let position = triangle.iter().position(|&v| v == vertex).unwrap();
+    triangle[(position + 1) % 3]
+}
+
+// Returns the vertex of the triangle that is not an endpoint of the edge.
+fn opposite(triangle: [usize; 3], from: usize, to: usize) -> usize {
+    *triangle.iter().find(|&&v| v != from && v != to).unwrap()
+}
+
+// Maps every directed edge, with the triangle on its left, to that triangle.
+fn edge_map(triangles: &[[usize; 3]]) -> BTreeMap<(usize, usize), usize> {
+    let mut edges = BTreeMap::new();
+    for (index, &[a, b, c]) in triangles.iter().enumerate() {
+        edges.extend([((a, b), index), ((b, c), index), ((c, a), index)]);
+    }
+    edges
+}
+
+// Quad-edge structure of Guibas and Stolfi: the four directed versions of an undirected edge,
+// primal and dual, are stored next to each other, so edge e turns to e + 1 mod 4 and reverses
+// to e ^ 2. Each one keeps its successor counterclockwise around its origin.
+struct QuadEdges {
+    onext: Vec<usize>,
+    // The origin of every primal version, unused for the dual ones.
+    origin: Vec<usize>,
+    deleted: Vec<bool>,
+}
+
+impl QuadEdges {
+    fn rot(e: usize) -> usize {
+        (e & !3) | ((e + 1) & 3)
+    }
+
+    fn sym(e: usize) -> usize {
+        e ^ 2
+    }
+
+    fn rot_inv(e: usize) -> usize {
+        (e & !3) | ((e + 3) & 3)
+    }
+
+    fn org(&self, e: usize) -> usize {
+        self.origin[e]
+    }
+
+    fn dest(&self, e: usize) -> usize {
+        self.origin[Self::sym(e)]
+    }
+
+    fn onext(&self, e: usize) -> usize {
+        self.onext[e]
+    }
+
+    fn oprev(&self, e: usize) -> usize {
+        Self::rot(self.onext[Self::rot(e)])
+    }
+
+    fn lnext(&self, e: usize) -> usize {
+        Self::rot(self.onext[Self::rot_inv(e)])
+    }
+
+    fn rprev(&self, e: usize) -> usize {
+        self.onext[Self::sym(e)]
+    }
+
+    fn make_edge(&mut self, from: usize, to: usize) -> usize {
+        let e = self.onext.len();
+        self.onext.extend([e, e + 3, e + 2, e + 1]);
+        self.origin.extend([from, usize::MAX, to, usize::MAX]);
+        self.deleted.extend([false; 4]);
+        e
+    }
+
+    // Joins the rings around the origins of a and b when they are apart and splits them otherwise.
+    fn splice(&mut self, a: usize, b: usize) {
+        let (alpha, beta) = (Self::rot(self.onext[a]), Self::rot(self.onext[b]));
+        self.onext.swap(a, b);
+        self.onext.swap(alpha, beta);
+    }
+
+    // Adds an edge from the destination of a to the origin of b, with the faces of both on its left.
+    fn connect(&mut self, a: usize, b: usize) -> usize {
+        let e = self.make_edge(self.dest(a), self.org(b));
+        self.splice(e, self.lnext(a));
+        self.splice(Self::sym(e), b);
+        e
+    }
+
+    fn delete(&mut self, e: usize) {
+        self.splice(e, self.oprev(e));
+        self.splice(Self::sym(e), self.oprev(Self::sym(e)));
+        let base = e & !3;
+        self.deleted[base..base + 4].fill(true);
+    }
+}
+
+struct DivideAndConquer<'a, T> {
+    points: &'a [Point<T>],
+    edges: QuadEdges,
+}
+
+impl<T: Coordinate> DivideAndConquer<'_, T> {
+    fn counterclockwise(&self, a: usize, b: usize, c: usize) -> bool {
+        orientation(self.points[a], self.points[b], self.points[c]) == Orientation::CounterClockwise
+    }
+
+    fn right_of(&self, point: usize, e: usize) -> bool {
+        self.counterclockwise(point, self.edges.dest(e), self.edges.org(e))
+    }
+
+    fn left_of(&self, point: usize, e: usize) -> bool {
+        self.counterclockwise(point, self.edges.org(e), self.edges.dest(e))
+    }
+
+    fn in_circle(&self, a: usize, b: usize, c: usize, d: usize) -> bool {
+        in_circle(self.points[a], self.points[b], self.points[c], self.points[d]) == Ordering::Greater
+    }
+
+    // Triangulates at least two distinct points sorted lexicographically and returns the
+    // counterclockwise hull edge out of the leftmost point and the clockwise one out of the rightmost.
+    fn triangulate(&mut self, order: &[usize]) -> (usize, usize) {
+        match *order {
+            [a, b] => {
+                let e = self.edges.make_edge(a, b);
+                return (e, QuadEdges::sym(e));
+            }
+            [a, b, c] => {
+                let first = self.edges.make_edge(a, b);
+                let second = self.edges.make_edge(b, c);
+                self.edges.splice(QuadEdges::sym(first), second);
+
+                return if self.counterclockwise(a, b, c) {
+                    self.edges.connect(second, first);
+                    (first, QuadEdges::sym(second))
+                } else if self.counterclockwise(a, c, b) {
+                    let third = self.edges.connect(second, first);
+                    (QuadEdges::sym(third), third)
+                } else {
+                    (first, QuadEdges::sym(second))
+                };
+            }
+            _ => {}
+        }
+
+        let (left, right) = order.split_at(order.len() / 2);
+        let (mut left_outer, mut left_inner) = self.triangulate(left);
+        let (mut right_inner, mut right_outer) = self.triangulate(right);
+
+        // Walks down to the lower common tangent of the two halves.
+        loop {
+            if self.left_of(self.edges.org(right_inner), left_inner) {
+                left_inner = self.edges.lnext(left_inner);
+            } else if self.right_of(self.edges.org(left_inner), right_inner) {
+                right_inner = self.edges.rprev(right_inner);
+            } else {
+                break;
+            }
+        }
+
+        let mut base = self.edges.connect(QuadEdges::sym(right_inner), left_inner);
+        if self.edges.org(left_inner) == self.edges.org(left_outer) {
+            left_outer = QuadEdges::sym(base);
+        }
+        if self.edges.org(right_inner) == self.edges.org(right_outer) {
+            right_outer = base;
+        }
+
+        // Zips the halves together upwards, deleting the edges the new ones leave non-Delaunay.
+        loop {
+            let valid = |this: &Self, e: usize| this.right_of(this.edges.dest(e), base);
+            let (base_from, base_to) = (self.edges.org(base), self.edges.dest(base));
+
+            let mut left_candidate = self.edges.onext(QuadEdges::sym(base));
+            if valid(self, left_candidate) {
+                while self.in_circle(base_to, base_from, self.edges.dest(left_candidate), self.edges.dest(self.edges.onext(left_candidate))) {
+                    let next = self.edges.onext(left_candidate);
+                    self.edges.delete(left_candidate);
+                    left_candidate = next;
+                }
+            }
+
+            let mut right_candidate = self.edges.oprev(base);
+            if valid(self, right_candidate) {
+                while self.in_circle(base_to, base_from, self.edges.dest(right_candidate), self.edges.dest(self.edges.oprev(right_candidate))) {
+                    let next = self.edges.oprev(right_candidate);
+                    self.edges.delete(right_candidate);
+                    right_candidate = next;
+                }
+            }
+
+            let (left_valid, right_valid) = (valid(self, left_candidate), valid(self, right_candidate));
+            if !left_valid && !right_valid {
+                break;
+            }
+
+            let take_right = !left_valid
+                || (right_valid
+                    && self.in_circle(
+                        self.edges.dest(left_candidate),
+                        self.edges.org(left_candidate),
+                        self.edges.org(right_candidate),
+                        self.edges.dest(right_candidate),
+                    ));
+            base = if take_right {
+                self.edges.connect(right_candidate, QuadEdges::sym(base))
+            } else {
+                self.edges.connect(QuadEdges::sym(base), QuadEdges::sym(left_candidate))
+            };
+        }
+
+        (left_outer, right_outer)
+    }
+
+    // Lists every bounded face, each a counterclockwise triangle.
+    fn triangles(&self) -> Vec<[usize; 3]> {
+        let edges = &self.edges;
+        let mut seen = vec![false; edges.onext.len()];
+        let mut triangles = Vec::new();
+
+        for e in (0..edges.onext.len()).filter(|&e| e % 2 == 0 && !edges.deleted[e]) {
+            if seen[e] {
+                continue;
+            }
+
+            let (second, third) = (edges.lnext(e), edges.lnext(edges.lnext(e)));
+            seen[e] = true;
+            seen[second] = true;
+            seen[third] = true;
+            let triangle = [edges.org(e), edges.org(second), edges.org(third)];
+            if edges.lnext(third) == e && self.counterclockwise(triangle[0], triangle[1], triangle[2]) {
+                triangles.push(triangle);
+            }
+        }
+
+        triangles
+    }
+}
+
+/// Computes the Delaunay triangulation of the points.
+///
+/// Note:
+///     This function uses the divide-and-conquer algorithm of Guibas and Stolfi: the points
+///     sorted by x are split in half, both halves are triangulated, and the two triangulations
+///     are merged along their lower common tangent upwards, deleting the edges whose triangles
+///     would contain a new vertex in their circumcircle. No point then lies inside the
+///     circumcircle of any triangle. Runs in O(n log n). Repeated points are triangulated once,
+///     under their first index, and cocircular points are split in an unspecified way.
+///
+/// Returns:
+///
+/// The triangulation, without triangles for fewer than three points or collinear points.
+pub fn delaunay<T: Coordinate>(points: &[Point<T>]) -> Triangulation {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&i, &j| points[i].lexicographic_cmp(&points[j]).then(i.cmp(&j)));
+    order.dedup_by(|i, j| points[*i] == points[*j]);
+    if order.len() < 3 {
+        return Triangulation::default();
+    }
+
+    let mut solver = DivideAndConquer { points, edges: QuadEdges { onext: Vec::new(), origin: Vec::new(), deleted: Vec::new() } };
+    solver.triangulate(&order);
+    Triangulation { triangles: solver.triangles() }
+}
+
+// Returns the center of the circle through the three points.
+fn circumcenter(a: Point<f64>, b: Point<f64>, c: Point<f64>) -> Point<f64> {
+    let (u, v) = (b - a, c - a);
+    let denominator = 2.0 * u.cross(v);
+    let offset = (v.perpendicular() * u.length_squared() - u.perpendicular() * v.length_squared()) * (1.0 / denominator);
+    a - offset
+}
+
+impl Triangulation {
+    /// Derives the Voronoi diagram of the triangulated points as the dual of the triangulation.
+    ///
+    /// Note:
+    ///     Every triangle gives a Voronoi vertex at its circumcenter, and the cell of a site
+    ///     joins the vertices of the triangles around it. Cells of sites on the hull are
+    ///     unbounded. Sites without triangles, such as repeated points or the points of a
+    ///     triangulation without triangles, get empty cells.
+    ///
+    /// Arguments:
+    ///
+    /// * `points`: The points the triangulation was computed from.
+    pub fn voronoi<T: Coordinate + ToPrimitive>(&self, points: &[Point<T>]) -> VoronoiDiagram {
+        let sites: Vec<Point<f64>> = points.iter().map(|p| p.to_f64()).collect();
+        let vertices = self.triangles.iter().map(|&[a, b, c]| circumcenter(sites[a], sites[b], sites[c])).collect();
+        let edges = edge_map(&self.triangles);
+
+        let mut incident = vec![None; points.len()];
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            for &vertex in triangle {
+                incident[vertex] = Some(index);
+            }
+        }
+
+        let outward = |from: usize, to: usize| -(sites[to] - sites[from]).perpendicular();
+        let cells = (0..points.len())
+            .map(|site| {
+                let Some(mut triangle) = incident[site] else {
+                    return VoronoiCell::default();
+                };
+
+                // Turn clockwise to the first triangle, which is past a hull edge unless the site is inside.
+                let mut bounded = false;
+                while let Some(&previous) = edges.get(&(after(self.triangles[triangle], site), site)) {
+                    triangle = previous;
+                    if Some(triangle) == incident[site] {
+                        bounded = true;
+                        break;
+                    }
+                }
+
+                let first = triangle;
+                let mut cell = VoronoiCell { vertices: vec![first], rays: None };
+                loop {
+                    let last = opposite(self.triangles[triangle], site, after(self.triangles[triangle], site));
+                    match edges.get(&(site, last)) {
+                        Some(&next) if next != first => {
+                            cell.vertices.push(next);
+                            triangle = next;
+                        }
+                        Some(_) => break,
+                        None => {
+                            let start = after(self.triangles[first], site);
+                            cell.rays = Some((outward(site, start), outward(last, site)));
+                            break;
+                        }
+                    }
+                }
+
+                debug_assert_eq!(bounded, cell.rays.is_none());
+                cell
+            })
+            .collect();
+
+        VoronoiDiagram { vertices, cells }
+    }
+}
+
+/// Computes the Voronoi diagram of the sites through their Delaunay triangulation.
+pub fn voronoi<T: Coordinate + ToPrimitive>(sites: &[Point<T>]) -> VoronoiDiagram {
+    delaunay(sites).voronoi(sites)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::convex_hull::convex_hull;
+    use num::Zero;
+    use rand::Rng;
+
+    fn points(coordinates: &[(i64, i64)]) -> Vec<Point<i64>> {
+        coordinates.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    fn assert_delaunay(points: &[Point<i64>], triangulation: &Triangulation) {
+        for &[a, b, c] in &triangulation.triangles {
+            assert_eq!(orientation(points[a], points[b], points[c]), Orientation::CounterClockwise);
+            for &d in points {
+                assert_ne!(in_circle(points[a], points[b], points[c], d), Ordering::Greater);
+            }
+        }
+    }
+
+    fn twice_area<T: Coordinate>(points: &[Point<T>], triangulation: &Triangulation) -> T::Wide {
+        triangulation.triangles.iter().fold(T::Wide::zero(), |area, &[a, b, c]| area + (points[b] - points[a]).cross(points[c] - points[a]))
+    }
+
+    #[test]
+    fn in_circle_should_be_exact_for_integers() {
+        let (a, b, c) = (Point::new(0i64, 0), Point::new(1 << 30, 0), Point::new(0, 1 << 30));
+
+        assert_eq!(in_circle(a, b, c, Point::new(1 << 29, 1 << 29)), Ordering::Greater);
+        assert_eq!(in_circle(a, b, c, Point::new(1 << 30, 1 << 30)), Ordering::Equal);
+        assert_eq!(in_circle(a, b, c, Point::new(1 << 30, (1 << 30) + 1)), Ordering::Less);
+        assert_eq!(in_circle(a, c, b, Point::new(1 << 29, 1 << 29)), Ordering::Less);
+    }
+
+    #[test]
+    fn square_should_be_split_into_two_triangles() {
+        let square = points(&[(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)]);
+
+        let triangulation = delaunay(&square[..4]);
+        let with_center = delaunay(&square);
+
+        assert_eq!(triangulation.triangles.len(), 2);
+        assert_eq!(twice_area(&square, &triangulation), 8);
+        assert_eq!(with_center.triangles.len(), 4);
+        assert!(with_center.triangles.iter().all(|triangle| triangle.contains(&4)));
+    }
+
+    #[test]
+    fn skinny_triangles_should_be_avoided() {
+        let kite = points(&[(0, 0), (10, -1), (20, 0), (10, 1)]);
+
+        let triangulation = delaunay(&kite);
+
+        assert_delaunay(&kite, &triangulation);
+        assert!(triangulation.triangles.iter().all(|triangle| triangle.contains(&1) && triangle.contains(&3)));
+    }
+
+    #[test]
+    fn degenerate_inputs_should_have_no_triangles() {
+        assert!(delaunay::<i64>(&[]).triangles.is_empty());
+        assert!(delaunay(&points(&[(0, 0), (1, 1)])).triangles.is_empty());
+        assert!(delaunay(&points(&[(0, 0), (3, 3), (1, 1), (2, 2), (1, 1)])).triangles.is_empty());
+    }
+
+    #[test]
+    fn repeated_points_should_be_triangulated_once() {
+        let points = points(&[(0, 0), (4, 0), (0, 4), (4, 0), (0, 0)]);
+
+        let triangulation = delaunay(&points);
+
+        assert_eq!(triangulation.triangles.len(), 1);
+        assert!(triangulation.triangles[0].iter().all(|&vertex| vertex < 3));
+    }
+
+    #[test]
+    fn random_triangulations_should_be_delaunay() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let n = rng.gen_range(3..60);
+            let points: Vec<Point<i64>> = (0..n).map(|_| Point::new(rng.gen_range(-20..20), rng.gen_range(-20..20))).collect();
+            let hull = convex_hull(&points);
+
+            let triangulation = delaunay(&points);
+
+            assert_delaunay(&points, &triangulation);
+            let hull_area: i128 = (0..hull.len()).map(|i| hull[i].to_vector().cross(hull[(i + 1) % hull.len()].to_vector())).sum();
+            assert_eq!(twice_area(&points, &triangulation), hull_area);
+        }
+    }
+
+    #[test]
+    fn grid_should_be_triangulated_despite_cocircular_points() {
+        let grid: Vec<Point<i64>> = (0..100).map(|i| Point::new(i % 10, i / 10)).collect();
+
+        let triangulation = delaunay(&grid);
+
+        assert_delaunay(&grid, &triangulation);
+        // Each of the 81 unit squares is split in two.
+        assert_eq!(triangulation.triangles.len(), 162);
+        assert_eq!(twice_area(&grid, &triangulation), 162);
+    }
+
+    #[test]
+    fn large_triangulation_should_be_delaunay() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<Point<i64>> = (0..1000).map(|_| Point::new(rng.gen_range(-1 << 28..1 << 28), rng.gen_range(-1 << 28..1 << 28))).collect();
+        let hull = convex_hull(&points);
+
+        let triangulation = delaunay(&points);
+
+        assert_delaunay(&points, &triangulation);
+        assert_eq!(triangulation.triangles.len(), 2 * points.len() - 2 - hull.len());
+    }
+
+    #[test]
+    fn float_triangulation_should_cover_hull() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<Point<f64>> = (0..200).map(|_| Point::new(rng.gen(), rng.gen())).collect();
+        let hull = convex_hull(&points);
+
+        let triangulation = delaunay(&points);
+
+        let hull_area: f64 = (0..hull.len()).map(|i| hull[i].to_vector().cross(hull[(i + 1) % hull.len()].to_vector())).sum();
+        assert!((twice_area(&points, &triangulation) - hull_area).abs() < 1e-9);
+    }
+
+    #[test]
+    fn voronoi_cells_of_square_should_meet_at_center() {
+        let square = points(&[(0, 0), (2, 0), (2, 2), (0, 2), (1, 5)]);
+
+        let diagram = voronoi(&square[..4]);
+
+        assert!(diagram.vertices.iter().all(|&vertex| vertex == Point::new(1.0, 1.0)));
+        assert!(diagram.cells.iter().all(|cell| !cell.vertices.is_empty() && cell.rays.is_some()));
+        let (into, out_of) = diagram.cells[0].rays.unwrap();
+        assert_eq!((into, out_of), (Vector::new(0.0, -2.0), Vector::new(-2.0, 0.0)));
+    }
+
+    #[test]
+    fn random_voronoi_vertices_should_be_nearest_to_their_sites() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..20 {
+            let sites: Vec<Point<i64>> = (0..rng.gen_range(3..40)).map(|_| Point::new(rng.gen_range(-50..50), rng.gen_range(-50..50))).collect();
+
+            let triangulation = delaunay(&sites);
+            let diagram = triangulation.voronoi(&sites);
+
+            for (site, cell) in diagram.cells.iter().enumerate() {
+                let triangulated = triangulation.triangles.iter().any(|triangle| triangle.contains(&site));
+                assert_eq!(cell.vertices.is_empty(), !triangulated);
+                for &vertex in &cell.vertices {
+                    let point = diagram.vertices[vertex];
+                    let nearest = sites.iter().map(|s| s.to_f64().distance_squared(point)).fold(f64::INFINITY, f64::min);
+                    assert!((sites[site].to_f64().distance_squared(point) - nearest).abs() < 1e-6 * (1.0 + nearest));
+                }
+            }
+        }
+    }
+}
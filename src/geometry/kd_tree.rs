@@ -0,0 +1,267 @@
+//! This module contains the k-d tree, answering nearest neighbor and box queries over points
+//! of any dimension.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::geometry::point::{Coordinate, Point};
+
+/// Point of a k-d tree found by a nearest neighbor query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbor<T> {
+    /// The index of the point in the points the tree was built from.
+    pub index: usize,
+    /// The squared distance to the query point, exact for integer coordinates.
+    pub distance_squared: T,
+}
+
+/// Static k-d tree over points with K coordinates.
+///
+/// Note:
+///     The tree splits the points at the median of one coordinate, cycling through the
+///     coordinates level by level. It is stored implicitly: the root of the points in
+///     `order[low..high]` is at the middle position, with the smaller half before it. Building
+///     takes O(n log n) with linear median selection. A nearest neighbor search descends
+///     towards the query first and skips every subtree farther than the best point found,
+///     taking O(log n) on typical inputs.
+#[derive(Debug, Clone)]
+pub struct KdTree<T, const K: usize> {
+    points: Vec<[T; K]>,
+    order: Vec<usize>,
+}
+
+fn compare<T: Coordinate>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).expect("Coordinates must be comparable")
+}
+
+fn distance_squared<T: Coordinate, const K: usize>(a: &[T; K], b: &[T; K]) -> T {
+    a.iter().zip(b).fold(T::zero(), |sum, (&x, &y)| sum + (x - y) * (x - y))
+}
+
+impl<T: Coordinate, const K: usize> KdTree<T, K> {
+    /// Builds the tree over the points.
+    ///
+    /// Panics when K is zero or a coordinate is NaN.
+    pub fn new(points: &[[T; K]]) -> Self {
+        assert!(K > 0, "Points must have at least one coordinate");
+
+        let mut tree = KdTree { points: points.to_vec(), order: (0..points.len()).collect() };
+        tree.build(0, points.len(), 0);
+        tree
+    }
+
+    fn build(&mut self, low: usize, high: usize, axis: usize) {
+        if high - low < 2 {
+            return;
+        }
+
+        let middle = low + (high - low) / 2;
+        let points = &self.points;
+        self.order[low..high].select_nth_unstable_by(middle - low, |&i, &j| compare(points[i][axis], points[j][axis]));
+
+        self.build(low, middle, (axis + 1) % K);
+        self.build(middle + 1, high, (axis + 1) % K);
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true when the tree has no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Finds the point closest to the query point.
+    ///
+    /// Returns:
+    ///
+    /// The nearest point, none when the tree is empty. Among points at the same distance any one may be returned.
+    pub fn nearest(&self, query: &[T; K]) -> Option<Neighbor<T>> {
+        self.k_nearest(query, 1).pop()
+    }
+
+    /// Finds the k points closest to the query point.
+    ///
+    /// Note: the k best points so far are kept sorted, and a subtree is skipped when the
+    /// splitting plane is not closer than the k-th best one. Runs in O(k log n) on typical inputs.
+    ///
+    /// Returns:
+    ///
+    /// Up to k nearest points by increasing distance.
+    pub fn k_nearest(&self, query: &[T; K], k: usize) -> Vec<Neighbor<T>> {
+        let mut best = Vec::with_capacity(k.min(self.len()) + 1);
+        if k > 0 {
+            self.search(query, k, 0, self.points.len(), 0, &mut best);
+        }
+        best
+    }
+
+    fn search(&self, query: &[T; K], k: usize, low: usize, high: usize, axis: usize, best: &mut Vec<Neighbor<T>>) {
+        if low >= high {
+            return;
+        }
+
+        let middle = low + (high - low) / 2;
+        let index = self.order[middle];
+        let point = &self.points[index];
+
+        let distance = distance_squared(query, point);
+        if best.len() < k || distance < best[k - 1].distance_squared {
+            let position = best.partition_point(|neighbor| neighbor.distance_squared <= distance);
+            best.insert(position, Neighbor { index, distance_squared: distance });
+            best.truncate(k);
+        }
+
+        let offset = query[axis] - point[axis];
+        let (near, far) = if offset < T::zero() { ((low, middle), (middle + 1, high)) } else { ((middle + 1, high), (low, middle)) };
+        let next_axis = (axis + 1) % K;
+
+        self.search(query, k, near.0, near.1, next_axis, best);
+        if best.len() < k || offset * offset < best[k - 1].distance_squared {
+            self.search(query, k, far.0, far.1, next_axis, best);
+        }
+    }
+
+    /// Finds the points inside an axis-aligned box, boundary included.
+    ///
+    /// Arguments:
+    ///
+    /// * `low`: The smallest value of every coordinate inside the box.
+    /// * `high`: The largest value of every coordinate inside the box.
+    ///
+    /// Returns:
+    ///
+    /// The indices of the points inside the box in increasing order.
+    pub fn range(&self, low: &[T; K], high: &[T; K]) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.collect_range(low, high, 0, self.points.len(), 0, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    fn collect_range(&self, low: &[T; K], high: &[T; K], from: usize, to: usize, axis: usize, found: &mut Vec<usize>) {
+        if from >= to {
+            return;
+        }
+
+        let middle = from + (to - from) / 2;
+        let index = self.order[middle];
+        let point = &self.points[index];
+        if (0..K).all(|i| low[i] <= point[i] && point[i] <= high[i]) {
+            found.push(index);
+        }
+
+        // Points before the middle have the coordinate not above the split, the ones after not below it.
+        let next_axis = (axis + 1) % K;
+        if low[axis] <= point[axis] {
+            self.collect_range(low, high, from, middle, next_axis, found);
+        }
+        if point[axis] <= high[axis] {
+            self.collect_range(low, high, middle + 1, to, next_axis, found);
+        }
+    }
+}
+
+impl<T: Coordinate> KdTree<T, 2> {
+    /// Builds the tree over points of the plane.
+    ///
+    /// Panics when a coordinate is NaN.
+    pub fn from_points(points: &[Point<T>]) -> Self {
+        Self::new(&points.iter().map(|point| [point.x, point.y]).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn brute_force<T: Coordinate, const K: usize>(points: &[[T; K]], query: &[T; K]) -> Vec<T> {
+        let mut distances: Vec<T> = points.iter().map(|point| distance_squared(point, query)).collect();
+        distances.sort_by(|&a, &b| compare(a, b));
+        distances
+    }
+
+    #[test]
+    fn nearest_point_should_match_brute_force_in_the_plane() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let points: Vec<Point<i64>> =
+                (0..rng.gen_range(1..60)).map(|_| Point::new(rng.gen_range(-20..20), rng.gen_range(-20..20))).collect();
+            let tree = KdTree::from_points(&points);
+
+            for _ in 0..20 {
+                let query = [rng.gen_range(-25..25), rng.gen_range(-25..25)];
+                let nearest = tree.nearest(&query).unwrap();
+
+                assert_eq!(nearest.distance_squared, brute_force(&tree.points, &query)[0]);
+                assert_eq!(points[nearest.index].distance_squared(Point::new(query[0], query[1])), nearest.distance_squared);
+            }
+        }
+    }
+
+    #[test]
+    fn k_nearest_points_should_match_brute_force_in_three_dimensions() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let points: Vec<[f64; 3]> =
+                (0..rng.gen_range(0..80)).map(|_| [rng.gen(), rng.gen(), rng.gen()]).collect();
+            let tree = KdTree::new(&points);
+            let query = [rng.gen(), rng.gen(), rng.gen()];
+            let k = rng.gen_range(0..10);
+
+            let found: Vec<f64> = tree.k_nearest(&query, k).iter().map(|neighbor| neighbor.distance_squared).collect();
+
+            let expected: Vec<f64> = brute_force(&points, &query).into_iter().take(k).collect();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn box_query_should_match_brute_force() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let points: Vec<[i32; 3]> = (0..rng.gen_range(0..100))
+                .map(|_| [rng.gen_range(0..10), rng.gen_range(0..10), rng.gen_range(0..10)])
+                .collect();
+            let tree = KdTree::new(&points);
+            let low = [rng.gen_range(0..10), rng.gen_range(0..10), rng.gen_range(0..10)];
+            let high = [low[0] + rng.gen_range(0..5), low[1] + rng.gen_range(0..5), low[2] + rng.gen_range(0..5)];
+
+            let expected: Vec<usize> =
+                (0..points.len()).filter(|&i| (0..3).all(|axis| low[axis] <= points[i][axis] && points[i][axis] <= high[axis])).collect();
+
+            assert_eq!(tree.range(&low, &high), expected);
+        }
+    }
+
+    #[test]
+    fn empty_tree_should_find_nothing() {
+        let tree: KdTree<i32, 2> = KdTree::new(&[]);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest(&[0, 0]), None);
+        assert_eq!(tree.range(&[0, 0], &[5, 5]), vec![]);
+    }
+
+    #[test]
+    fn duplicate_points_should_all_be_reported() {
+        let points = [[1, 1], [1, 1], [1, 1], [5, 5]];
+        let tree = KdTree::new(&points);
+
+        assert_eq!(tree.range(&[1, 1], &[1, 1]), vec![0, 1, 2]);
+        assert_eq!(tree.k_nearest(&[0, 0], 3).iter().map(|n| n.distance_squared).collect::<Vec<_>>(), vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn huge_k_should_return_every_point() {
+        let tree = KdTree::new(&[[3, 4]]);
+
+        assert_eq!(tree.k_nearest(&[0, 0], usize::MAX).iter().map(|n| n.distance_squared).collect::<Vec<_>>(), vec![25]);
+    }
+}
@@ -0,0 +1,21 @@
+pub mod point;
+pub mod convex_hull;
+pub mod segment;
+pub mod segment_sweep;
+pub mod closest_pair;
+pub mod polygon;
+pub mod rotating_calipers;
+pub mod delaunay;
+pub mod kd_tree;
+
+pub use point::{cross, orientation, Coordinate, Orientation, Point, Vector, EPSILON};
+pub use convex_hull::convex_hull;
+pub use segment::{segment_intersection, segments_intersect, Segment, SegmentIntersection};
+pub use segment_sweep::{segment_intersections, Intersection};
+pub use closest_pair::{closest_pair, closest_pair_sweep, ClosestPair};
+pub use polygon::{centroid, point_in_polygon_ray_casting, point_in_polygon_winding, polygon_area, signed_area_doubled, winding_number, Location};
+pub use rotating_calipers::{farthest_pair, BoundingRectangle, FarthestPair};
+#[cfg(feature = "std")]
+pub use rotating_calipers::{minimum_bounding_rectangle, minimum_width};
+pub use delaunay::{delaunay, in_circle, voronoi, Triangulation, VoronoiCell, VoronoiDiagram};
+pub use kd_tree::{KdTree, Neighbor};
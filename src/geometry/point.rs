@@ -0,0 +1,351 @@
+//! This module contains points, vectors and the orientation predicate of the plane.
+
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::{Signed, ToPrimitive};
+
+/// Relative tolerance of floating point predicates.
+///
+/// Note: a floating point cross product counts as zero when it is within this fraction
+/// of the product of the lengths it is computed from.
+pub const EPSILON: f64 = 1e-9;
+
+/// Numeric type of point coordinates.
+///
+/// Note: integer coordinates give exact predicates as long as coordinate differences fit
+/// into the type, since products are computed in `Wide`. Floating point predicates round
+/// values within `EPSILON` to zero.
+pub trait Coordinate: Signed + PartialOrd + Copy + Debug {
+    /// Type holding products of coordinate differences, four of them for the in-circle predicate.
+    type Wide: Coordinate + ToPrimitive;
+
+    /// Converts the value to the wide type.
+    fn widen(self) -> Self::Wide;
+
+    /// Returns true when a product of two differences is zero up to rounding.
+    ///
+    /// Note: exact types compare with zero and never compute the magnitude, which may
+    /// overflow even when the product itself does not.
+    ///
+    /// Arguments:
+    ///
+    /// * `magnitude`: Computes an upper bound of the absolute value of the product without cancellation.
+    fn is_negligible(self, magnitude: impl FnOnce() -> Self) -> bool {
+        let _ = magnitude;
+        self.is_zero()
+    }
+
+    /// Compares the value with zero, treating negligible values as zero.
+    fn sign_within(self, magnitude: impl FnOnce() -> Self) -> Ordering {
+        if self.is_negligible(magnitude) {
+            Ordering::Equal
+        } else if self.is_positive() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+macro_rules! impl_exact_coordinate {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                type Wide = $wide;
+
+                fn widen(self) -> $wide {
+                    self as $wide
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_coordinate!(i8 => i64, i16 => i128, i32 => i128, i64 => i128, i128 => i128, isize => i128);
+
+impl Coordinate for f32 {
+    type Wide = f64;
+
+    fn widen(self) -> f64 {
+        self as f64
+    }
+
+    fn is_negligible(self, magnitude: impl FnOnce() -> Self) -> bool {
+        self.abs() <= EPSILON as f32 * magnitude()
+    }
+}
+
+impl Coordinate for f64 {
+    type Wide = f64;
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn is_negligible(self, magnitude: impl FnOnce() -> Self) -> bool {
+        self.abs() <= EPSILON * magnitude()
+    }
+}
+
+/// Point of the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Displacement between two points of the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    /// Creates a point with the given coordinates.
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns the vector from the origin to the point.
+    pub fn to_vector(self) -> Vector<T> {
+        Vector::new(self.x, self.y)
+    }
+
+    /// Returns the squared Euclidean distance to another point, exact for integer coordinates.
+    pub fn distance_squared(self, other: Self) -> T {
+        (other - self).length_squared()
+    }
+
+    /// Compares points by x, then by y.
+    ///
+    /// Panics when a coordinate is NaN.
+    pub fn lexicographic_cmp(&self, other: &Self) -> Ordering {
+        (self.x, self.y).partial_cmp(&(other.x, other.y)).expect("Coordinates must be comparable")
+    }
+}
+
+impl<T: Coordinate + ToPrimitive> Point<T> {
+    /// Converts the coordinates to `f64`.
+    pub fn to_f64(self) -> Point<f64> {
+        Point::new(self.x.to_f64().unwrap(), self.y.to_f64().unwrap())
+    }
+
+    /// Returns the Euclidean distance to another point.
+    #[cfg(feature = "std")]
+    pub fn distance(self, other: Self) -> f64 {
+        self.distance_squared(other).to_f64().unwrap().sqrt()
+    }
+}
+
+impl<T: Coordinate> Vector<T> {
+    /// Creates a vector with the given components.
+    pub fn new(x: T, y: T) -> Self {
+        Vector { x, y }
+    }
+
+    /// Returns the dot product, positive when the vectors point the same way.
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the z component of the cross product, positive when the other vector turns counterclockwise.
+    ///
+    /// Note: computed in the wide type, so it is exact for any integer components.
+    pub fn cross(self, other: Self) -> T::Wide {
+        self.x.widen() * other.y.widen() - self.y.widen() * other.x.widen()
+    }
+
+    /// Returns the squared length.
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns the vector turned a quarter counterclockwise.
+    pub fn perpendicular(self) -> Self {
+        Vector::new(-self.y, self.x)
+    }
+
+    // Bounds the length from above without square roots.
+    fn manhattan_length(self) -> T {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+impl<T: Coordinate + ToPrimitive> Vector<T> {
+    /// Converts the components to `f64`.
+    pub fn to_f64(self) -> Vector<f64> {
+        Vector::new(self.x.to_f64().unwrap(), self.y.to_f64().unwrap())
+    }
+
+    /// Returns the Euclidean length.
+    #[cfg(feature = "std")]
+    pub fn length(self) -> f64 {
+        self.length_squared().to_f64().unwrap().sqrt()
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Self) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, vector: Vector<T>) -> Point<T> {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl<T: Coordinate> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, vector: Vector<T>) -> Point<T> {
+        Point::new(self.x - vector.x, self.y - vector.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, vector: Vector<T>) {
+        *self = *self + vector;
+    }
+}
+
+impl<T: Coordinate> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, vector: Vector<T>) {
+        *self = *self - vector;
+    }
+}
+
+impl<T: Coordinate> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, other: Self) -> Vector<T> {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Self) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, factor: T) -> Vector<T> {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Direction of the turn made by three points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    CounterClockwise,
+}
+
+/// Returns the cross product of `a - origin` and `b - origin`, twice the signed area of the triangle.
+pub fn cross<T: Coordinate>(origin: Point<T>, a: Point<T>, b: Point<T>) -> T::Wide {
+    (a - origin).cross(b - origin)
+}
+
+/// Tells whether going from a through b to c turns left, turns right or goes straight.
+///
+/// Note: the result is exact for integer coordinates. For floating point coordinates,
+/// turns within the relative tolerance `EPSILON` count as collinear.
+pub fn orientation<T: Coordinate>(a: Point<T>, b: Point<T>, c: Point<T>) -> Orientation {
+    let (u, v) = (b - a, c - a);
+
+    match u.cross(v).sign_within(|| u.manhattan_length().widen() * v.manhattan_length().widen()) {
+        Ordering::Greater => Orientation::CounterClockwise,
+        Ordering::Less => Orientation::Clockwise,
+        Ordering::Equal => Orientation::Collinear,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_orientation_should_be_exact() {
+        let (a, b) = (Point::new(0i64, 0), Point::new(1 << 30, 1 << 30));
+
+        assert_eq!(orientation(a, b, Point::new(1, 2)), Orientation::CounterClockwise);
+        assert_eq!(orientation(a, b, Point::new(2, 1)), Orientation::Clockwise);
+        assert_eq!(orientation(a, b, Point::new(-7, -7)), Orientation::Collinear);
+        assert_eq!(orientation(a, b, Point::new((1 << 30) - 1, 1 << 30)), Orientation::CounterClockwise);
+    }
+
+    #[test]
+    fn large_integer_orientation_should_not_overflow() {
+        let (a, b) = (Point::new(0i32, 0), Point::new(40_000, 40_000));
+
+        assert_eq!(orientation(a, b, Point::new(40_000, 40_000)), Orientation::Collinear);
+        assert_eq!(orientation(a, b, Point::new(40_000, 39_999)), Orientation::Clockwise);
+        assert_eq!(orientation(a, b, Point::new(-40_000, 0)), Orientation::CounterClockwise);
+    }
+
+    #[test]
+    fn wide_i64_orientation_should_not_overflow() {
+        let a = Point::new(0i64, 0);
+
+        assert_eq!(orientation(a, Point::new(1 << 40, 1), Point::new(1, 1 << 40)), Orientation::CounterClockwise);
+        assert_eq!(orientation(a, Point::new(i64::MAX, i64::MAX - 1), Point::new(i64::MAX - 1, i64::MAX - 2)), Orientation::Clockwise);
+        assert_eq!(orientation(a, Point::new(i64::MIN, i64::MIN), Point::new(i64::MAX, i64::MAX)), Orientation::Collinear);
+        assert_eq!(cross(a, Point::new(i64::MIN, 0), Point::new(0, i64::MIN)), 1 << 126);
+    }
+
+    #[test]
+    fn float_orientation_should_tolerate_rounding() {
+        let (a, b) = (Point::new(0.1, 0.1), Point::new(0.3, 0.3));
+        let c = Point::new(0.1 + 0.2 + 0.4, 0.7);
+
+        assert_eq!(orientation(a, b, c), Orientation::Collinear);
+        assert_eq!(orientation(a, b, Point::new(0.7, 0.7001)), Orientation::CounterClockwise);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn vector_arithmetic_should_be_consistent() {
+        let (p, q) = (Point::new(1, 2), Point::new(4, 6));
+        let v = q - p;
+
+        assert_eq!(v, Vector::new(3, 4));
+        assert_eq!(p + v, q);
+        assert_eq!(q - v, p);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(p.distance_squared(q), 25);
+        assert_eq!(v.dot(v.perpendicular()), 0);
+        assert_eq!(v.cross(v.perpendicular()), 25i128);
+        assert_eq!(-v * 2, Vector::new(-6, -8));
+    }
+
+    #[test]
+    fn points_should_be_compared_lexicographically() {
+        let mut points = vec![Point::new(2, 1), Point::new(1, 5), Point::new(2, 0)];
+
+        points.sort_by(Point::lexicographic_cmp);
+
+        assert_eq!(points, vec![Point::new(1, 5), Point::new(2, 0), Point::new(2, 1)]);
+    }
+}
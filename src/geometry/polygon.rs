@@ -0,0 +1,219 @@
+//! This module contains the area, centroid and point location of simple polygons.
+
+use num::{Signed, ToPrimitive, Zero};
+
+use crate::geometry::point::{orientation, Coordinate, Orientation, Point};
+use crate::geometry::segment::Segment;
+
+/// Position of a point relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+fn edges<T: Copy>(polygon: &[Point<T>]) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+    polygon.iter().copied().zip(polygon.iter().copied().cycle().skip(1))
+}
+
+/// Returns twice the signed area with the shoelace formula, exact for integer coordinates.
+///
+/// Note: the area is positive when the vertices go counterclockwise and negative when they
+/// go clockwise. The polygon is closed implicitly, the last vertex connects to the first.
+/// Products are computed in `Coordinate::Wide`, which the sum has to fit into.
+pub fn signed_area_doubled<T: Coordinate>(polygon: &[Point<T>]) -> T::Wide {
+    edges(polygon).fold(T::Wide::zero(), |area, (a, b)| area + a.to_vector().cross(b.to_vector()))
+}
+
+/// Returns the area of a simple polygon given by its vertices in either order.
+pub fn polygon_area<T: Coordinate + ToPrimitive>(polygon: &[Point<T>]) -> f64 {
+    signed_area_doubled(polygon).abs().to_f64().unwrap() / 2.0
+}
+
+/// Returns the center of mass of the region enclosed by a simple polygon.
+///
+/// Note: every edge adds the triangle it forms with the origin, weighted by its signed area.
+///
+/// Returns:
+///
+/// The centroid, `None` when the polygon encloses no area.
+pub fn centroid<T: Coordinate + ToPrimitive>(polygon: &[Point<T>]) -> Option<Point<f64>> {
+    let doubled_area = signed_area_doubled(polygon);
+    if doubled_area.is_zero() {
+        return None;
+    }
+
+    let (mut x, mut y) = (0.0, 0.0);
+    for (a, b) in edges(polygon) {
+        let (a, b) = (a.to_f64(), b.to_f64());
+        let weight = a.x * b.y - b.x * a.y;
+        x += (a.x + b.x) * weight;
+        y += (a.y + b.y) * weight;
+    }
+
+    let scale = 3.0 * doubled_area.to_f64().unwrap();
+    Some(Point::new(x / scale, y / scale))
+}
+
+fn on_boundary<T: Coordinate>(polygon: &[Point<T>], point: Point<T>) -> bool {
+    edges(polygon).any(|(a, b)| Segment::new(a, b).contains(point))
+}
+
+/// Locates a point with the even-odd rule by casting a horizontal ray to the right.
+///
+/// Note:
+///     Every edge crossing the ray toggles between inside and outside. An edge counts when
+///     exactly one endpoint lies strictly above the ray, so a ray through a vertex counts
+///     it once. Points on an edge or vertex are reported as on the boundary. Runs in O(n).
+pub fn point_in_polygon_ray_casting<T: Coordinate>(polygon: &[Point<T>], point: Point<T>) -> Location {
+    if on_boundary(polygon, point) {
+        return Location::OnBoundary;
+    }
+
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        if (a.y > point.y) != (b.y > point.y) {
+            // Looking along the edge upwards, the crossing is right of the point when the point is on the left.
+            let (low, high) = if a.y < b.y { (a, b) } else { (b, a) };
+            if orientation(low, high, point) == Orientation::CounterClockwise {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Returns how many times the polygon winds counterclockwise around the point.
+///
+/// Note: clockwise turns count negatively. The result is meaningless for points on the boundary.
+pub fn winding_number<T: Coordinate>(polygon: &[Point<T>], point: Point<T>) -> i64 {
+    let mut winding = 0;
+    for (a, b) in edges(polygon) {
+        if a.y <= point.y {
+            if b.y > point.y && orientation(a, b, point) == Orientation::CounterClockwise {
+                winding += 1;
+            }
+        } else if b.y <= point.y && orientation(a, b, point) == Orientation::Clockwise {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
+
+/// Locates a point with the nonzero winding rule.
+///
+/// Note: for simple polygons this agrees with ray casting. For self-intersecting ones,
+/// regions wound around twice count as inside here but as outside with the even-odd rule.
+/// Points on an edge or vertex are reported as on the boundary. Runs in O(n).
+pub fn point_in_polygon_winding<T: Coordinate>(polygon: &[Point<T>], point: Point<T>) -> Location {
+    if on_boundary(polygon, point) {
+        Location::OnBoundary
+    } else if winding_number(polygon, point) != 0 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Vec<Point<i64>> {
+        vertices.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn area_should_follow_orientation() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        let reversed: Vec<_> = square.iter().rev().copied().collect();
+
+        assert_eq!(signed_area_doubled(&square), 32);
+        assert_eq!(signed_area_doubled(&reversed), -32);
+        assert_eq!(polygon_area(&reversed), 16.0);
+        assert_eq!(polygon_area::<i64>(&[]), 0.0);
+    }
+
+    #[test]
+    fn large_integer_area_should_not_overflow() {
+        let side = 1 << 40;
+        let square = polygon(&[(0, 0), (side, 0), (side, side), (0, side)]);
+
+        assert_eq!(signed_area_doubled(&square), 2 * (side as i128).pow(2));
+    }
+
+    #[test]
+    fn centroid_should_be_center_of_mass() {
+        let l_shape = polygon(&[(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)]);
+
+        let center = centroid(&l_shape).unwrap();
+
+        assert!((center.x - 5.0 / 6.0).abs() < 1e-12 && (center.y - 5.0 / 6.0).abs() < 1e-12);
+        assert_eq!(centroid(&polygon(&[(0, 0), (1, 1), (2, 2)])), None);
+    }
+
+    #[test]
+    fn points_should_be_located_in_concave_polygon() {
+        // A square with a notch cut down from the top edge.
+        let notched = polygon(&[(0, 0), (6, 0), (6, 6), (4, 6), (3, 2), (2, 6), (0, 6)]);
+
+        for locate in [point_in_polygon_ray_casting::<i64>, point_in_polygon_winding::<i64>] {
+            assert_eq!(locate(&notched, Point::new(1, 1)), Location::Inside);
+            assert_eq!(locate(&notched, Point::new(3, 5)), Location::Outside);
+            assert_eq!(locate(&notched, Point::new(3, 2)), Location::OnBoundary);
+            assert_eq!(locate(&notched, Point::new(6, 3)), Location::OnBoundary);
+            assert_eq!(locate(&notched, Point::new(1, 6)), Location::OnBoundary);
+            assert_eq!(locate(&notched, Point::new(-1, 0)), Location::Outside);
+            assert_eq!(locate(&notched, Point::new(1, 2)), Location::Inside);
+        }
+    }
+
+    #[test]
+    fn rules_should_differ_on_doubly_wound_regions() {
+        // A pentagram wraps its center twice.
+        let star = polygon(&[(0, 10), (6, -8), (-10, 3), (10, 3), (-6, -8)]);
+        let center = Point::new(0, 0);
+
+        assert_eq!(winding_number(&star, center).abs(), 2);
+        assert_eq!(point_in_polygon_winding(&star, center), Location::Inside);
+        assert_eq!(point_in_polygon_ray_casting(&star, center), Location::Outside);
+    }
+
+    #[test]
+    fn methods_should_agree_on_random_convex_polygons() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let points: Vec<Point<i64>> = (0..20).map(|_| Point::new(rng.gen_range(-10..10), rng.gen_range(-10..10))).collect();
+            let hull = crate::geometry::convex_hull(&points);
+            if hull.len() < 3 {
+                continue;
+            }
+
+            for _ in 0..50 {
+                let point = Point::new(rng.gen_range(-12..12), rng.gen_range(-12..12));
+
+                // On a counterclockwise convex polygon, inside points are left of every edge.
+                let turns: Vec<Orientation> = edges(&hull).map(|(a, b)| orientation(a, b, point)).collect();
+                let expected = if turns.contains(&Orientation::Clockwise) {
+                    Location::Outside
+                } else if turns.contains(&Orientation::Collinear) && on_boundary(&hull, point) {
+                    Location::OnBoundary
+                } else {
+                    Location::Inside
+                };
+
+                assert_eq!(point_in_polygon_ray_casting(&hull, point), expected);
+                assert_eq!(point_in_polygon_winding(&hull, point), expected);
+            }
+        }
+    }
+}
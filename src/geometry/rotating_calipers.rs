@@ -0,0 +1,263 @@
+//! This module contains rotating calipers over the convex hull: the diameter, the width
+//! and the minimum-area bounding rectangle of a set of points.
+
+use num::{Signed, ToPrimitive};
+
+use crate::geometry::convex_hull::convex_hull;
+use crate::geometry::point::{Coordinate, Point};
+
+/// Two points at the largest distance among a set of points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FarthestPair<T> {
+    pub points: (Point<T>, Point<T>),
+    /// The squared distance between the points, exact for integer coordinates.
+    pub distance_squared: T,
+}
+
+impl<T: Coordinate + ToPrimitive> FarthestPair<T> {
+    /// Returns the distance between the points, the diameter of the set.
+    #[cfg(feature = "std")]
+    pub fn distance(&self) -> f64 {
+        self.distance_squared.to_f64().unwrap().sqrt()
+    }
+}
+
+/// Rectangle enclosing a set of points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingRectangle {
+    /// The corners in counterclockwise order.
+    pub corners: [Point<f64>; 4],
+    pub area: f64,
+}
+
+// Index of the hull vertex following the given one.
+fn next(hull_len: usize, index: usize) -> usize {
+    (index + 1) % hull_len
+}
+
+/// Finds the two points farthest apart.
+///
+/// Note:
+///     The farthest points are vertices of the convex hull lying on parallel supporting
+///     lines. Turning a pair of such lines around the hull visits every antipodal pair of
+///     vertices in O(n), after the O(n log n) hull.
+///
+/// Returns:
+///
+/// The farthest pair, `None` for no points. A single point is paired with itself.
+pub fn farthest_pair<T: Coordinate>(points: &[Point<T>]) -> Option<FarthestPair<T>> {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    let first = *hull.first()?;
+
+    let mut best = FarthestPair { points: (first, first), distance_squared: T::zero() };
+    let mut consider = |a: Point<T>, b: Point<T>| {
+        let distance_squared = a.distance_squared(b);
+        if distance_squared > best.distance_squared {
+            best = FarthestPair { points: (a, b), distance_squared };
+        }
+    };
+
+    let mut j = next(n, 0);
+    for i in 0..n {
+        let edge = hull[next(n, i)] - hull[i];
+        // The opposite vertex moves on while it gets farther from the edge line.
+        while edge.cross(hull[next(n, j)] - hull[j]).is_positive() {
+            j = next(n, j);
+        }
+
+        consider(hull[i], hull[j]);
+        consider(hull[next(n, i)], hull[j]);
+    }
+
+    Some(best)
+}
+
+/// Returns the smallest distance between two parallel lines enclosing all points.
+///
+/// Note: the narrowest strip has one side along a hull edge, and its width is the distance
+/// from that edge to the farthest vertex. Runs in O(n log n). The width is 0 for fewer than
+/// three points or collinear points. Needs the `std` feature.
+#[cfg(feature = "std")]
+pub fn minimum_width<T: Coordinate + ToPrimitive>(points: &[Point<T>]) -> f64 {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    if n < 3 {
+        return 0.0;
+    }
+
+    let mut width = f64::INFINITY;
+    let mut j = next(n, 0);
+    for i in 0..n {
+        let edge = hull[next(n, i)] - hull[i];
+        while edge.cross(hull[next(n, j)] - hull[j]).is_positive() {
+            j = next(n, j);
+        }
+
+        let height = edge.cross(hull[j] - hull[i]).to_f64().unwrap() / edge.length();
+        width = width.min(height);
+    }
+
+    width
+}
+
+/// Finds the rectangle of smallest area enclosing all points.
+///
+/// Note:
+///     The smallest rectangle has a side along a hull edge. For every edge, the vertices
+///     extreme along it, opposite to it and behind it are tracked with calipers that only
+///     move forward, so all edges are handled in O(n) after the O(n log n) hull.
+///
+/// Returns:
+///
+/// The rectangle, `None` for no points. Degenerate point sets give a rectangle of zero area.
+/// Needs the `std` feature.
+#[cfg(feature = "std")]
+pub fn minimum_bounding_rectangle<T: Coordinate + ToPrimitive>(points: &[Point<T>]) -> Option<BoundingRectangle> {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    let first = hull.first()?.to_f64();
+    if n == 1 {
+        return Some(BoundingRectangle { corners: [first; 4], area: 0.0 });
+    }
+
+    let mut best: Option<BoundingRectangle> = None;
+    // Vertices farthest forward along the edge, farthest from it and farthest backward.
+    let (mut front, mut top, mut back) = (next(n, 0), next(n, 0), next(n, 0));
+
+    for i in 0..n {
+        let edge = hull[next(n, i)] - hull[i];
+        let step = |index: usize| hull[next(n, index)] - hull[index];
+
+        while edge.dot(step(front)) > T::zero() {
+            front = next(n, front);
+        }
+        if i == 0 {
+            top = front;
+        }
+        while edge.cross(step(top)).is_positive() {
+            top = next(n, top);
+        }
+        if i == 0 {
+            back = top;
+        }
+        while edge.dot(step(back)) < T::zero() {
+            back = next(n, back);
+        }
+
+        let origin = hull[i].to_f64();
+        let length = edge.length();
+        let along = edge.to_f64() * (1.0 / length);
+        let across = along.perpendicular();
+        let offset = |index: usize| hull[index].to_f64() - origin;
+
+        let (start, end) = (offset(back).dot(along), offset(front).dot(along));
+        let height = offset(top).dot(across);
+        let area = (end - start) * height;
+
+        if best.is_none_or(|best| area < best.area) {
+            let corner = |forward: f64, up: f64| origin + along * forward + across * up;
+            best = Some(BoundingRectangle {
+                corners: [corner(start, 0.0), corner(end, 0.0), corner(end, height), corner(start, height)],
+                area,
+            });
+        }
+    }
+
+    best
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::geometry::point::Vector;
+    use rand::Rng;
+
+    fn points(coordinates: &[(i64, i64)]) -> Vec<Point<i64>> {
+        coordinates.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn farthest_pair_should_be_found() {
+        let set = points(&[(0, 0), (2, 1), (5, 0), (3, 4), (1, 3), (2, 2)]);
+
+        let farthest = farthest_pair(&set).unwrap();
+
+        assert_eq!(farthest.distance_squared, 25);
+        assert_eq!(farthest.distance(), 5.0);
+    }
+
+    #[test]
+    fn width_of_rectangle_should_be_its_short_side() {
+        let set = points(&[(0, 0), (10, 0), (10, 3), (0, 3), (5, 1)]);
+
+        assert!((minimum_width(&set) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotated_square_should_be_its_own_bounding_rectangle() {
+        let diamond = points(&[(0, -2), (2, 0), (0, 2), (-2, 0), (0, 0), (1, 0)]);
+
+        let rectangle = minimum_bounding_rectangle(&diamond).unwrap();
+
+        assert!((rectangle.area - 8.0).abs() < 1e-12);
+        for corner in rectangle.corners {
+            assert!(diamond.iter().any(|p| (p.to_f64() - corner).length() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn degenerate_sets_should_be_handled() {
+        let single = points(&[(3, 3), (3, 3)]);
+        let collinear = points(&[(0, 0), (1, 1), (3, 3)]);
+
+        assert_eq!(farthest_pair(&single).unwrap().distance_squared, 0);
+        assert_eq!(farthest_pair(&collinear).unwrap().distance_squared, 18);
+        assert_eq!(minimum_width(&collinear), 0.0);
+        assert_eq!(minimum_bounding_rectangle(&collinear).unwrap().area, 0.0);
+        assert_eq!(minimum_bounding_rectangle(&single).unwrap().corners, [Point::new(3.0, 3.0); 4]);
+        assert_eq!(farthest_pair::<i64>(&[]), None);
+    }
+
+    #[test]
+    fn calipers_should_match_brute_force_on_random_points() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let set: Vec<Point<i64>> =
+                (0..rng.gen_range(3..40)).map(|_| Point::new(rng.gen_range(-50..50), rng.gen_range(-50..50))).collect();
+            let hull = convex_hull(&set);
+            if hull.len() < 3 {
+                continue;
+            }
+
+            let diameter = set.iter().flat_map(|a| set.iter().map(move |b| a.distance_squared(*b))).max().unwrap();
+            assert_eq!(farthest_pair(&set).unwrap().distance_squared, diameter);
+
+            // Every hull edge gives a candidate strip and rectangle.
+            let (mut width, mut area) = (f64::INFINITY, f64::INFINITY);
+            for i in 0..hull.len() {
+                let (a, b) = (hull[i].to_f64(), hull[(i + 1) % hull.len()].to_f64());
+                let along = (b - a) * (1.0 / (b - a).length());
+                let projections = |direction: Vector<f64>| hull.iter().map(move |p| (p.to_f64() - a).dot(direction));
+                let (low, high) =
+                    projections(along).fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), v| (low.min(v), high.max(v)));
+                let height = projections(along.perpendicular()).fold(0.0, f64::max);
+                width = width.min(height);
+                area = area.min((high - low) * height);
+            }
+
+            assert!((minimum_width(&set) - width).abs() < 1e-9);
+            let rectangle = minimum_bounding_rectangle(&set).unwrap();
+            assert!((rectangle.area - area).abs() < 1e-6);
+            let covered = |p: &Point<i64>| {
+                let p = p.to_f64();
+                (0..4).all(|k| {
+                    let (c, d) = (rectangle.corners[k], rectangle.corners[(k + 1) % 4]);
+                    (d - c).cross(p - c) >= -1e-9
+                })
+            };
+            assert!(set.iter().all(covered));
+        }
+    }
+}
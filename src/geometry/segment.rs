@@ -0,0 +1,183 @@
+//! This module contains line segments and their pairwise intersection.
+
+use num::ToPrimitive;
+
+use crate::geometry::point::{orientation, Coordinate, Orientation, Point, Vector};
+
+/// Closed line segment between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment<T> {
+    pub a: Point<T>,
+    pub b: Point<T>,
+}
+
+impl<T: Coordinate> Segment<T> {
+    /// Creates the segment between two points.
+    pub fn new(a: Point<T>, b: Point<T>) -> Self {
+        Segment { a, b }
+    }
+
+    /// Returns the vector from the first endpoint to the second.
+    pub fn direction(&self) -> Vector<T> {
+        self.b - self.a
+    }
+
+    /// Returns true when the segment has both endpoints at the same place.
+    pub fn is_degenerate(&self) -> bool {
+        self.a == self.b
+    }
+
+    /// Returns true when the point lies on the segment, endpoints included.
+    pub fn contains(&self, point: Point<T>) -> bool {
+        orientation(self.a, self.b, point) == Orientation::Collinear && self.bounds(point)
+    }
+
+    // Tells whether a point on the line of the segment lies between its endpoints.
+    fn bounds(&self, point: Point<T>) -> bool {
+        let between = |value: T, a: T, b: T| (a <= value && value <= b) || (b <= value && value <= a);
+
+        between(point.x, self.a.x, self.b.x) && between(point.y, self.a.y, self.b.y)
+    }
+
+    // Returns the endpoints ordered by x, then y.
+    fn ordered(&self) -> (Point<T>, Point<T>) {
+        if self.a.lexicographic_cmp(&self.b).is_le() {
+            (self.a, self.b)
+        } else {
+            (self.b, self.a)
+        }
+    }
+}
+
+/// Common part of two segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection<T> {
+    /// The segments meet at a single point.
+    Point(Point<f64>),
+    /// The segments are collinear and share a piece of positive length, given with its endpoints ordered by x, then y.
+    Overlap(Segment<T>),
+}
+
+/// Returns true when the segments share at least one point, endpoints included.
+///
+/// Note: collinear segments intersect when they overlap or touch. The result is exact for
+/// integer coordinates, see `orientation` for floating point ones.
+pub fn segments_intersect<T: Coordinate>(s: Segment<T>, t: Segment<T>) -> bool {
+    let (o1, o2) = (orientation(s.a, s.b, t.a), orientation(s.a, s.b, t.b));
+    let (o3, o4) = (orientation(t.a, t.b, s.a), orientation(t.a, t.b, s.b));
+
+    let opposite = |p: Orientation, q: Orientation| p != q && p != Orientation::Collinear && q != Orientation::Collinear;
+    if opposite(o1, o2) && opposite(o3, o4) {
+        return true;
+    }
+
+    (o1 == Orientation::Collinear && s.bounds(t.a))
+        || (o2 == Orientation::Collinear && s.bounds(t.b))
+        || (o3 == Orientation::Collinear && t.bounds(s.a))
+        || (o4 == Orientation::Collinear && t.bounds(s.b))
+}
+
+/// Computes the common part of two segments.
+///
+/// Note: crossing points are computed in `f64`. When an endpoint of one segment lies on
+/// the other, that endpoint is returned exactly.
+///
+/// Returns:
+///
+/// The intersection point or the overlapping piece, `None` when the segments are disjoint.
+pub fn segment_intersection<T: Coordinate + ToPrimitive>(s: Segment<T>, t: Segment<T>) -> Option<SegmentIntersection<T>> {
+    let orientations = [
+        orientation(s.a, s.b, t.a),
+        orientation(s.a, s.b, t.b),
+        orientation(t.a, t.b, s.a),
+        orientation(t.a, t.b, s.b),
+    ];
+
+    if orientations.iter().all(|&o| o == Orientation::Collinear) {
+        let ((s_low, s_high), (t_low, t_high)) = (s.ordered(), t.ordered());
+        let low = if s_low.lexicographic_cmp(&t_low).is_ge() { s_low } else { t_low };
+        let high = if s_high.lexicographic_cmp(&t_high).is_le() { s_high } else { t_high };
+
+        return match low.lexicographic_cmp(&high) {
+            core::cmp::Ordering::Greater => None,
+            core::cmp::Ordering::Equal => Some(SegmentIntersection::Point(low.to_f64())),
+            core::cmp::Ordering::Less => Some(SegmentIntersection::Overlap(Segment::new(low, high))),
+        };
+    }
+
+    if !segments_intersect(s, t) {
+        return None;
+    }
+
+    let touching = [(orientations[0], t.a), (orientations[1], t.b), (orientations[2], s.a), (orientations[3], s.b)];
+    if let Some(&(_, endpoint)) = touching.iter().find(|(o, _)| *o == Orientation::Collinear) {
+        return Some(SegmentIntersection::Point(endpoint.to_f64()));
+    }
+
+    let (a, d1, d2) = (s.a.to_f64(), (s.b - s.a).to_f64(), (t.b - t.a).to_f64());
+    let along = (t.a.to_f64() - a).cross(d2) / d1.cross(d2);
+
+    Some(SegmentIntersection::Point(a + d1 * along))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(ax: i64, ay: i64, bx: i64, by: i64) -> Segment<i64> {
+        Segment::new(Point::new(ax, ay), Point::new(bx, by))
+    }
+
+    #[test]
+    fn crossing_segments_should_intersect_in_one_point() {
+        let (s, t) = (segment(0, 0, 4, 4), segment(0, 4, 4, 0));
+
+        assert!(segments_intersect(s, t));
+        assert_eq!(segment_intersection(s, t), Some(SegmentIntersection::Point(Point::new(2.0, 2.0))));
+    }
+
+    #[test]
+    fn touching_segments_should_meet_at_the_endpoint() {
+        let (s, t) = (segment(0, 0, 4, 0), segment(2, 0, 3, 5));
+
+        assert!(segments_intersect(s, t));
+        assert_eq!(segment_intersection(s, t), Some(SegmentIntersection::Point(Point::new(2.0, 0.0))));
+    }
+
+    #[test]
+    fn collinear_segments_should_overlap_touch_or_miss() {
+        let s = segment(0, 0, 4, 2);
+
+        assert_eq!(segment_intersection(s, segment(6, 3, 2, 1)), Some(SegmentIntersection::Overlap(segment(2, 1, 4, 2))));
+        assert_eq!(segment_intersection(s, segment(4, 2, 8, 4)), Some(SegmentIntersection::Point(Point::new(4.0, 2.0))));
+        assert_eq!(segment_intersection(s, segment(6, 3, 8, 4)), None);
+        assert!(!segments_intersect(s, segment(6, 3, 8, 4)));
+    }
+
+    #[test]
+    fn parallel_and_separated_segments_should_not_intersect() {
+        let s = segment(0, 0, 4, 0);
+
+        assert!(!segments_intersect(s, segment(0, 1, 4, 1)));
+        assert!(!segments_intersect(s, segment(5, -1, 5, 1)));
+        assert_eq!(segment_intersection(s, segment(1, 1, 3, 5)), None);
+    }
+
+    #[test]
+    fn degenerate_segments_should_act_as_points() {
+        let s = segment(0, 0, 4, 4);
+
+        assert!(segments_intersect(s, segment(1, 1, 1, 1)));
+        assert!(!segments_intersect(s, segment(1, 2, 1, 2)));
+        assert_eq!(segment_intersection(s, segment(3, 3, 3, 3)), Some(SegmentIntersection::Point(Point::new(3.0, 3.0))));
+        assert!(segment(2, 2, 2, 2).contains(Point::new(2, 2)));
+    }
+
+    #[test]
+    fn float_segments_should_be_supported() {
+        let s = Segment::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        let t = Segment::new(Point::new(0.0, 1.0), Point::new(1.0, 0.0));
+
+        assert_eq!(segment_intersection(s, t), Some(SegmentIntersection::Point(Point::new(0.5, 0.5))));
+    }
+}
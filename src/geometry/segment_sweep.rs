@@ -0,0 +1,369 @@
+//! This module contains the Bentley–Ottmann sweep reporting all intersections among segments.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use num::rational::Ratio;
+use num::{Signed, ToPrimitive, Zero};
+
+use crate::geometry::point::{Coordinate, Point};
+use crate::geometry::segment::Segment;
+use crate::structures::order_statistic_tree::splitmix64;
+
+/// Point where two or more segments meet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intersection {
+    /// The meeting point.
+    pub point: Point<f64>,
+    /// The indices of all segments through the point, in increasing order.
+    pub segments: Vec<usize>,
+}
+
+type Rational = Ratio<i128>;
+
+// Points are compared by x, then y, which is the order the sweep line visits them.
+type SweepPoint = (Rational, Rational);
+
+// A segment with its endpoints ordered by x, then y.
+#[derive(Debug, Clone, Copy)]
+struct Oriented {
+    from: (i128, i128),
+    to: (i128, i128),
+}
+
+impl Oriented {
+    fn direction(&self) -> (i128, i128) {
+        (self.to.0 - self.from.0, self.to.1 - self.from.1)
+    }
+
+    fn is_degenerate(&self) -> bool {
+        self.from == self.to
+    }
+
+    fn start(&self) -> SweepPoint {
+        (Rational::from_integer(self.from.0), Rational::from_integer(self.from.1))
+    }
+
+    fn end(&self) -> SweepPoint {
+        (Rational::from_integer(self.to.0), Rational::from_integer(self.to.1))
+    }
+
+    // Where the segment lies relative to a point on the sweep line: below it, through it or above it.
+    fn side(&self, point: &SweepPoint) -> Ordering {
+        let (dx, dy) = self.direction();
+        let relative = (point.0 - self.from.0, point.1 - self.from.1);
+        let turn = relative.1 * dx - relative.0 * dy;
+
+        if turn.is_zero() {
+            Ordering::Equal
+        } else if turn.is_positive() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+fn cross(u: (i128, i128), v: (i128, i128)) -> i128 {
+    u.0 * v.1 - u.1 * v.0
+}
+
+// Returns the single crossing point of two non-parallel segments.
+fn crossing(s: &Oriented, t: &Oriented) -> Option<SweepPoint> {
+    let (d1, d2) = (s.direction(), t.direction());
+    let denominator = cross(d1, d2);
+    if denominator == 0 {
+        return None;
+    }
+
+    let offset = (t.from.0 - s.from.0, t.from.1 - s.from.1);
+    let along_s = Rational::new(cross(offset, d2), denominator);
+    let along_t = Rational::new(cross(offset, d1), denominator);
+    let unit = Rational::from_integer(1);
+    if along_s < Rational::zero() || along_s > unit || along_t < Rational::zero() || along_t > unit {
+        return None;
+    }
+
+    Some((along_s * d1.0 + s.from.0, along_s * d1.1 + s.from.1))
+}
+
+// Segments crossed by the sweep line from bottom to top, as a treap ordered by position only,
+// since their order is only defined relative to the sweep line.
+type Status = Option<Box<StatusNode>>;
+
+#[derive(Debug)]
+struct StatusNode {
+    segment: usize,
+    priority: u64,
+    left: Status,
+    right: Status,
+}
+
+fn leaf(segment: usize) -> Status {
+    Some(Box::new(StatusNode { segment, priority: splitmix64(segment as u64), left: None, right: None }))
+}
+
+// Joins two trees, every segment of the first one being below every segment of the second one.
+fn merge(first: Status, second: Status) -> Status {
+    match (first, second) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut first), Some(mut second)) => {
+            if first.priority > second.priority {
+                first.right = merge(first.right.take(), Some(second));
+                Some(first)
+            } else {
+                second.left = merge(Some(first), second.left.take());
+                Some(second)
+            }
+        }
+    }
+}
+
+// Splits off the segments for which the predicate holds, which must come first in the order.
+fn split(tree: Status, below: &impl Fn(usize) -> bool) -> (Status, Status) {
+    let Some(mut node) = tree else {
+        return (None, None);
+    };
+
+    if below(node.segment) {
+        let (lower, upper) = split(node.right.take(), below);
+        node.right = lower;
+        (Some(node), upper)
+    } else {
+        let (lower, upper) = split(node.left.take(), below);
+        node.left = upper;
+        (lower, Some(node))
+    }
+}
+
+fn into_segments(tree: Status, segments: &mut Vec<usize>) {
+    if let Some(node) = tree {
+        into_segments(node.left, segments);
+        segments.push(node.segment);
+        into_segments(node.right, segments);
+    }
+}
+
+fn lowest(tree: &Status) -> Option<usize> {
+    let mut node = tree.as_ref()?;
+    while let Some(left) = &node.left {
+        node = left;
+    }
+    Some(node.segment)
+}
+
+fn highest(tree: &Status) -> Option<usize> {
+    let mut node = tree.as_ref()?;
+    while let Some(right) = &node.right {
+        node = right;
+    }
+    Some(node.segment)
+}
+
+/// Reports every point where two or more segments meet, with the Bentley–Ottmann sweep.
+///
+/// Note:
+///     A vertical line sweeps from left to right, keeping the segments it crosses ordered
+///     from bottom to top. Only neighbors in that order can meet next, so every pair of
+///     segments that become neighbors is checked for a crossing ahead of the line. The
+///     segments are kept in a treap split and joined at every event, so the sweep runs in
+///     O((n + k) log n) expected time for k meetings of a segment with a reported point.
+///     All computations are exact, with crossing points kept as rationals, which is why
+///     coordinates must be integers within ±2^30. Points where several segments meet,
+///     shared endpoints and vertical or zero-length segments are handled. Collinear
+///     overlapping segments are reported together at every endpoint or crossing point
+///     lying on their overlap.
+///
+/// Returns:
+///
+/// The meeting points in order of x, then y.
+///
+/// Panics when a coordinate is outside ±2^30.
+pub fn segment_intersections<T>(segments: &[Segment<T>]) -> Vec<Intersection>
+where
+    T: Coordinate + Into<i128>,
+{
+    const LIMIT: i128 = 1 << 30;
+
+    let oriented: Vec<Oriented> = segments
+        .iter()
+        .map(|segment| {
+            let to_integers = |point: Point<T>| {
+                let (x, y) = (point.x.into(), point.y.into());
+                assert!(x.abs() <= LIMIT && y.abs() <= LIMIT, "Segment coordinates must be within 2^30");
+                (x, y)
+            };
+            let (a, b) = (to_integers(segment.a), to_integers(segment.b));
+
+            if a <= b {
+                Oriented { from: a, to: b }
+            } else {
+                Oriented { from: b, to: a }
+            }
+        })
+        .collect();
+
+    // Every event lists the segments starting at its point.
+    let mut events: BTreeMap<SweepPoint, Vec<usize>> = BTreeMap::new();
+    for (index, segment) in oriented.iter().enumerate() {
+        events.entry(segment.start()).or_default().push(index);
+        events.entry(segment.end()).or_default();
+    }
+
+    // Segments crossed by the sweep line ordered from bottom to top just after the last event.
+    let mut status: Status = None;
+    let mut intersections = Vec::new();
+
+    while let Some((point, starting)) = events.pop_first() {
+        let (lower, rest) = split(status.take(), &|index| oriented[index].side(&point) == Ordering::Less);
+        let (through, upper) = split(rest, &|index| oriented[index].side(&point) != Ordering::Greater);
+        let mut through_segments = Vec::new();
+        into_segments(through, &mut through_segments);
+
+        let mut meeting: Vec<usize> = through_segments.iter().chain(&starting).copied().collect();
+        if meeting.len() > 1 {
+            meeting.sort_unstable();
+            let to_f64 = |value: &Rational| value.to_f64().unwrap();
+            intersections.push(Intersection { point: Point::new(to_f64(&point.0), to_f64(&point.1)), segments: meeting });
+        }
+
+        // Segments continuing past the point are ordered by slope, vertical ones on top.
+        let mut continuing: Vec<usize> = through_segments
+            .into_iter()
+            .filter(|&index| oriented[index].end() != point)
+            .chain(starting.into_iter().filter(|&index| !oriented[index].is_degenerate()))
+            .collect();
+        continuing.sort_unstable_by(|&s, &t| {
+            0.cmp(&cross(oriented[s].direction(), oriented[t].direction())).then(s.cmp(&t))
+        });
+
+        let mut check = |lower: Option<usize>, upper: Option<usize>| {
+            if let (Some(lower), Some(upper)) = (lower, upper) {
+                if let Some(crossing) = crossing(&oriented[lower], &oriented[upper]) {
+                    if crossing > point {
+                        events.entry(crossing).or_default();
+                    }
+                }
+            }
+        };
+
+        let (neighbor_below, neighbor_above) = (highest(&lower), lowest(&upper));
+        if continuing.is_empty() {
+            check(neighbor_below, neighbor_above);
+        } else {
+            check(neighbor_below, continuing.first().copied());
+            check(continuing.last().copied(), neighbor_above);
+        }
+
+        let middle = continuing.into_iter().fold(None, |tree, segment| merge(tree, leaf(segment)));
+        status = merge(lower, merge(middle, upper));
+    }
+
+    intersections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::segment::{segment_intersection, SegmentIntersection};
+    use rand::Rng;
+
+    fn segment(ax: i64, ay: i64, bx: i64, by: i64) -> Segment<i64> {
+        Segment::new(Point::new(ax, ay), Point::new(bx, by))
+    }
+
+    #[test]
+    fn crossings_should_be_reported_in_sweep_order() {
+        let segments = [segment(0, 0, 4, 4), segment(0, 4, 4, 0), segment(0, 1, 4, 1), segment(5, 0, 6, 1)];
+
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(
+            intersections,
+            vec![
+                Intersection { point: Point::new(1.0, 1.0), segments: vec![0, 2] },
+                Intersection { point: Point::new(2.0, 2.0), segments: vec![0, 1] },
+                Intersection { point: Point::new(3.0, 1.0), segments: vec![1, 2] },
+            ]
+        );
+    }
+
+    #[test]
+    fn many_segments_through_one_point_should_be_reported_once() {
+        let segments = [segment(-2, 0, 2, 0), segment(0, -2, 0, 2), segment(-2, -2, 2, 2), segment(-2, 2, 2, -2)];
+
+        assert_eq!(
+            segment_intersections(&segments),
+            vec![Intersection { point: Point::new(0.0, 0.0), segments: vec![0, 1, 2, 3] }]
+        );
+    }
+
+    #[test]
+    fn shared_endpoints_and_overlaps_should_be_reported() {
+        let segments = [segment(0, 0, 6, 0), segment(2, 0, 4, 0), segment(6, 0, 6, 3), segment(3, 3, 3, 3)];
+
+        let reported: Vec<(f64, Vec<usize>)> =
+            segment_intersections(&segments).into_iter().map(|meeting| (meeting.point.x, meeting.segments)).collect();
+
+        assert_eq!(reported, vec![(2.0, vec![0, 1]), (4.0, vec![0, 1]), (6.0, vec![0, 2])]);
+    }
+
+    #[test]
+    fn tall_status_should_be_swept_quickly() {
+        let n = 10_000;
+        let mut segments: Vec<Segment<i64>> = (0..n).map(|i| segment(i, i, 3 * n, i)).collect();
+        segments.push(segment(2 * n, -1, 2 * n, n));
+
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(intersections.len(), n as usize);
+        assert!(intersections.iter().enumerate().all(|(i, meeting)| meeting.segments == vec![i, n as usize]));
+    }
+
+    #[test]
+    fn sweep_should_match_brute_force_on_random_segments() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let range = rng.gen_range(2..12);
+            let segments: Vec<Segment<i64>> = (0..rng.gen_range(0..12))
+                .map(|_| {
+                    let mut coordinate = || rng.gen_range(0..range);
+                    segment(coordinate(), coordinate(), coordinate(), coordinate())
+                })
+                .collect();
+
+            // Every endpoint and every crossing of two segments is a candidate meeting point. Points are
+            // compared on a fine grid, as crossings computed in floating point differ in the last bits.
+            let key = |p: Point<f64>| ((p.x * 1e6).round() as i64, (p.y * 1e6).round() as i64);
+            let mut candidates: Vec<Point<f64>> = segments.iter().flat_map(|s| [s.a.to_f64(), s.b.to_f64()]).collect();
+            for (i, s) in segments.iter().enumerate() {
+                for t in &segments[i + 1..] {
+                    if let Some(SegmentIntersection::Point(point)) = segment_intersection(*s, *t) {
+                        candidates.push(point);
+                    }
+                }
+            }
+            candidates.sort_by_key(|&p| key(p));
+            candidates.dedup_by_key(|p| key(*p));
+
+            let on_segment = |s: &Segment<i64>, p: Point<f64>| {
+                let (a, b) = (s.a.to_f64(), s.b.to_f64());
+                let within = |v: f64, a: f64, b: f64| v >= a.min(b) - 1e-9 && v <= a.max(b) + 1e-9;
+                ((b - a).cross(p - a)).abs() < 1e-9 && within(p.x, a.x, b.x) && within(p.y, a.y, b.y)
+            };
+            let expected: Vec<((i64, i64), Vec<usize>)> = candidates
+                .into_iter()
+                .map(|p| (key(p), (0..segments.len()).filter(|&i| on_segment(&segments[i], p)).collect::<Vec<_>>()))
+                .filter(|(_, through)| through.len() > 1)
+                .collect();
+
+            let reported: Vec<((i64, i64), Vec<usize>)> =
+                segment_intersections(&segments).into_iter().map(|meeting| (key(meeting.point), meeting.segments)).collect();
+
+            assert_eq!(reported, expected);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! This module contains a graph backed by adjacency lists.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{Edge, GraphBase, NodeId};
+
+/// Graph backed by adjacency lists, suited to sparse graphs.
+///
+/// Note: parallel edges and self-loops are allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<N = usize, W = ()> {
+    directed: bool,
+    adjacency: Vec<Vec<(N, W)>>,
+    edges: Vec<Edge<N, W>>,
+}
+
+impl<N: NodeId, W: Copy> Graph<N, W> {
+    /// Creates a directed graph with the given number of nodes and no edges.
+    pub fn directed(node_count: usize) -> Self {
+        Self::with_nodes(node_count, true)
+    }
+
+    /// Creates an undirected graph with the given number of nodes and no edges.
+    pub fn undirected(node_count: usize) -> Self {
+        Self::with_nodes(node_count, false)
+    }
+
+    fn with_nodes(node_count: usize, directed: bool) -> Self {
+        Graph { directed, adjacency: vec![Vec::new(); node_count], edges: Vec::new() }
+    }
+
+    /// Creates a directed graph from a list of `(from, to, weight)` edges.
+    pub fn directed_from_edges(node_count: usize, edges: impl IntoIterator<Item = (N, N, W)>) -> Self {
+        let mut graph = Self::directed(node_count);
+        graph.extend_edges(edges);
+        graph
+    }
+
+    /// Creates an undirected graph from a list of `(from, to, weight)` edges.
+    pub fn undirected_from_edges(node_count: usize, edges: impl IntoIterator<Item = (N, N, W)>) -> Self {
+        let mut graph = Self::undirected(node_count);
+        graph.extend_edges(edges);
+        graph
+    }
+
+    /// Adds a node without edges and returns it.
+    pub fn add_node(&mut self) -> N {
+        self.adjacency.push(Vec::new());
+        N::from_index(self.adjacency.len() - 1)
+    }
+
+    /// Adds an edge, undirected edges become reachable from both endpoints.
+    ///
+    /// Panics when some endpoint is not a node of the graph.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let node_count = self.adjacency.len();
+        assert!(from.index() < node_count && to.index() < node_count, "Edge {:?} -> {:?} has an endpoint outside of {} nodes", from, to, node_count);
+
+        self.adjacency[from.index()].push((to, weight));
+        if !self.directed && from != to {
+            self.adjacency[to.index()].push((from, weight));
+        }
+
+        self.edges.push(Edge::new(from, to, weight));
+    }
+
+    /// Adds every `(from, to, weight)` edge of the list.
+    pub fn extend_edges(&mut self, edges: impl IntoIterator<Item = (N, N, W)>) {
+        for (from, to, weight) in edges {
+            self.add_edge(from, to, weight);
+        }
+    }
+
+    /// Returns the number of edges leaving the node, for undirected graphs the number of incident edges.
+    pub fn out_degree(&self, node: N) -> usize {
+        self.adjacency[node.index()].len()
+    }
+
+    /// Returns the graph with every edge reversed; undirected graphs are returned as they are.
+    pub fn reversed(&self) -> Self {
+        if !self.directed {
+            return self.clone();
+        }
+
+        Self::directed_from_edges(self.node_count(), self.edges.iter().map(|edge| (edge.to, edge.from, edge.weight)))
+    }
+}
+
+impl<N: NodeId> Graph<N, ()> {
+    /// Creates a directed unweighted graph from a list of `(from, to)` pairs.
+    pub fn directed_from_pairs(node_count: usize, pairs: impl IntoIterator<Item = (N, N)>) -> Self {
+        Self::directed_from_edges(node_count, pairs.into_iter().map(|(from, to)| (from, to, ())))
+    }
+
+    /// Creates an undirected unweighted graph from a list of `(from, to)` pairs.
+    pub fn undirected_from_pairs(node_count: usize, pairs: impl IntoIterator<Item = (N, N)>) -> Self {
+        Self::undirected_from_edges(node_count, pairs.into_iter().map(|(from, to)| (from, to, ())))
+    }
+}
+
+impl<N: NodeId, W: Copy> GraphBase for Graph<N, W> {
+    type Node = N;
+    type Weight = W;
+
+    fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn neighbors(&self, node: N) -> impl Iterator<Item = (N, W)> + '_ {
+        self.adjacency[node.index()].iter().copied()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = Edge<N, W>> + '_ {
+        self.edges.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directed_edges_should_be_reachable_only_from_their_source() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(3, [(0, 1, 5), (1, 2, 7)]);
+
+        assert_eq!(graph.neighbors(0).collect::<Vec<_>>(), vec![(1, 5)]);
+        assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![(2, 7)]);
+        assert_eq!(graph.neighbors(2).count(), 0);
+        assert!(graph.has_edge(0, 1) && !graph.has_edge(1, 0));
+    }
+
+    #[test]
+    fn undirected_edges_should_be_reachable_from_both_endpoints() {
+        let graph: Graph<u32> = Graph::undirected_from_pairs(3, [(0, 1), (1, 2)]);
+
+        assert_eq!(graph.neighbors(1).map(|(node, _)| node).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.edges().count(), 2);
+    }
+
+    #[test]
+    fn self_loop_in_undirected_graph_should_be_listed_once() {
+        let graph: Graph = Graph::undirected_from_pairs(1, [(0, 0)]);
+
+        assert_eq!(graph.out_degree(0), 1);
+    }
+
+    #[test]
+    fn added_nodes_should_be_numbered_consecutively() {
+        let mut graph: Graph<u8, ()> = Graph::directed(2);
+
+        let node = graph.add_node();
+        graph.add_edge(node, 0, ());
+
+        assert_eq!(node, 2);
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.nodes().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn reversed_graph_should_have_every_edge_reversed() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(3, [(0, 1, 1), (0, 2, 2)]);
+
+        let reversed = graph.reversed();
+
+        assert_eq!(reversed.edges().collect::<Vec<_>>(), vec![Edge::new(1, 0, 1), Edge::new(2, 0, 2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn edge_to_missing_node_should_panic() {
+        let mut graph: Graph = Graph::directed(2);
+
+        graph.add_edge(0, 2, ());
+    }
+}
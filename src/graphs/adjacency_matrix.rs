@@ -0,0 +1,227 @@
+//! This module contains a graph backed by an adjacency matrix.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{Edge, GraphBase, NodeId};
+
+/// Graph backed by an adjacency matrix, suited to dense graphs.
+///
+/// Note:
+///     There is at most one edge between two nodes, adding it again replaces the weight.
+///     Edge lookups take O(1), listing neighbors takes O(n).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixGraph<N = usize, W = ()> {
+    directed: bool,
+    node_count: usize,
+    edge_count: usize,
+    weights: Vec<Option<W>>,
+    nodes: core::marker::PhantomData<N>,
+}
+
+impl<N: NodeId, W: Copy> MatrixGraph<N, W> {
+    /// Creates a directed graph with the given number of nodes and no edges.
+    pub fn directed(node_count: usize) -> Self {
+        Self::with_nodes(node_count, true)
+    }
+
+    /// Creates an undirected graph with the given number of nodes and no edges.
+    pub fn undirected(node_count: usize) -> Self {
+        Self::with_nodes(node_count, false)
+    }
+
+    fn with_nodes(node_count: usize, directed: bool) -> Self {
+        MatrixGraph {
+            directed,
+            node_count,
+            edge_count: 0,
+            weights: vec![None; node_count * node_count],
+            nodes: core::marker::PhantomData,
+        }
+    }
+
+    /// Creates a directed graph from a list of `(from, to, weight)` edges.
+    pub fn directed_from_edges(node_count: usize, edges: impl IntoIterator<Item = (N, N, W)>) -> Self {
+        let mut graph = Self::directed(node_count);
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+
+        graph
+    }
+
+    /// Creates an undirected graph from a list of `(from, to, weight)` edges.
+    pub fn undirected_from_edges(node_count: usize, edges: impl IntoIterator<Item = (N, N, W)>) -> Self {
+        let mut graph = Self::undirected(node_count);
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+
+        graph
+    }
+
+    /// Copies any graph into an adjacency matrix, later parallel edges replace earlier ones.
+    pub fn from_graph<G: GraphBase<Node = N, Weight = W>>(graph: &G) -> Self {
+        let mut matrix = Self::with_nodes(graph.node_count(), graph.is_directed());
+        for edge in graph.edges() {
+            matrix.add_edge(edge.from, edge.to, edge.weight);
+        }
+
+        matrix
+    }
+
+    /// Adds an edge or replaces the weight of an existing one.
+    ///
+    /// Panics when some endpoint is not a node of the graph.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let cell = self.cell(from, to);
+        if self.weights[cell].is_none() {
+            self.edge_count += 1;
+        }
+
+        self.weights[cell] = Some(weight);
+        if !self.directed {
+            let mirrored = self.cell(to, from);
+            self.weights[mirrored] = Some(weight);
+        }
+    }
+
+    /// Removes an edge and returns its weight, if there was one.
+    ///
+    /// Panics when some endpoint is not a node of the graph.
+    pub fn remove_edge(&mut self, from: N, to: N) -> Option<W> {
+        let cell = self.cell(from, to);
+        let weight = self.weights[cell].take();
+
+        if weight.is_some() {
+            self.edge_count -= 1;
+            if !self.directed {
+                let mirrored = self.cell(to, from);
+                self.weights[mirrored] = None;
+            }
+        }
+
+        weight
+    }
+
+    /// Returns the weight of the edge between the nodes, if there is one.
+    ///
+    /// Panics when some endpoint is not a node of the graph.
+    pub fn weight(&self, from: N, to: N) -> Option<W> {
+        self.weights[self.cell(from, to)]
+    }
+
+    fn cell(&self, from: N, to: N) -> usize {
+        assert!(from.index() < self.node_count && to.index() < self.node_count, "Edge {:?} -> {:?} has an endpoint outside of {} nodes", from, to, self.node_count);
+        from.index() * self.node_count + to.index()
+    }
+}
+
+impl<N: NodeId, W: Copy> GraphBase for MatrixGraph<N, W> {
+    type Node = N;
+    type Weight = W;
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn neighbors(&self, node: N) -> impl Iterator<Item = (N, W)> + '_ {
+        let row = &self.weights[node.index() * self.node_count..(node.index() + 1) * self.node_count];
+        row.iter().enumerate().filter_map(|(to, weight)| weight.map(|weight| (N::from_index(to), weight)))
+    }
+
+    fn edges(&self) -> impl Iterator<Item = Edge<N, W>> + '_ {
+        let node_count = self.node_count;
+        let directed = self.directed;
+
+        self.weights.iter().enumerate().filter_map(move |(cell, weight)| {
+            let (from, to) = (cell / node_count, cell % node_count);
+            match weight {
+                Some(weight) if directed || from <= to => Some(Edge::new(N::from_index(from), N::from_index(to), *weight)),
+                _ => None,
+            }
+        })
+    }
+
+    fn has_edge(&self, from: N, to: N) -> bool {
+        self.weight(from, to).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+
+    #[test]
+    fn directed_edges_should_be_stored_in_one_direction() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(3, [(0, 1, 4), (2, 0, 1)]);
+
+        assert_eq!(graph.weight(0, 1), Some(4));
+        assert_eq!(graph.weight(1, 0), None);
+        assert_eq!(graph.neighbors(2).collect::<Vec<_>>(), vec![(0, 1)]);
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn undirected_edges_should_be_listed_once() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::undirected_from_edges(3, [(1, 0, 4), (1, 2, 1)]);
+
+        assert_eq!(graph.weight(0, 1), Some(4));
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![Edge::new(0, 1, 4), Edge::new(1, 2, 1)]);
+    }
+
+    #[test]
+    fn adding_existing_edge_should_replace_its_weight() {
+        let mut graph: MatrixGraph<usize, i32> = MatrixGraph::directed(2);
+
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(0, 1, 2);
+
+        assert_eq!(graph.weight(0, 1), Some(2));
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn removed_edge_should_disappear_in_both_directions() {
+        let mut graph: MatrixGraph<usize, i32> = MatrixGraph::undirected_from_edges(2, [(0, 1, 3)]);
+
+        assert_eq!(graph.remove_edge(1, 0), Some(3));
+        assert!(!graph.has_edge(0, 1));
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn adjacency_list_should_convert_into_equivalent_matrix() {
+        let list: Graph<usize, i32> = Graph::directed_from_edges(3, [(0, 1, 1), (1, 2, 2), (2, 0, 3)]);
+
+        let matrix = MatrixGraph::from_graph(&list);
+
+        assert_eq!(matrix.edges().collect::<Vec<_>>(), list.edges().collect::<Vec<_>>());
+        assert!(matrix.is_directed());
+    }
+
+    #[test]
+    #[should_panic]
+    fn lookup_outside_the_graph_should_panic() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(3, [(1, 0, 7)]);
+
+        graph.weight(0, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn removal_outside_the_graph_should_panic() {
+        let mut graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(3, [(1, 0, 7)]);
+
+        graph.remove_edge(0, 3);
+    }
+}
@@ -0,0 +1,325 @@
+//! This module contains A* search and a grid graph with the usual heuristics.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Add;
+
+use num::Zero;
+
+use crate::graphs::{Edge, GraphBase, NodeId};
+use crate::structures::IndexedHeap;
+
+/// Estimate of the remaining distance from a node to the goal.
+///
+/// Note:
+///     A* returns a shortest path when the estimate never exceeds the real distance.
+///     Closures `Fn(N) -> W` are heuristics too.
+pub trait Heuristic<N, W> {
+    /// Returns the estimated distance from the node to the goal.
+    fn estimate(&self, node: N) -> W;
+}
+
+impl<N, W, F: Fn(N) -> W> Heuristic<N, W> for F {
+    fn estimate(&self, node: N) -> W {
+        self(node)
+    }
+}
+
+/// Totally ordered wrapper for partially ordered priorities, such as floats without NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Priority<W>(W);
+
+impl<W: PartialEq> Eq for Priority<W> {}
+
+impl<W: PartialOrd> PartialOrd for Priority<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd> Ord for Priority<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).expect("A* priorities must be comparable")
+    }
+}
+
+/// Finds a shortest path from the start to the goal guided by a heuristic.
+///
+/// Note:
+///     Weights must be non-negative. Nodes are reopened when a shorter path to them is
+///     found later, so the result is optimal for any admissible heuristic, and every
+///     node is expanded at most once for a consistent one.
+///
+/// Returns:
+///
+/// The length of the path and its nodes from the start to the goal, `None` when the goal is unreachable.
+pub fn astar<G, H>(graph: &G, start: G::Node, goal: G::Node, heuristic: H) -> Option<(G::Weight, Vec<G::Node>)>
+where
+    G: GraphBase,
+    G::Weight: PartialOrd + Add<Output = G::Weight> + Zero,
+    H: Heuristic<G::Node, G::Weight>,
+{
+    let node_count = graph.node_count();
+    let mut distances: Vec<Option<G::Weight>> = vec![None; node_count];
+    let mut predecessors: Vec<Option<G::Node>> = vec![None; node_count];
+
+    let mut open = IndexedHeap::new(node_count);
+    distances[start.index()] = Some(G::Weight::zero());
+    open.push(start.index(), Priority(heuristic.estimate(start)));
+
+    while let Some((index, _)) = open.pop() {
+        let node = G::Node::from_index(index);
+        let distance = distances[index].unwrap();
+
+        if node == goal {
+            let mut path = vec![goal];
+            while let Some(previous) = predecessors[path.last().unwrap().index()] {
+                path.push(previous);
+            }
+
+            path.reverse();
+            return Some((distance, path));
+        }
+
+        for (neighbor, weight) in graph.neighbors(node) {
+            let candidate = distance + weight;
+            if distances[neighbor.index()].is_none_or(|current| candidate < current) {
+                distances[neighbor.index()] = Some(candidate);
+                predecessors[neighbor.index()] = Some(node);
+                open.push(neighbor.index(), Priority(candidate + heuristic.estimate(neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Rectangular grid of cells where moves go between adjacent free cells.
+///
+/// Note:
+///     Nodes are cells numbered row by row, `y * width + x`. Straight moves cost 1,
+///     diagonal moves, when enabled, cost the square root of 2 and may not cut corners.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridGraph {
+    width: usize,
+    height: usize,
+    blocked: Vec<bool>,
+    diagonal: bool,
+}
+
+impl GridGraph {
+    /// Creates a grid without blocked cells and with straight moves only.
+    pub fn new(width: usize, height: usize) -> Self {
+        GridGraph { width, height, blocked: vec![false; width * height], diagonal: false }
+    }
+
+    /// Creates a grid from lines of text where `#` marks blocked cells.
+    ///
+    /// Panics when the lines have different lengths.
+    pub fn from_ascii(map: &str) -> Self {
+        let lines: Vec<&str> = map.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        assert!(lines.iter().all(|line| line.chars().count() == width), "Every line of the grid must have the same length");
+
+        let mut grid = Self::new(width, lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                grid.set_blocked(x, y, cell == '#');
+            }
+        }
+
+        grid
+    }
+
+    /// Allows or forbids diagonal moves.
+    pub fn with_diagonal_moves(mut self, diagonal: bool) -> Self {
+        self.diagonal = diagonal;
+        self
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Blocks or frees the cell.
+    pub fn set_blocked(&mut self, x: usize, y: usize, blocked: bool) {
+        let node = self.node(x, y);
+        self.blocked[node] = blocked;
+    }
+
+    /// Returns true when the cell is blocked.
+    pub fn is_blocked(&self, x: usize, y: usize) -> bool {
+        self.blocked[self.node(x, y)]
+    }
+
+    /// Returns the node of the cell.
+    pub fn node(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "Cell ({}, {}) is outside of the grid", x, y);
+        y * self.width + x
+    }
+
+    /// Returns the cell of the node.
+    pub fn coordinates(&self, node: usize) -> (usize, usize) {
+        (node % self.width, node / self.width)
+    }
+
+    /// Returns the Manhattan distance heuristic towards the goal, admissible for straight moves only.
+    pub fn manhattan_to(&self, goal: usize) -> impl Fn(usize) -> f64 + '_ {
+        let goal = self.coordinates(goal);
+        move |node| manhattan_distance(self.coordinates(node), goal)
+    }
+
+    /// Returns the Euclidean distance heuristic towards the goal, admissible for any moves.
+    #[cfg(feature = "std")]
+    pub fn euclidean_to(&self, goal: usize) -> impl Fn(usize) -> f64 + '_ {
+        let goal = self.coordinates(goal);
+        move |node| euclidean_distance(self.coordinates(node), goal)
+    }
+
+    fn is_free(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height && !self.is_blocked(x as usize, y as usize)
+    }
+}
+
+/// Returns the Manhattan distance between two cells.
+pub fn manhattan_distance(a: (usize, usize), b: (usize, usize)) -> f64 {
+    (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as f64
+}
+
+/// Returns the Euclidean distance between two cells.
+#[cfg(feature = "std")]
+pub fn euclidean_distance(a: (usize, usize), b: (usize, usize)) -> f64 {
+    let dx = a.0.abs_diff(b.0) as f64;
+    let dy = a.1.abs_diff(b.1) as f64;
+    (dx * dx + dy * dy).sqrt()
+}
+
+const STRAIGHT_MOVES: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const DIAGONAL_MOVES: [(isize, isize); 4] = [(1, 1), (-1, 1), (-1, -1), (1, -1)];
+
+impl GraphBase for GridGraph {
+    type Node = usize;
+    type Weight = f64;
+
+    fn node_count(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let (x, y) = self.coordinates(node);
+        let (x, y) = (x as isize, y as isize);
+        let free = !self.blocked[node];
+
+        let straight = STRAIGHT_MOVES.iter().map(|&(dx, dy)| (dx, dy, 1.0));
+        let diagonal = DIAGONAL_MOVES
+            .iter()
+            .filter(move |&&(dx, dy)| self.diagonal && self.is_free(x + dx, y) && self.is_free(x, y + dy))
+            .map(|&(dx, dy)| (dx, dy, core::f64::consts::SQRT_2));
+
+        straight
+            .chain(diagonal)
+            .filter(move |&(dx, dy, _)| free && self.is_free(x + dx, y + dy))
+            .map(move |(dx, dy, weight)| (self.node((x + dx) as usize, (y + dy) as usize), weight))
+    }
+
+    fn edges(&self) -> impl Iterator<Item = Edge<usize, f64>> + '_ {
+        self.nodes().flat_map(move |from| self.neighbors(from).filter(move |&(to, _)| from < to).map(move |(to, weight)| Edge::new(from, to, weight)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+
+    const MAZE: &str = "
+        .....
+        .###.
+        ...#.
+        .#.#.
+        .#...
+    ";
+
+    #[test]
+    fn path_around_walls_should_be_found() {
+        let grid = GridGraph::from_ascii(MAZE);
+        let (start, goal) = (grid.node(0, 4), grid.node(4, 4));
+
+        let (cost, path) = astar(&grid, start, goal, grid.manhattan_to(goal)).unwrap();
+
+        assert_eq!(cost, 8.0);
+        assert_eq!(path.len(), 9);
+        assert!(path.iter().all(|&node| !grid.blocked[node]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn diagonal_moves_should_shorten_paths() {
+        let grid = GridGraph::new(4, 4).with_diagonal_moves(true);
+        let (start, goal) = (grid.node(0, 0), grid.node(3, 3));
+
+        let (cost, path) = astar(&grid, start, goal, grid.euclidean_to(goal)).unwrap();
+
+        assert!((cost - 3.0 * core::f64::consts::SQRT_2).abs() < 1e-9);
+        assert_eq!(path, vec![0, 5, 10, 15]);
+    }
+
+    #[test]
+    fn diagonal_moves_should_not_cut_corners() {
+        let grid = GridGraph::from_ascii("
+            .#
+            ..
+        ").with_diagonal_moves(true);
+
+        assert!(grid.neighbors(grid.node(0, 0)).all(|(node, _)| node != grid.node(1, 1)));
+    }
+
+    #[test]
+    fn walled_off_goal_should_be_unreachable() {
+        let grid = GridGraph::from_ascii("
+            ..#..
+            ..#..
+        ");
+
+        assert_eq!(astar(&grid, grid.node(0, 0), grid.node(4, 0), grid.manhattan_to(grid.node(4, 0))), None);
+    }
+
+    #[test]
+    fn zero_heuristic_should_find_shortest_path_on_weighted_graph() {
+        let graph: Graph<usize, u32> = Graph::directed_from_edges(4, [(0, 1, 1), (1, 3, 5), (0, 2, 2), (2, 3, 1)]);
+
+        let result = astar(&graph, 0, 3, |_| 0);
+
+        assert_eq!(result, Some((3, vec![0, 2, 3])));
+    }
+
+    #[test]
+    fn heuristic_trait_should_be_implementable() {
+        struct Landmarks([u32; 4]);
+
+        impl Heuristic<usize, u32> for Landmarks {
+            fn estimate(&self, node: usize) -> u32 {
+                self.0[node]
+            }
+        }
+
+        let graph: Graph<usize, u32> = Graph::undirected_from_edges(4, [(0, 1, 2), (1, 2, 2), (2, 3, 2), (0, 3, 7)]);
+
+        assert_eq!(astar(&graph, 0, 3, Landmarks([6, 4, 2, 0])), Some((6, vec![0, 1, 2, 3])));
+    }
+}
@@ -0,0 +1,88 @@
+//! This module contains the traits every graph representation implements
+//! and graph algorithms are written against.
+
+use core::fmt::Debug;
+use core::hash::Hash;
+
+/// Identifier of a node.
+///
+/// Note:
+///     Nodes of a graph with n nodes are always numbered 0..n, so algorithms can keep
+///     per-node data in vectors indexed by `index`.
+pub trait NodeId: Copy + Eq + Ord + Hash + Debug {
+    /// Returns the position of the node in 0..n.
+    fn index(self) -> usize;
+
+    /// Returns the node at the given position.
+    fn from_index(index: usize) -> Self;
+}
+
+macro_rules! impl_node_id {
+    ($($t:ty),*) => {
+        $(
+            impl NodeId for $t {
+                fn index(self) -> usize {
+                    self as usize
+                }
+
+                fn from_index(index: usize) -> Self {
+                    <$t>::try_from(index).expect("Node index does not fit into the node type")
+                }
+            }
+        )*
+    };
+}
+
+impl_node_id!(u8, u16, u32, u64, usize);
+
+/// An edge with its endpoints and weight.
+///
+/// Note: for undirected graphs the order of `from` and `to` carries no meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<N, W> {
+    pub from: N,
+    pub to: N,
+    pub weight: W,
+}
+
+impl<N, W> Edge<N, W> {
+    /// Creates an edge.
+    pub fn new(from: N, to: N, weight: W) -> Self {
+        Edge { from, to, weight }
+    }
+}
+
+/// Read access to a graph that graph algorithms are written against.
+pub trait GraphBase {
+    /// Type of node identifiers.
+    type Node: NodeId;
+    /// Type of edge weights, `()` for unweighted graphs.
+    type Weight: Copy;
+
+    /// Returns the number of nodes.
+    fn node_count(&self) -> usize;
+
+    /// Returns the number of edges, an undirected edge counts once.
+    fn edge_count(&self) -> usize;
+
+    /// Returns true when edges have a direction.
+    fn is_directed(&self) -> bool;
+
+    /// Returns the nodes reachable by a single edge together with the edge weights.
+    ///
+    /// Note: for undirected graphs this includes every incident edge.
+    fn neighbors(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, Self::Weight)> + '_;
+
+    /// Returns every edge, an undirected edge is returned once.
+    fn edges(&self) -> impl Iterator<Item = Edge<Self::Node, Self::Weight>> + '_;
+
+    /// Returns every node in increasing order.
+    fn nodes(&self) -> impl Iterator<Item = Self::Node> + '_ {
+        (0..self.node_count()).map(Self::Node::from_index)
+    }
+
+    /// Returns true when there is an edge between the nodes.
+    fn has_edge(&self, from: Self::Node, to: Self::Node) -> bool {
+        self.neighbors(from).any(|(neighbor, _)| neighbor == to)
+    }
+}
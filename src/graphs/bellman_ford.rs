@@ -0,0 +1,205 @@
+//! This module contains the Bellman–Ford shortest path algorithm.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Add;
+
+use num::Zero;
+
+use crate::graphs::paths::ShortestPaths;
+use crate::graphs::{GraphBase, NodeId};
+
+/// Error reported when a cycle of negative total weight is reachable from the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle<N>(pub Vec<N>);
+
+impl<N: fmt::Debug> fmt::Display for NegativeCycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle through nodes {:?}", self.0)
+    }
+}
+
+impl<N: fmt::Debug> core::error::Error for NegativeCycle<N> {}
+
+/// Result of `bellman_ford` for the graph type `G`.
+pub type BellmanFordResult<G> = Result<ShortestPaths<<G as GraphBase>::Node, <G as GraphBase>::Weight>, NegativeCycle<<G as GraphBase>::Node>>;
+
+/// Finds shortest paths from the source to every node, allowing negative weights.
+///
+/// Note:
+///     This function uses the Bellman–Ford algorithm and runs in O(V * (V + E)). An undirected
+///     edge with negative weight is itself a negative cycle, as it can be walked back and forth.
+///     After every round the predecessors are checked for a cycle, which is always negative,
+///     so distances never go below the weight of a path of about 2V edges and cannot overflow
+///     however negative the cycle is.
+///
+/// Returns:
+///
+/// The shortest paths, or the nodes of a negative cycle reachable from the source in
+/// the order its edges are followed.
+pub fn bellman_ford<G>(graph: &G, source: G::Node) -> BellmanFordResult<G>
+where
+    G: GraphBase,
+    G::Weight: PartialOrd + Add<Output = G::Weight> + Zero,
+{
+    let node_count = graph.node_count();
+    let mut distances: Vec<Option<G::Weight>> = vec![None; node_count];
+    let mut predecessors: Vec<Option<G::Node>> = vec![None; node_count];
+    distances[source.index()] = Some(G::Weight::zero());
+
+    // After i rounds every shortest path of at most i edges is found, a round that
+    // still relaxes something after V - 1 rounds proves a negative cycle.
+    for _ in 0..node_count {
+        let mut relaxed = false;
+
+        for node in graph.nodes() {
+            let Some(distance) = distances[node.index()] else {
+                continue;
+            };
+
+            for (neighbor, weight) in graph.neighbors(node) {
+                let candidate = distance + weight;
+                if distances[neighbor.index()].is_none_or(|current| candidate < current) {
+                    distances[neighbor.index()] = Some(candidate);
+                    predecessors[neighbor.index()] = Some(node);
+                    relaxed = true;
+                }
+            }
+        }
+
+        if !relaxed {
+            return Ok(ShortestPaths::new(source, distances, predecessors));
+        }
+        if let Some(cycle) = predecessor_cycle(&predecessors) {
+            return Err(NegativeCycle(cycle));
+        }
+    }
+
+    // Relaxing in round V leaves a cycle among the predecessors, found above.
+    unreachable!("Relaxations continued without a predecessor cycle")
+}
+
+// Finds a cycle among the predecessor links in O(V), in the order its edges are followed.
+fn predecessor_cycle<N: NodeId>(predecessors: &[Option<N>]) -> Option<Vec<N>> {
+    const UNSEEN: usize = usize::MAX;
+    let mut walk_of = vec![UNSEEN; predecessors.len()];
+
+    for start in 0..predecessors.len() {
+        let mut current = start;
+        while walk_of[current] == UNSEEN {
+            walk_of[current] = start;
+            match predecessors[current] {
+                Some(predecessor) => current = predecessor.index(),
+                None => break,
+            }
+        }
+
+        // Reaching a node of the same walk again closes a cycle.
+        if walk_of[current] == start && predecessors[current].is_some() {
+            let on_cycle = N::from_index(current);
+            let mut cycle = vec![on_cycle];
+            let mut node = predecessors[current].unwrap();
+            while node != on_cycle {
+                cycle.push(node);
+                node = predecessors[node.index()].unwrap();
+            }
+
+            cycle.reverse();
+            return Some(cycle);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn cycle_weight(graph: &Graph<usize, i32>, cycle: &[usize]) -> i32 {
+        (0..cycle.len())
+            .map(|i| {
+                let (from, to) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                graph.neighbors(from).filter(|&(node, _)| node == to).map(|(_, weight)| weight).min().unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn negative_weights_should_be_handled() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(5, [(0, 1, 6), (0, 2, 7), (1, 2, 8), (1, 3, 5), (1, 4, -4), (2, 3, -3), (2, 4, 9), (3, 1, -2), (4, 3, 7)]);
+
+        let paths = bellman_ford(&graph, 0).unwrap();
+
+        assert_eq!(paths.distances(), &[Some(0), Some(2), Some(7), Some(4), Some(-2)]);
+        assert_eq!(paths.path_to(4), Some(vec![0, 2, 3, 1, 4]));
+    }
+
+    #[test]
+    fn unreachable_nodes_should_have_no_distance() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(3, [(0, 1, -1)]);
+
+        let paths = bellman_ford(&graph, 0).unwrap();
+
+        assert_eq!(paths.distances(), &[Some(0), Some(-1), None]);
+    }
+
+    #[test]
+    fn reachable_negative_cycle_should_be_reported() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(5, [(0, 1, 1), (1, 2, 1), (2, 3, -3), (3, 1, 1), (3, 4, 1)]);
+
+        let NegativeCycle(cycle) = bellman_ford(&graph, 0).unwrap_err();
+
+        let mut sorted = cycle.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![1, 2, 3]);
+        assert!(cycle_weight(&graph, &cycle) < 0);
+    }
+
+    #[test]
+    fn unreachable_negative_cycle_should_be_ignored() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(4, [(0, 1, 2), (2, 3, -5), (3, 2, 1)]);
+
+        let paths = bellman_ford(&graph, 0).unwrap();
+
+        assert_eq!(paths.distance(1), Some(2));
+        assert_eq!(paths.distance(2), None);
+    }
+
+    #[test]
+    fn negative_undirected_edge_should_be_a_negative_cycle() {
+        let graph: Graph<usize, i32> = Graph::undirected_from_edges(2, [(0, 1, -1)]);
+
+        assert!(bellman_ford(&graph, 0).is_err());
+    }
+
+    #[test]
+    fn deeply_negative_cycle_should_be_reported_without_overflow() {
+        let graph: Graph<usize, i64> = Graph::directed_from_edges(64, (0..64).map(|i| (i, (i + 1) % 64, -(1 << 56))));
+
+        let NegativeCycle(mut cycle) = bellman_ford(&graph, 0).unwrap_err();
+
+        cycle.sort();
+        assert_eq!(cycle, (0..64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reported_cycles_of_random_graphs_should_be_negative() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let node_count = rng.gen_range(1..12);
+            let edges: Vec<(usize, usize, i32)> = (0..rng.gen_range(0..30))
+                .map(|_| (rng.gen_range(0..node_count), rng.gen_range(0..node_count), rng.gen_range(-5..20)))
+                .collect();
+            let graph: Graph<usize, i32> = Graph::directed_from_edges(node_count, edges);
+
+            if let Err(NegativeCycle(cycle)) = bellman_ford(&graph, 0) {
+                assert!(cycle_weight(&graph, &cycle) < 0, "cycle {:?}", cycle);
+            }
+        }
+    }
+}
@@ -0,0 +1,339 @@
+//! This module contains bipartiteness checking, maximum bipartite matching and minimum vertex cover.
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{GraphBase, NodeId};
+
+/// Colors the nodes with two colors so that every edge connects different colors.
+///
+/// Note: edge directions are ignored. Every component gets its smallest node colored `false`.
+///
+/// Returns:
+///
+/// The color of every node, `None` when the graph has an odd cycle and so is not bipartite.
+pub fn two_coloring<G: GraphBase>(graph: &G) -> Option<Vec<bool>> {
+    let mut adjacency = vec![Vec::new(); graph.node_count()];
+    for edge in graph.edges() {
+        adjacency[edge.from.index()].push(edge.to.index());
+        adjacency[edge.to.index()].push(edge.from.index());
+    }
+
+    let mut colors: Vec<Option<bool>> = vec![None; graph.node_count()];
+    for root in 0..graph.node_count() {
+        if colors[root].is_some() {
+            continue;
+        }
+
+        colors[root] = Some(false);
+        let mut queue = VecDeque::from([root]);
+        while let Some(node) = queue.pop_front() {
+            let color = colors[node].unwrap();
+            for &neighbor in &adjacency[node] {
+                match colors[neighbor] {
+                    None => {
+                        colors[neighbor] = Some(!color);
+                        queue.push_back(neighbor);
+                    }
+                    Some(other) if other == color => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Some(colors.into_iter().map(Option::unwrap).collect())
+}
+
+/// Returns true when the nodes can be split into two sides with every edge between the sides.
+pub fn is_bipartite<G: GraphBase>(graph: &G) -> bool {
+    two_coloring(graph).is_some()
+}
+
+/// Matching of a bipartite graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matching<N> {
+    mates: Vec<Option<N>>,
+}
+
+impl<N: NodeId> Matching<N> {
+    /// Returns the node matched with the given one.
+    pub fn mate(&self, node: N) -> Option<N> {
+        self.mates[node.index()]
+    }
+
+    /// Returns the number of matched pairs.
+    pub fn len(&self) -> usize {
+        self.mates.iter().filter(|mate| mate.is_some()).count() / 2
+    }
+
+    /// Returns true when nothing is matched.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the matched pairs as `(left, right)`, ordered by the left node.
+    pub fn pairs(&self, left_side: &[bool]) -> Vec<(N, N)> {
+        self.mates
+            .iter()
+            .enumerate()
+            .filter(|&(node, _)| left_side[node])
+            .filter_map(|(node, mate)| mate.map(|mate| (N::from_index(node), mate)))
+            .collect()
+    }
+}
+
+const INFINITY: usize = usize::MAX;
+
+/// Finds a maximum matching of a bipartite graph.
+///
+/// Note:
+///     This function uses the Hopcroft–Karp algorithm: every phase finds a maximal set
+///     of shortest disjoint augmenting paths. Runs in O(E * sqrt(V)).
+///
+/// Arguments:
+///
+/// * `graph`: The graph, edge directions are ignored.
+/// * `left_side`: The side of every node, for example the result of `two_coloring`.
+///
+/// Panics when some edge connects nodes on the same side.
+pub fn hopcroft_karp<G: GraphBase>(graph: &G, left_side: &[bool]) -> Matching<G::Node> {
+    let node_count = graph.node_count();
+    let mut adjacency = vec![Vec::new(); node_count];
+    for edge in graph.edges() {
+        let (from, to) = (edge.from.index(), edge.to.index());
+        assert_ne!(left_side[from], left_side[to], "Edge {:?} - {:?} connects nodes on the same side", edge.from, edge.to);
+
+        let (left, right) = if left_side[from] { (from, to) } else { (to, from) };
+        adjacency[left].push(right);
+    }
+
+    let left_nodes: Vec<usize> = (0..node_count).filter(|&node| left_side[node]).collect();
+    let mut mates: Vec<Option<usize>> = vec![None; node_count];
+    let mut distances = vec![INFINITY; node_count];
+    let mut path = Vec::new();
+
+    while let Some(shortest) = layer(&left_nodes, &adjacency, &mates, &mut distances) {
+        let mut next = vec![0; node_count];
+        for &left in &left_nodes {
+            if mates[left].is_none() {
+                augment(left, shortest, &adjacency, &mut mates, &mut distances, &mut next, &mut path);
+            }
+        }
+    }
+
+    Matching { mates: mates.into_iter().map(|mate| mate.map(G::Node::from_index)).collect() }
+}
+
+/// Breadth-first search from free left nodes over alternating paths, up to the first layer
+/// next to a free right node.
+///
+/// Returns:
+///
+/// The distance of the left nodes ending shortest augmenting paths, none when there is no augmenting path.
+fn layer(left_nodes: &[usize], adjacency: &[Vec<usize>], mates: &[Option<usize>], distances: &mut [usize]) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    for &left in left_nodes {
+        distances[left] = if mates[left].is_none() { 0 } else { INFINITY };
+        if mates[left].is_none() {
+            queue.push_back(left);
+        }
+    }
+
+    let mut shortest = None;
+    while let Some(left) = queue.pop_front() {
+        if shortest.is_some_and(|shortest| distances[left] > shortest) {
+            break;
+        }
+
+        for &right in &adjacency[left] {
+            match mates[right] {
+                None => shortest = Some(distances[left]),
+                Some(next) if distances[next] == INFINITY => {
+                    distances[next] = distances[left] + 1;
+                    queue.push_back(next);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    shortest
+}
+
+/// Depth-first search for a shortest augmenting path along the layers, flipping it when found.
+///
+/// Note: the path is kept on an explicit stack of left nodes, so long paths cannot overflow
+/// the call stack. Left nodes that lead nowhere are taken out of the layers.
+fn augment(
+    root: usize,
+    shortest: usize,
+    adjacency: &[Vec<usize>],
+    mates: &mut [Option<usize>],
+    distances: &mut [usize],
+    next: &mut [usize],
+    path: &mut Vec<usize>,
+) -> bool {
+    path.clear();
+    path.push(root);
+
+    while let Some(&left) = path.last() {
+        let Some(&right) = adjacency[left].get(next[left]) else {
+            distances[left] = INFINITY;
+            path.pop();
+            continue;
+        };
+        next[left] += 1;
+
+        match mates[right] {
+            None if distances[left] == shortest => {
+                // Every left node of the path takes the right node it was reached from next.
+                let mut right = Some(right);
+                for &left in path.iter().rev() {
+                    let previous = mates[left];
+                    mates[left] = right;
+                    mates[right.unwrap()] = Some(left);
+                    right = previous;
+                }
+                return true;
+            }
+            Some(other) if distances[other] == distances[left] + 1 => path.push(other),
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Finds a minimum vertex cover of a bipartite graph from a maximum matching.
+///
+/// Note:
+///     By König's theorem its size equals the size of the matching. Nodes reachable from
+///     free left nodes by alternating paths are collected, the cover is the unreached left
+///     nodes together with the reached right nodes.
+///
+/// Returns:
+///
+/// The nodes of the cover in increasing order.
+pub fn minimum_vertex_cover<G: GraphBase>(graph: &G, left_side: &[bool], matching: &Matching<G::Node>) -> Vec<G::Node> {
+    let node_count = graph.node_count();
+    let mut adjacency = vec![Vec::new(); node_count];
+    for edge in graph.edges() {
+        adjacency[edge.from.index()].push(edge.to.index());
+        adjacency[edge.to.index()].push(edge.from.index());
+    }
+
+    let mut reached = vec![false; node_count];
+    let mut queue: VecDeque<usize> = (0..node_count).filter(|&node| left_side[node] && matching.mates[node].is_none()).collect();
+    for &node in &queue {
+        reached[node] = true;
+    }
+
+    while let Some(left) = queue.pop_front() {
+        for &right in &adjacency[left] {
+            if reached[right] || matching.mates[left].map(NodeId::index) == Some(right) {
+                continue;
+            }
+
+            reached[right] = true;
+            if let Some(mate) = matching.mates[right] {
+                if !reached[mate.index()] {
+                    reached[mate.index()] = true;
+                    queue.push_back(mate.index());
+                }
+            }
+        }
+    }
+
+    (0..node_count).filter(|&node| left_side[node] != reached[node]).map(G::Node::from_index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn brute_force_matching_size(edges: &[(usize, usize)]) -> usize {
+        (0u32..1 << edges.len())
+            .filter(|&mask| {
+                let chosen: Vec<_> = (0..edges.len()).filter(|&i| mask >> i & 1 == 1).map(|i| edges[i]).collect();
+                chosen.iter().enumerate().all(|(i, a)| chosen[i + 1..].iter().all(|b| a.0 != b.0 && a.1 != b.1 && a.0 != b.1 && a.1 != b.0))
+            })
+            .map(u32::count_ones)
+            .max()
+            .unwrap() as usize
+    }
+
+    #[test]
+    fn even_cycle_should_be_bipartite() {
+        let graph: Graph = Graph::undirected_from_pairs(4, [(0, 1), (1, 2), (2, 3), (3, 0)]);
+
+        assert_eq!(two_coloring(&graph), Some(vec![false, true, false, true]));
+    }
+
+    #[test]
+    fn odd_cycle_should_not_be_bipartite() {
+        let graph: Graph = Graph::undirected_from_pairs(3, [(0, 1), (1, 2), (2, 0)]);
+
+        assert!(!is_bipartite(&graph));
+    }
+
+    #[test]
+    fn maximum_matching_should_be_found() {
+        // Left nodes 0..3, right nodes 3..6.
+        let graph: Graph = Graph::undirected_from_pairs(6, [(0, 3), (0, 4), (1, 3), (2, 3)]);
+        let left_side = [true, true, true, false, false, false];
+
+        let matching = hopcroft_karp(&graph, &left_side);
+
+        assert_eq!(matching.len(), 2);
+        assert_eq!(matching.mate(0), Some(4));
+        assert_eq!(matching.mate(5), None);
+    }
+
+    #[test]
+    fn vertex_cover_should_cover_every_edge() {
+        let graph: Graph = Graph::undirected_from_pairs(6, [(0, 3), (0, 4), (1, 3), (2, 3)]);
+        let left_side = [true, true, true, false, false, false];
+
+        let matching = hopcroft_karp(&graph, &left_side);
+        let cover = minimum_vertex_cover(&graph, &left_side, &matching);
+
+        assert_eq!(cover, vec![0, 3]);
+    }
+
+    #[test]
+    fn matching_and_cover_should_be_optimal_on_random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let (left, right) = (rng.gen_range(1..6), rng.gen_range(1..6));
+            let edges: Vec<(usize, usize)> = (0..rng.gen_range(0..12)).map(|_| (rng.gen_range(0..left), left + rng.gen_range(0..right))).collect();
+            let graph: Graph = Graph::undirected_from_pairs(left + right, edges.iter().copied());
+            let left_side: Vec<bool> = (0..left + right).map(|node| node < left).collect();
+
+            let matching = hopcroft_karp(&graph, &left_side);
+            let cover = minimum_vertex_cover(&graph, &left_side, &matching);
+
+            assert_eq!(matching.len(), brute_force_matching_size(&edges));
+            assert_eq!(matching.pairs(&left_side).len(), matching.len());
+            assert_eq!(cover.len(), matching.len());
+            assert!(edges.iter().all(|(a, b)| cover.contains(a) || cover.contains(b)));
+        }
+    }
+
+    #[test]
+    fn long_augmenting_path_should_not_overflow_the_stack() {
+        // Left node i prefers right node i + 1, so the first phase leaves one augmenting path through every node.
+        let n = 200_000;
+        let pairs = (0..n).flat_map(|i| [(i, n + i + 1), (i, n + i)]).filter(|&(_, right)| right < 2 * n);
+        let graph: Graph = Graph::undirected_from_pairs(2 * n, pairs);
+        let left_side: Vec<bool> = (0..2 * n).map(|node| node < n).collect();
+
+        let matching = hopcroft_karp(&graph, &left_side);
+
+        assert_eq!(matching.len(), n);
+    }
+}
@@ -0,0 +1,323 @@
+//! This module contains greedy and exact vertex coloring of graphs.
+
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{GraphBase, NodeId};
+
+/// Assignment of colors to the nodes so that the ends of every edge get different colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coloring {
+    /// The number of colors used.
+    pub count: usize,
+    /// The color of every node, in `0..count`.
+    pub colors: Vec<usize>,
+}
+
+impl Coloring {
+    /// Returns the nodes grouped by color.
+    pub fn classes<N: NodeId>(&self) -> Vec<Vec<N>> {
+        let mut classes = vec![Vec::new(); self.count];
+        for (node, &color) in self.colors.iter().enumerate() {
+            classes[color].push(N::from_index(node));
+        }
+
+        classes
+    }
+}
+
+fn adjacency<G: GraphBase>(graph: &G) -> Vec<Vec<usize>> {
+    let mut adjacency = vec![Vec::new(); graph.node_count()];
+    for edge in graph.edges() {
+        let (from, to) = (edge.from.index(), edge.to.index());
+        assert!(from != to, "Graphs with self loops cannot be colored");
+
+        adjacency[from].push(to);
+        adjacency[to].push(from);
+    }
+
+    for neighbors in &mut adjacency {
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+
+    adjacency
+}
+
+// Gives every node, in the given order, the smallest color unused by its neighbors.
+fn greedy(adjacency: &[Vec<usize>], order: impl IntoIterator<Item = usize>) -> Coloring {
+    let mut colors = vec![usize::MAX; adjacency.len()];
+    let mut used = Vec::new();
+    let mut count = 0;
+
+    for node in order {
+        used.clear();
+        used.resize(count + 1, false);
+        for &neighbor in &adjacency[node] {
+            if colors[neighbor] != usize::MAX {
+                used[colors[neighbor]] = true;
+            }
+        }
+
+        colors[node] = used.iter().position(|&taken| !taken).unwrap();
+        count = count.max(colors[node] + 1);
+    }
+
+    Coloring { count, colors }
+}
+
+/// Colors the graph greedily, visiting the nodes from the highest degree to the lowest.
+///
+/// Note:
+///     This is the Welsh–Powell heuristic. It uses at most one more color than the largest
+///     degree, but may be far from optimal. Edge directions are ignored. Runs in O(V log V + E).
+///
+/// Panics when the graph has a self loop.
+pub fn welsh_powell<G: GraphBase>(graph: &G) -> Coloring {
+    let adjacency = adjacency(graph);
+
+    let mut order: Vec<usize> = (0..adjacency.len()).collect();
+    order.sort_by_key(|&node| core::cmp::Reverse(adjacency[node].len()));
+
+    greedy(&adjacency, order)
+}
+
+/// Colors the graph greedily, always taking the node with the most distinct neighbor colors.
+///
+/// Note:
+///     This is the DSATUR heuristic. Ties are broken by the number of uncolored neighbors,
+///     then by the smaller node. It colors bipartite graphs optimally. Edge directions are
+///     ignored. Runs in O(V^2 + E).
+///
+/// Panics when the graph has a self loop.
+pub fn dsatur<G: GraphBase>(graph: &G) -> Coloring {
+    let adjacency = adjacency(graph);
+    let node_count = adjacency.len();
+
+    let mut neighbor_colors = vec![BTreeSet::new(); node_count];
+    let mut uncolored_degrees: Vec<usize> = adjacency.iter().map(Vec::len).collect();
+    let mut colored = vec![false; node_count];
+    let mut order = Vec::with_capacity(node_count);
+
+    for _ in 0..node_count {
+        let node = (0..node_count)
+            .filter(|&node| !colored[node])
+            .max_by_key(|&node| (neighbor_colors[node].len(), uncolored_degrees[node], core::cmp::Reverse(node)))
+            .unwrap();
+
+        let color = (0..).find(|color| !neighbor_colors[node].contains(color)).unwrap();
+        colored[node] = true;
+        order.push(node);
+        for &neighbor in &adjacency[node] {
+            neighbor_colors[neighbor].insert(color);
+            uncolored_degrees[neighbor] -= 1;
+        }
+    }
+
+    // Replaying the order greedily picks the same colors.
+    greedy(&adjacency, order)
+}
+
+/// Colors the graph with at most k colors if possible.
+///
+/// Note:
+///     This function backtracks, always branching on the uncolored node with the fewest
+///     colors left and failing as soon as some node has none. New colors are introduced
+///     in increasing order only, which skips colorings that differ by renaming. The worst
+///     case is exponential.
+///
+/// Returns:
+///
+/// The coloring, `None` when the graph needs more than k colors.
+///
+/// Panics when the graph has a self loop.
+pub fn k_coloring<G: GraphBase>(graph: &G, k: usize) -> Option<Coloring> {
+    let adjacency = adjacency(graph);
+    let node_count = adjacency.len();
+    if node_count == 0 {
+        return Some(Coloring { count: 0, colors: Vec::new() });
+    }
+
+    let mut search = Backtracking {
+        adjacency: &adjacency,
+        k,
+        colors: vec![usize::MAX; node_count],
+        // blocked[node][color] counts the neighbors of the node having that color.
+        blocked: vec![vec![0; k]; node_count],
+    };
+
+    if !search.extend(node_count, 0) {
+        return None;
+    }
+
+    let count = search.colors.iter().max().unwrap() + 1;
+    Some(Coloring { count, colors: search.colors })
+}
+
+/// Colors the graph with the fewest possible colors.
+///
+/// Note:
+///     The DSATUR coloring is an upper bound, and fewer colors are tried with `k_coloring`
+///     until one fails. The worst case is exponential.
+///
+/// Panics when the graph has a self loop.
+pub fn chromatic_coloring<G: GraphBase>(graph: &G) -> Coloring {
+    let mut best = dsatur(graph);
+    while best.count > 1 {
+        match k_coloring(graph, best.count - 1) {
+            Some(coloring) => best = coloring,
+            None => break,
+        }
+    }
+
+    best
+}
+
+struct Backtracking<'a> {
+    adjacency: &'a [Vec<usize>],
+    k: usize,
+    colors: Vec<usize>,
+    blocked: Vec<Vec<usize>>,
+}
+
+impl Backtracking<'_> {
+    fn available(&self, node: usize, used: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.k.min(used + 1)).filter(move |&color| self.blocked[node][color] == 0)
+    }
+
+    // Colors the remaining nodes, `used` is the number of colors introduced so far.
+    fn extend(&mut self, remaining: usize, used: usize) -> bool {
+        if remaining == 0 {
+            return true;
+        }
+
+        let node = (0..self.colors.len())
+            .filter(|&node| self.colors[node] == usize::MAX)
+            .min_by_key(|&node| (self.available(node, used).count(), core::cmp::Reverse(self.adjacency[node].len())))
+            .unwrap();
+
+        let candidates: Vec<usize> = self.available(node, used).collect();
+        for color in candidates {
+            self.assign(node, color, true);
+            if self.extend(remaining - 1, used.max(color + 1)) {
+                return true;
+            }
+            self.assign(node, color, false);
+        }
+
+        false
+    }
+
+    fn assign(&mut self, node: usize, color: usize, set: bool) {
+        self.colors[node] = if set { color } else { usize::MAX };
+        for &neighbor in self.adjacency[node].iter() {
+            if set {
+                self.blocked[neighbor][color] += 1;
+            } else {
+                self.blocked[neighbor][color] -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn is_proper(graph: &Graph, coloring: &Coloring) -> bool {
+        graph.edges().all(|edge| coloring.colors[edge.from] != coloring.colors[edge.to])
+            && coloring.colors.iter().all(|&color| color < coloring.count)
+    }
+
+    fn cycle(n: usize) -> Graph {
+        Graph::undirected_from_pairs(n, (0..n).map(|node| (node, (node + 1) % n)))
+    }
+
+    fn petersen() -> Graph {
+        let outer = (0..5).map(|node| (node, (node + 1) % 5));
+        let spokes = (0..5).map(|node| (node, node + 5));
+        let inner = (0..5).map(|node| (node + 5, (node + 2) % 5 + 5));
+
+        Graph::undirected_from_pairs(10, outer.chain(spokes).chain(inner))
+    }
+
+    #[test]
+    fn greedy_colorings_should_be_proper() {
+        let graph = petersen();
+
+        let welsh_powell = welsh_powell(&graph);
+        let dsatur = dsatur(&graph);
+
+        assert!(is_proper(&graph, &welsh_powell));
+        assert!(is_proper(&graph, &dsatur));
+        assert_eq!(dsatur.count, 3);
+    }
+
+    #[test]
+    fn dsatur_should_color_even_cycles_with_two_colors() {
+        let coloring = dsatur(&cycle(8));
+
+        assert_eq!(coloring.count, 2);
+        assert_eq!(coloring.classes::<usize>(), vec![vec![0, 2, 4, 6], vec![1, 3, 5, 7]]);
+    }
+
+    #[test]
+    fn odd_cycle_should_not_be_two_colorable() {
+        let graph = cycle(7);
+
+        assert_eq!(k_coloring(&graph, 2), None);
+        assert!(is_proper(&graph, &k_coloring(&graph, 3).unwrap()));
+    }
+
+    #[test]
+    fn complete_graph_should_need_a_color_per_node() {
+        let graph: Graph = Graph::undirected_from_pairs(5, (0..5).flat_map(|a| (a + 1..5).map(move |b| (a, b))));
+
+        assert_eq!(chromatic_coloring(&graph).count, 5);
+        assert_eq!(k_coloring(&graph, 4), None);
+    }
+
+    #[test]
+    fn graph_without_edges_should_use_one_color() {
+        let graph: Graph = Graph::undirected(4);
+
+        assert_eq!(chromatic_coloring(&graph), Coloring { count: 1, colors: vec![0; 4] });
+        assert_eq!(chromatic_coloring(&Graph::<usize, ()>::undirected(0)).count, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn self_loop_should_panic() {
+        let graph: Graph = Graph::undirected_from_pairs(2, [(0, 1), (1, 1)]);
+
+        welsh_powell(&graph);
+    }
+
+    #[test]
+    fn chromatic_coloring_should_match_brute_force_on_random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let n = rng.gen_range(1..8);
+            let pairs: Vec<_> = (0..n).flat_map(|a| (a + 1..n).map(move |b| (a, b))).collect();
+            let graph: Graph = Graph::undirected_from_pairs(n, pairs.into_iter().filter(|_| rng.gen_bool(0.5)));
+
+            let fits = |k: usize| {
+                (0..k.pow(n as u32)).any(|code| {
+                    let colors: Vec<usize> = (0..n).map(|node| code / k.pow(node as u32) % k).collect();
+                    graph.edges().all(|edge| colors[edge.from] != colors[edge.to])
+                })
+            };
+            let chromatic_number = (1..=n).find(|&k| fits(k)).unwrap();
+
+            let coloring = chromatic_coloring(&graph);
+
+            assert!(is_proper(&graph, &coloring));
+            assert_eq!(coloring.count, chromatic_number);
+            assert!(welsh_powell(&graph).count >= chromatic_number);
+        }
+    }
+}
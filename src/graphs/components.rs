@@ -0,0 +1,107 @@
+//! This module contains connected-component labeling.
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{Graph, GraphBase, NodeId};
+
+/// Connected components of a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The number of components.
+    pub count: usize,
+    /// The component of every node, components are numbered in order of their smallest node.
+    pub labels: Vec<usize>,
+}
+
+impl Components {
+    /// Returns the nodes of every component in increasing order.
+    pub fn groups<N: NodeId>(&self) -> Vec<Vec<N>> {
+        let mut groups = vec![Vec::new(); self.count];
+        for (node, &label) in self.labels.iter().enumerate() {
+            groups[label].push(N::from_index(node));
+        }
+
+        groups
+    }
+}
+
+/// Labels the connected components of the graph.
+///
+/// Note:
+///     For directed graphs edge directions are ignored, which gives the weakly connected
+///     components. This function runs a breadth-first traversal from every unlabeled node,
+///     sharing the labels as the visited marks, so it takes O(n + m) in total.
+pub fn connected_components<G: GraphBase>(graph: &G) -> Components {
+    if graph.is_directed() {
+        let undirected: Graph<G::Node, ()> = Graph::undirected_from_edges(graph.node_count(), graph.edges().map(|edge| (edge.from, edge.to, ())));
+        return connected_components(&undirected);
+    }
+
+    let mut labels = vec![usize::MAX; graph.node_count()];
+    let mut count = 0;
+    let mut queue = VecDeque::new();
+    for start in graph.nodes() {
+        if labels[start.index()] != usize::MAX {
+            continue;
+        }
+
+        labels[start.index()] = count;
+        queue.push_back(start);
+        while let Some(node) = queue.pop_front() {
+            for (neighbor, _) in graph.neighbors(node) {
+                if labels[neighbor.index()] == usize::MAX {
+                    labels[neighbor.index()] = count;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        count += 1;
+    }
+
+    Components { count, labels }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_should_be_labeled_in_order_of_smallest_node() {
+        let graph: Graph = Graph::undirected_from_pairs(6, [(0, 3), (1, 2), (4, 1)]);
+
+        let components = connected_components(&graph);
+
+        assert_eq!(components.count, 3);
+        assert_eq!(components.labels, vec![0, 1, 1, 0, 1, 2]);
+        assert_eq!(components.groups::<usize>(), vec![vec![0, 3], vec![1, 2, 4], vec![5]]);
+    }
+
+    #[test]
+    fn directed_graph_should_get_weakly_connected_components() {
+        let graph: Graph = Graph::directed_from_pairs(4, [(1, 0), (2, 3)]);
+
+        let components = connected_components(&graph);
+
+        assert_eq!(components.labels, vec![0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn many_isolated_nodes_should_be_labeled_in_linear_time() {
+        let graph: Graph = Graph::undirected(200_000);
+
+        let components = connected_components(&graph);
+
+        assert_eq!(components.count, 200_000);
+        assert_eq!(components.labels[199_999], 199_999);
+    }
+
+    #[test]
+    fn empty_graph_should_have_no_components() {
+        let graph: Graph = Graph::undirected(0);
+
+        assert_eq!(connected_components(&graph).count, 0);
+    }
+}
@@ -0,0 +1,206 @@
+//! This module contains bridges, articulation points and biconnected components of undirected graphs.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{Edge, GraphBase, NodeId};
+
+/// Weak spots of an undirected graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connectivity<N, W> {
+    /// Edges whose removal disconnects their endpoints.
+    pub bridges: Vec<Edge<N, W>>,
+    /// Nodes whose removal increases the number of connected components, in increasing order.
+    pub articulation_points: Vec<N>,
+    /// Maximal groups of edges where any two edges lie on a common simple cycle.
+    pub biconnected_components: Vec<Vec<Edge<N, W>>>,
+}
+
+const UNVISITED: usize = usize::MAX;
+
+/// Finds bridges, articulation points and biconnected components.
+///
+/// Note:
+///     A single depth-first traversal computes low-link values: the earliest discovered
+///     node reachable from a subtree through at most one non-tree edge. Runs in O(V + E).
+///     Parallel edges are told apart, so two parallel edges are never bridges.
+///     Self-loops affect nothing and belong to no component.
+///
+/// Panics when the graph is directed.
+pub fn connectivity<G: GraphBase>(graph: &G) -> Connectivity<G::Node, G::Weight> {
+    assert!(!graph.is_directed(), "Connectivity is computed only for undirected graphs");
+
+    let edges: Vec<Edge<G::Node, G::Weight>> = graph.edges().collect();
+    let node_count = graph.node_count();
+
+    let mut adjacency = vec![Vec::new(); node_count];
+    for (id, edge) in edges.iter().enumerate() {
+        let (from, to) = (edge.from.index(), edge.to.index());
+        if from != to {
+            adjacency[from].push((to, id));
+            adjacency[to].push((from, id));
+        }
+    }
+
+    let mut discovery = vec![UNVISITED; node_count];
+    let mut low = vec![0; node_count];
+    let mut is_articulation = vec![false; node_count];
+    let mut bridges = Vec::new();
+    let mut components = Vec::new();
+    let mut edge_stack = Vec::new();
+    let mut next_discovery = 0;
+
+    for root in 0..node_count {
+        if discovery[root] != UNVISITED {
+            continue;
+        }
+
+        discovery[root] = next_discovery;
+        low[root] = next_discovery;
+        next_discovery += 1;
+
+        let mut root_children = 0;
+        // Frames hold the node, the tree edge leading to it and the next adjacency position.
+        let mut frames = vec![(root, usize::MAX, 0)];
+        while let Some(&mut (node, parent_edge, ref mut next)) = frames.last_mut() {
+            if let Some(&(neighbor, id)) = adjacency[node].get(*next) {
+                *next += 1;
+
+                if id == parent_edge {
+                    continue;
+                }
+
+                if discovery[neighbor] == UNVISITED {
+                    discovery[neighbor] = next_discovery;
+                    low[neighbor] = next_discovery;
+                    next_discovery += 1;
+                    edge_stack.push(id);
+                    frames.push((neighbor, id, 0));
+                } else if discovery[neighbor] < discovery[node] {
+                    edge_stack.push(id);
+                    low[node] = low[node].min(discovery[neighbor]);
+                }
+
+                continue;
+            }
+
+            frames.pop();
+            let Some(&(parent, _, _)) = frames.last() else {
+                continue;
+            };
+
+            low[parent] = low[parent].min(low[node]);
+            if low[node] > discovery[parent] {
+                bridges.push(edges[parent_edge]);
+            }
+
+            if low[node] >= discovery[parent] {
+                if parent == root {
+                    root_children += 1;
+                } else {
+                    is_articulation[parent] = true;
+                }
+
+                let mut component = Vec::new();
+                while let Some(id) = edge_stack.pop() {
+                    component.push(edges[id]);
+                    if id == parent_edge {
+                        break;
+                    }
+                }
+
+                components.push(component);
+            }
+        }
+
+        is_articulation[root] = root_children > 1;
+    }
+
+    Connectivity {
+        bridges,
+        articulation_points: (0..node_count).filter(|&node| is_articulation[node]).map(G::Node::from_index).collect(),
+        biconnected_components: components,
+    }
+}
+
+/// Returns the bridges of an undirected graph.
+pub fn bridges<G: GraphBase>(graph: &G) -> Vec<Edge<G::Node, G::Weight>> {
+    connectivity(graph).bridges
+}
+
+/// Returns the articulation points of an undirected graph in increasing order.
+pub fn articulation_points<G: GraphBase>(graph: &G) -> Vec<G::Node> {
+    connectivity(graph).articulation_points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::components::connected_components;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn without_node(graph: &Graph, removed: usize) -> Graph {
+        Graph::undirected_from_pairs(graph.node_count(), graph.edges().filter(|edge| edge.from != removed && edge.to != removed).map(|edge| (edge.from, edge.to)))
+    }
+
+    fn without_edge(graph: &Graph, removed: usize) -> Graph {
+        Graph::undirected_from_pairs(graph.node_count(), graph.edges().enumerate().filter(|&(id, _)| id != removed).map(|(_, edge)| (edge.from, edge.to)))
+    }
+
+    fn sample() -> Graph {
+        // Two triangles joined by the bridge 2 - 3, and a pendant node 6.
+        Graph::undirected_from_pairs(7, [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3), (5, 6)])
+    }
+
+    #[test]
+    fn bridges_should_be_found() {
+        let found: Vec<(usize, usize)> = bridges(&sample()).iter().map(|edge| (edge.from, edge.to)).collect();
+
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&(2, 3)) && found.contains(&(5, 6)));
+    }
+
+    #[test]
+    fn articulation_points_should_be_found() {
+        assert_eq!(articulation_points(&sample()), vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn biconnected_components_should_group_edges() {
+        let mut sizes: Vec<usize> = connectivity(&sample()).biconnected_components.iter().map(Vec::len).collect();
+        sizes.sort();
+
+        assert_eq!(sizes, vec![1, 1, 3, 3]);
+    }
+
+    #[test]
+    fn parallel_edges_should_not_be_bridges() {
+        let graph: Graph = Graph::undirected_from_pairs(3, [(0, 1), (0, 1), (1, 2)]);
+
+        let found: Vec<(usize, usize)> = bridges(&graph).iter().map(|edge| (edge.from, edge.to)).collect();
+
+        assert_eq!(found, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn results_should_match_brute_force_on_random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let n = rng.gen_range(1..12);
+            let graph: Graph = Graph::undirected_from_pairs(n, (0..rng.gen_range(0..16)).map(|_| (rng.gen_range(0..n), rng.gen_range(0..n))));
+            let components = connected_components(&graph).count;
+
+            let result = connectivity(&graph);
+
+            let expected_points: Vec<usize> = (0..n).filter(|&node| connected_components(&without_node(&graph, node)).count > components + 1).collect();
+            let expected_bridges = (0..graph.edge_count()).filter(|&id| connected_components(&without_edge(&graph, id)).count > components).count();
+            let non_loop_edges = graph.edges().filter(|edge| edge.from != edge.to).count();
+
+            assert_eq!(result.articulation_points, expected_points);
+            assert_eq!(result.bridges.len(), expected_bridges);
+            assert_eq!(result.biconnected_components.iter().map(Vec::len).sum::<usize>(), non_loop_edges);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! This module contains Dijkstra's shortest path algorithm.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use num::Zero;
+
+use crate::graphs::paths::ShortestPaths;
+use crate::graphs::{GraphBase, NodeId};
+use crate::structures::IndexedHeap;
+
+/// Finds shortest paths from the source to every node.
+///
+/// Note:
+///     This function uses Dijkstra's algorithm with an indexed binary heap and runs in
+///     O((V + E) log V). Every weight must be non-negative.
+pub fn dijkstra<G>(graph: &G, source: G::Node) -> ShortestPaths<G::Node, G::Weight>
+where
+    G: GraphBase,
+    G::Weight: Ord + Add<Output = G::Weight> + Zero,
+{
+    run(graph, source, None)
+}
+
+/// Finds a shortest path from the source to the target, stopping as soon as the target is settled.
+///
+/// Returns:
+///
+/// The length of the path and its nodes from the source to the target, `None` when the target is unreachable.
+pub fn dijkstra_to<G>(graph: &G, source: G::Node, target: G::Node) -> Option<(G::Weight, Vec<G::Node>)>
+where
+    G: GraphBase,
+    G::Weight: Ord + Add<Output = G::Weight> + Zero,
+{
+    let paths = run(graph, source, Some(target));
+    Some((paths.distance(target)?, paths.path_to(target)?))
+}
+
+fn run<G>(graph: &G, source: G::Node, target: Option<G::Node>) -> ShortestPaths<G::Node, G::Weight>
+where
+    G: GraphBase,
+    G::Weight: Ord + Add<Output = G::Weight> + Zero,
+{
+    let node_count = graph.node_count();
+    let mut distances: Vec<Option<G::Weight>> = vec![None; node_count];
+    let mut predecessors = vec![None; node_count];
+    let mut settled = vec![false; node_count];
+
+    let mut heap = IndexedHeap::new(node_count);
+    distances[source.index()] = Some(G::Weight::zero());
+    heap.push(source.index(), G::Weight::zero());
+
+    while let Some((index, distance)) = heap.pop() {
+        settled[index] = true;
+
+        let node = G::Node::from_index(index);
+        if Some(node) == target {
+            break;
+        }
+
+        for (neighbor, weight) in graph.neighbors(node) {
+            let candidate = distance + weight;
+            if !settled[neighbor.index()] && heap.push_decrease(neighbor.index(), candidate) {
+                distances[neighbor.index()] = Some(candidate);
+                predecessors[neighbor.index()] = Some(node);
+            }
+        }
+    }
+
+    ShortestPaths::new(source, distances, predecessors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::{Graph, MatrixGraph};
+
+    fn sample() -> Graph<usize, u32> {
+        Graph::directed_from_edges(6, [(0, 1, 7), (0, 2, 9), (0, 5, 14), (1, 2, 10), (1, 3, 15), (2, 3, 11), (2, 5, 2), (3, 4, 6), (5, 4, 9)])
+    }
+
+    #[test]
+    fn distances_from_source_should_be_computed_correctly() {
+        let paths = dijkstra(&sample(), 0);
+
+        assert_eq!(paths.distances(), &[Some(0), Some(7), Some(9), Some(20), Some(20), Some(11)]);
+    }
+
+    #[test]
+    fn shortest_path_should_be_reconstructed() {
+        let paths = dijkstra(&sample(), 0);
+
+        assert_eq!(paths.path_to(4), Some(vec![0, 2, 5, 4]));
+        assert_eq!(paths.path_to(0), Some(vec![0]));
+        assert_eq!(paths.predecessor(0), None);
+    }
+
+    #[test]
+    fn unreachable_nodes_should_have_no_distance() {
+        let graph: Graph<usize, u32> = Graph::directed_from_edges(3, [(1, 0, 1)]);
+
+        let paths = dijkstra(&graph, 0);
+
+        assert_eq!(paths.distance(1), None);
+        assert_eq!(paths.path_to(2), None);
+    }
+
+    #[test]
+    fn single_target_search_should_find_shortest_path() {
+        assert_eq!(dijkstra_to(&sample(), 0, 3), Some((20, vec![0, 2, 3])));
+        assert_eq!(dijkstra_to(&sample(), 4, 0), None);
+    }
+
+    #[test]
+    fn undirected_matrix_graph_should_be_supported() {
+        let graph: MatrixGraph<usize, i64> = MatrixGraph::undirected_from_edges(4, [(0, 1, 1), (1, 2, 1), (0, 2, 5), (2, 3, 1)]);
+
+        let paths = dijkstra(&graph, 3);
+
+        assert_eq!(paths.distance(0), Some(3));
+        assert_eq!(paths.path_to(0), Some(vec![3, 2, 1, 0]));
+    }
+}
@@ -0,0 +1,559 @@
+//! This module contains export to and import from the Graphviz DOT language.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::graphs::{Edge, Graph, GraphBase, NodeId};
+
+/// Writes the graph in the DOT language, naming every node by its index.
+pub fn to_dot<G: GraphBase>(graph: &G) -> String {
+    to_dot_with(graph, |_| None, |_| None)
+}
+
+/// Writes the graph in the DOT language with labels on nodes and edges.
+///
+/// Note: every node is declared on its own line before the edges, so isolated nodes
+/// are kept and parsing the output numbers the nodes as in the graph.
+///
+/// Arguments:
+///
+/// * `graph`: The graph to write, undirected graphs become `graph` and directed ones `digraph`.
+/// * `node_label`: Returns the label of a node, `None` for no label.
+/// * `edge_label`: Returns the label of an edge, `None` for no label.
+pub fn to_dot_with<G, NL, EL>(graph: &G, node_label: NL, edge_label: EL) -> String
+where
+    G: GraphBase,
+    NL: Fn(G::Node) -> Option<String>,
+    EL: Fn(&Edge<G::Node, G::Weight>) -> Option<String>,
+{
+    let (keyword, operator) = if graph.is_directed() { ("digraph", "->") } else { ("graph", "--") };
+
+    let mut output = format!("{keyword} {{\n");
+    for node in graph.nodes() {
+        write!(output, "    {}", node.index()).unwrap();
+        write_label(&mut output, node_label(node));
+    }
+
+    for edge in graph.edges() {
+        write!(output, "    {} {} {}", edge.from.index(), operator, edge.to.index()).unwrap();
+        write_label(&mut output, edge_label(&edge));
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+fn write_label(output: &mut String, label: Option<String>) {
+    if let Some(label) = label {
+        output.push_str(" [label=\"");
+        for c in label.chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                c => output.push(c),
+            }
+        }
+        output.push_str("\"]");
+    }
+
+    output.push_str(";\n");
+}
+
+/// Graph read from the DOT language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotGraph {
+    /// The graph, nodes are numbered in the order they first appear.
+    pub graph: Graph,
+    /// The DOT identifier of every node.
+    pub names: Vec<String>,
+    /// The `label` attribute of every node.
+    pub node_labels: Vec<Option<String>>,
+    /// The `label` attribute of every edge, in the order of `graph.edges()`.
+    pub edge_labels: Vec<Option<String>>,
+    // The node of every DOT identifier.
+    indices: BTreeMap<String, usize>,
+}
+
+impl DotGraph {
+    /// Returns the node with the given DOT identifier in O(log n).
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+}
+
+/// Error produced when parsing malformed or unsupported DOT input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDotError {
+    /// The input ended before the graph was closed.
+    UnexpectedEnd,
+    /// A quoted string or a comment is not closed.
+    Unterminated {
+        /// The line where the string or comment starts.
+        line: usize,
+    },
+    /// The input contains a token that is not valid at its position or not supported, like subgraphs.
+    UnexpectedToken {
+        /// The line of the token.
+        line: usize,
+        /// The text of the token.
+        token: String,
+    },
+}
+
+impl fmt::Display for ParseDotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDotError::UnexpectedEnd => write!(f, "DOT input ended unexpectedly"),
+            ParseDotError::Unterminated { line } => write!(f, "unterminated string or comment starting on line {line}"),
+            ParseDotError::UnexpectedToken { line, token } => write!(f, "unexpected `{token}` on line {line}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDotError {}
+
+/// Reads a graph written in a subset of the DOT language.
+///
+/// Note:
+///     Node statements, edge chains like `a -> b -> c` and attribute lists are supported,
+///     along with comments and quoted identifiers, which are never taken as keywords.
+///     Only `label` attributes are kept.
+///     Graph-wide attribute statements are skipped, while subgraphs and ports are rejected.
+///     `strict` is accepted, but repeated edges are kept.
+pub fn parse_dot(input: &str) -> Result<DotGraph, ParseDotError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, position: 0, names: Vec::new(), node_labels: Vec::new(), indices: BTreeMap::new() };
+    parser.parse()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Id(String),
+    Quoted(String),
+    Arrow,
+    Line,
+    OpenBrace,
+    CloseBrace,
+    OpenBracket,
+    CloseBracket,
+    Equals,
+    Semicolon,
+    Comma,
+    Other(char),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Id(id) => write!(f, "{id}"),
+            Token::Quoted(id) => write!(f, "\"{id}\""),
+            Token::Arrow => write!(f, "->"),
+            Token::Line => write!(f, "--"),
+            Token::OpenBrace => write!(f, "{{"),
+            Token::CloseBrace => write!(f, "}}"),
+            Token::OpenBracket => write!(f, "["),
+            Token::CloseBracket => write!(f, "]"),
+            Token::Equals => write!(f, "="),
+            Token::Semicolon => write!(f, ";"),
+            Token::Comma => write!(f, ","),
+            Token::Other(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, ParseDotError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while let Some(&c) = chars.get(i) {
+        let next = chars.get(i + 1).copied();
+        let start_line = line;
+
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            // Lines starting with '#' are preprocessor output and ignored.
+            '#' if tokens.last().is_none_or(|&(_, last_line)| last_line < line) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                loop {
+                    match (chars.get(i), chars.get(i + 1)) {
+                        (Some('*'), Some('/')) => break,
+                        (Some(c), _) => {
+                            if *c == '\n' {
+                                line += 1;
+                            }
+                            i += 1;
+                        }
+                        (None, _) => return Err(ParseDotError::Unterminated { line: start_line }),
+                    }
+                }
+                i += 2;
+            }
+            '"' => {
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('"') => break,
+                        Some('\\') if matches!(chars.get(i + 1), Some('"' | '\\')) => {
+                            value.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some('\\') if chars.get(i + 1) == Some(&'n') => {
+                            value.push('\n');
+                            i += 2;
+                        }
+                        // A backslash before a line break continues the string on the next line.
+                        Some('\\') if chars.get(i + 1) == Some(&'\n') => {
+                            line += 1;
+                            i += 2;
+                        }
+                        Some(&c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            value.push(c);
+                            i += 1;
+                        }
+                        None => return Err(ParseDotError::Unterminated { line: start_line }),
+                    }
+                }
+                i += 1;
+                tokens.push((Token::Quoted(value), start_line));
+            }
+            '-' if next == Some('>') => {
+                tokens.push((Token::Arrow, line));
+                i += 2;
+            }
+            '-' if next == Some('-') => {
+                tokens.push((Token::Line, line));
+                i += 2;
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push((Token::Id(chars[start..i].iter().collect()), line));
+            }
+            c => {
+                let token = match c {
+                    '{' => Token::OpenBrace,
+                    '}' => Token::CloseBrace,
+                    '[' => Token::OpenBracket,
+                    ']' => Token::CloseBracket,
+                    '=' => Token::Equals,
+                    ';' => Token::Semicolon,
+                    ',' => Token::Comma,
+                    c => Token::Other(c),
+                };
+                tokens.push((token, line));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    names: Vec<String>,
+    node_labels: Vec<Option<String>>,
+    indices: BTreeMap<String, usize>,
+}
+
+impl Parser {
+    fn parse(&mut self) -> Result<DotGraph, ParseDotError> {
+        if self.peek_keyword("strict") {
+            self.position += 1;
+        }
+
+        let directed = if self.peek_keyword("digraph") {
+            true
+        } else if self.peek_keyword("graph") {
+            false
+        } else {
+            return Err(self.unexpected());
+        };
+        self.position += 1;
+
+        if let Some(Token::Id(_) | Token::Quoted(_)) = self.peek() {
+            self.position += 1;
+        }
+        self.expect(Token::OpenBrace)?;
+
+        let mut edges = Vec::new();
+        let mut edge_labels = Vec::new();
+        loop {
+            match self.peek() {
+                None => return Err(ParseDotError::UnexpectedEnd),
+                Some(Token::CloseBrace) => {
+                    self.position += 1;
+                    break;
+                }
+                Some(Token::Semicolon) => self.position += 1,
+                Some(Token::Id(_) | Token::Quoted(_)) => self.statement(directed, &mut edges, &mut edge_labels)?,
+                Some(_) => return Err(self.unexpected()),
+            }
+        }
+
+        if self.peek().is_some() {
+            return Err(self.unexpected());
+        }
+
+        let node_count = self.names.len();
+        let graph = if directed {
+            Graph::directed_from_pairs(node_count, edges)
+        } else {
+            Graph::undirected_from_pairs(node_count, edges)
+        };
+
+        Ok(DotGraph {
+            graph,
+            names: core::mem::take(&mut self.names),
+            node_labels: core::mem::take(&mut self.node_labels),
+            edge_labels,
+            indices: core::mem::take(&mut self.indices),
+        })
+    }
+
+    fn statement(
+        &mut self,
+        directed: bool,
+        edges: &mut Vec<(usize, usize)>,
+        edge_labels: &mut Vec<Option<String>>,
+    ) -> Result<(), ParseDotError> {
+        if ["graph", "node", "edge"].iter().any(|keyword| self.peek_keyword(keyword)) {
+            self.position += 1;
+            self.attributes()?;
+            return Ok(());
+        }
+
+        if self.peek_keyword("subgraph") {
+            return Err(self.unexpected());
+        }
+
+        let first = self.identifier()?;
+        if self.peek() == Some(&Token::Equals) {
+            self.position += 1;
+            self.identifier()?;
+            return Ok(());
+        }
+
+        let mut chain = vec![first];
+        loop {
+            match self.peek() {
+                Some(Token::Arrow) if directed => self.position += 1,
+                Some(Token::Line) if !directed => self.position += 1,
+                _ => break,
+            }
+            chain.push(self.identifier()?);
+        }
+
+        let label = self.attributes()?;
+        let nodes: Vec<usize> = chain.into_iter().map(|name| self.node(name)).collect();
+        if nodes.len() == 1 {
+            if label.is_some() {
+                self.node_labels[nodes[0]] = label;
+            }
+        } else {
+            for pair in nodes.windows(2) {
+                edges.push((pair[0], pair[1]));
+                edge_labels.push(label.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    // Parses any number of bracketed attribute lists and returns the last label among them.
+    fn attributes(&mut self) -> Result<Option<String>, ParseDotError> {
+        let mut label = None;
+        while self.peek() == Some(&Token::OpenBracket) {
+            self.position += 1;
+            loop {
+                match self.peek() {
+                    Some(Token::CloseBracket) => {
+                        self.position += 1;
+                        break;
+                    }
+                    Some(Token::Semicolon | Token::Comma) => self.position += 1,
+                    Some(Token::Id(_) | Token::Quoted(_)) => {
+                        let key = self.identifier()?;
+                        self.expect(Token::Equals)?;
+                        let value = self.identifier()?;
+                        if key == "label" {
+                            label = Some(value);
+                        }
+                    }
+                    Some(_) => return Err(self.unexpected()),
+                    None => return Err(ParseDotError::UnexpectedEnd),
+                }
+            }
+        }
+
+        Ok(label)
+    }
+
+    fn node(&mut self, name: String) -> usize {
+        if let Some(&node) = self.indices.get(&name) {
+            return node;
+        }
+
+        let node = self.names.len();
+        self.indices.insert(name.clone(), node);
+        self.names.push(name);
+        self.node_labels.push(None);
+        node
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Id(id)) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn identifier(&mut self) -> Result<String, ParseDotError> {
+        match self.tokens.get(self.position) {
+            Some((Token::Id(id) | Token::Quoted(id), _)) => {
+                self.position += 1;
+                Ok(id.clone())
+            }
+            Some(_) => Err(self.unexpected()),
+            None => Err(ParseDotError::UnexpectedEnd),
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ParseDotError> {
+        match self.peek() {
+            Some(token) if *token == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(_) => Err(self.unexpected()),
+            None => Err(ParseDotError::UnexpectedEnd),
+        }
+    }
+
+    fn unexpected(&self) -> ParseDotError {
+        match self.tokens.get(self.position) {
+            Some((token, line)) => ParseDotError::UnexpectedToken { line: *line, token: token.to_string() },
+            None => ParseDotError::UnexpectedEnd,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directed_graph_should_be_exported() {
+        let graph: Graph<usize, i32> = Graph::directed_from_edges(3, [(0, 1, 5), (1, 2, -2)]);
+
+        let dot = to_dot_with(&graph, |node| Some(format!("v{node}")), |edge| Some(edge.weight.to_string()));
+
+        assert_eq!(
+            dot,
+            "digraph {\n    0 [label=\"v0\"];\n    1 [label=\"v1\"];\n    2 [label=\"v2\"];\n    0 -> 1 [label=\"5\"];\n    1 -> 2 [label=\"-2\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn undirected_graph_should_be_exported_without_labels() {
+        let graph: Graph = Graph::undirected_from_pairs(3, [(0, 1)]);
+
+        assert_eq!(to_dot(&graph), "graph {\n    0;\n    1;\n    2;\n    0 -- 1;\n}\n");
+    }
+
+    #[test]
+    fn exported_graph_should_parse_back() {
+        let graph: Graph = Graph::directed_from_pairs(5, [(0, 1), (1, 2), (2, 0), (3, 1), (1, 3)]);
+        let dot = to_dot_with(&graph, |node| (node == 4).then(|| "say \"hi\"\\".to_string()), |_| None);
+
+        let parsed = parse_dot(&dot).unwrap();
+
+        assert_eq!(parsed.graph, graph);
+        assert_eq!(parsed.node_labels[4].as_deref(), Some("say \"hi\"\\"));
+        assert_eq!(parsed.edge_labels, vec![None; 5]);
+    }
+
+    #[test]
+    fn handwritten_dot_should_be_parsed() {
+        let input = r#"
+            // A small network.
+            strict digraph Network {
+                rankdir = LR;
+                node [shape=box];
+                a [label="Start"]
+                a -> b -> "c d" [color=red, label="chain"];
+                /* Isolated node
+                   spanning lines. */
+                e
+            }
+        "#;
+
+        let parsed = parse_dot(input).unwrap();
+
+        assert_eq!(parsed.names, vec!["a", "b", "c d", "e"]);
+        assert_eq!(parsed.node_labels[parsed.node("a").unwrap()].as_deref(), Some("Start"));
+        assert_eq!(parsed.graph.edges().map(|edge| (edge.from, edge.to)).collect::<Vec<_>>(), vec![(0, 1), (1, 2)]);
+        assert_eq!(parsed.edge_labels, vec![Some("chain".to_string()); 2]);
+        assert!(parsed.graph.is_directed());
+    }
+
+    #[test]
+    fn malformed_dot_should_be_rejected() {
+        assert_eq!(parse_dot("graph { a -- b"), Err(ParseDotError::UnexpectedEnd));
+        assert_eq!(parse_dot("graph {\n a -> b }"), Err(ParseDotError::UnexpectedToken { line: 2, token: "->".to_string() }));
+        assert_eq!(parse_dot("digraph { a [label=\"x] }"), Err(ParseDotError::Unterminated { line: 1 }));
+        assert!(matches!(parse_dot("digraph { subgraph s { a } }"), Err(ParseDotError::UnexpectedToken { .. })));
+    }
+
+    #[test]
+    fn quoted_keywords_should_be_identifiers() {
+        let parsed = parse_dot("digraph { \"graph\" -> \"node\"; \"subgraph\" [label=\"edge\"] }").unwrap();
+
+        assert_eq!(parsed.names, vec!["graph", "node", "subgraph"]);
+        assert_eq!(parsed.node("subgraph"), Some(2));
+        assert_eq!(parsed.node_labels[2].as_deref(), Some("edge"));
+        assert_eq!(parse_dot("\"graph\" { }"), Err(ParseDotError::UnexpectedToken { line: 1, token: "\"graph\"".to_string() }));
+    }
+
+    #[test]
+    fn many_nodes_should_be_parsed_quickly() {
+        let graph: Graph = Graph::directed_from_pairs(50_000, (0..49_999).map(|node| (node, node + 1)));
+
+        let parsed = parse_dot(&to_dot(&graph)).unwrap();
+
+        assert_eq!(parsed.graph, graph);
+        assert_eq!(parsed.node("49999"), Some(49_999));
+        assert_eq!(parsed.node("50000"), None);
+    }
+}
@@ -0,0 +1,207 @@
+//! This module contains Eulerian path and circuit construction.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{Edge, GraphBase, NodeId};
+
+/// Finds a path that uses every edge exactly once.
+///
+/// Note:
+///     Degree conditions are checked first: in a directed graph every node must have equal
+///     in- and out-degree, except possibly a start with one extra outgoing edge and an end
+///     with one extra incoming edge; in an undirected graph at most two nodes may have odd
+///     degree. The path is then built with Hierholzer's algorithm in O(V + E). When a
+///     circuit exists, the returned path is a circuit.
+///
+/// Returns:
+///
+/// The edges in the order they are walked, oriented along the walk, or `None` when there is no such path.
+pub fn euler_path<G: GraphBase>(graph: &G) -> Option<Vec<Edge<G::Node, G::Weight>>> {
+    let start = start_node(graph)?;
+    hierholzer(graph, start)
+}
+
+/// Finds a closed walk that uses every edge exactly once.
+///
+/// Returns:
+///
+/// The edges in the order they are walked, oriented along the walk, or `None` when there is no such circuit.
+pub fn euler_circuit<G: GraphBase>(graph: &G) -> Option<Vec<Edge<G::Node, G::Weight>>> {
+    let path = euler_path(graph)?;
+
+    match (path.first(), path.last()) {
+        (Some(first), Some(last)) if first.from != last.to => None,
+        _ => Some(path),
+    }
+}
+
+/// Picks the node the walk must start from, `None` when the degrees rule out a path.
+fn start_node<G: GraphBase>(graph: &G) -> Option<usize> {
+    let node_count = graph.node_count();
+    // balance[v] is out-degree minus in-degree for directed graphs and the degree for undirected ones.
+    let mut balance = vec![0isize; node_count];
+    for edge in graph.edges() {
+        if graph.is_directed() {
+            balance[edge.from.index()] += 1;
+            balance[edge.to.index()] -= 1;
+        } else {
+            balance[edge.from.index()] += 1;
+            balance[edge.to.index()] += 1;
+        }
+    }
+
+    let any_with_edges = graph.edges().next().map(|edge| edge.from.index()).unwrap_or(0);
+
+    if graph.is_directed() {
+        let starts: Vec<usize> = (0..node_count).filter(|&node| balance[node] == 1).collect();
+        let ends = (0..node_count).filter(|&node| balance[node] == -1).count();
+        let unbalanced = (0..node_count).filter(|&node| balance[node].abs() > 1).count();
+
+        match (starts.as_slice(), ends, unbalanced) {
+            ([], 0, 0) => Some(any_with_edges),
+            ([start], 1, 0) => Some(*start),
+            _ => None,
+        }
+    } else {
+        let odd: Vec<usize> = (0..node_count).filter(|&node| balance[node] % 2 != 0).collect();
+
+        match odd.as_slice() {
+            [] => Some(any_with_edges),
+            [start, _] => Some(*start),
+            _ => None,
+        }
+    }
+}
+
+fn hierholzer<G: GraphBase>(graph: &G, start: usize) -> Option<Vec<Edge<G::Node, G::Weight>>> {
+    let edges: Vec<Edge<G::Node, G::Weight>> = graph.edges().collect();
+
+    let mut adjacency = vec![Vec::new(); graph.node_count()];
+    for (id, edge) in edges.iter().enumerate() {
+        adjacency[edge.from.index()].push((edge.to.index(), id));
+        if !graph.is_directed() && edge.from != edge.to {
+            adjacency[edge.to.index()].push((edge.from.index(), id));
+        }
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut next = vec![0; graph.node_count()];
+    let mut walk = Vec::with_capacity(edges.len());
+
+    // The stack holds the current trail, every entry with the edge that led to it.
+    let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+    while let Some(&(node, via)) = stack.last() {
+        while next[node] < adjacency[node].len() && used[adjacency[node][next[node]].1] {
+            next[node] += 1;
+        }
+
+        match adjacency[node].get(next[node]) {
+            Some(&(neighbor, id)) => {
+                used[id] = true;
+                stack.push((neighbor, Some(id)));
+            }
+            None => {
+                stack.pop();
+                if let (Some(id), Some(&(previous, _))) = (via, stack.last()) {
+                    let weight = edges[id].weight;
+                    walk.push(Edge::new(G::Node::from_index(previous), G::Node::from_index(node), weight));
+                }
+            }
+        }
+    }
+
+    // Edges left unused lie in another component.
+    if walk.len() < edges.len() {
+        return None;
+    }
+
+    walk.reverse();
+    Some(walk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn is_trail_over_every_edge(graph: &Graph, path: &[Edge<usize, ()>]) -> bool {
+        let consecutive = path.windows(2).all(|pair| pair[0].to == pair[1].from);
+
+        let mut remaining: Vec<(usize, usize)> = graph.edges().map(|edge| (edge.from, edge.to)).collect();
+        let covers = path.iter().all(|edge| {
+            let position = remaining.iter().position(|&(from, to)| (from, to) == (edge.from, edge.to) || (!graph.is_directed() && (to, from) == (edge.from, edge.to)));
+            position.map(|position| remaining.swap_remove(position)).is_some()
+        });
+
+        consecutive && covers && remaining.is_empty()
+    }
+
+    #[test]
+    fn directed_path_should_start_at_the_node_with_extra_outgoing_edge() {
+        let graph: Graph = Graph::directed_from_pairs(4, [(1, 2), (2, 3), (3, 1), (0, 1)]);
+
+        let path = euler_path(&graph).unwrap();
+
+        assert_eq!(path.iter().map(|edge| edge.from).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(euler_circuit(&graph), None);
+    }
+
+    #[test]
+    fn undirected_circuit_should_be_found() {
+        // Two triangles sharing node 0.
+        let graph: Graph = Graph::undirected_from_pairs(5, [(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0)]);
+
+        let circuit = euler_circuit(&graph).unwrap();
+
+        assert!(is_trail_over_every_edge(&graph, &circuit));
+        assert_eq!(circuit.first().unwrap().from, circuit.last().unwrap().to);
+    }
+
+    #[test]
+    fn undirected_path_should_run_between_odd_nodes() {
+        let graph: Graph = Graph::undirected_from_pairs(4, [(0, 1), (1, 2), (2, 3), (3, 1)]);
+
+        let path = euler_path(&graph).unwrap();
+
+        assert!(is_trail_over_every_edge(&graph, &path));
+        assert_eq!((path.first().unwrap().from, path.last().unwrap().to), (0, 1));
+    }
+
+    #[test]
+    fn too_many_odd_nodes_should_rule_out_a_path() {
+        let graph: Graph = Graph::undirected_from_pairs(4, [(0, 1), (0, 2), (0, 3)]);
+
+        assert_eq!(euler_path(&graph), None);
+    }
+
+    #[test]
+    fn disconnected_edges_should_rule_out_a_path() {
+        let graph: Graph = Graph::directed_from_pairs(4, [(0, 1), (1, 0), (2, 3), (3, 2)]);
+
+        assert_eq!(euler_path(&graph), None);
+    }
+
+    #[test]
+    fn random_eulerian_graphs_should_get_circuits() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            // A random closed walk is an Eulerian circuit of the graph made of its edges.
+            let n = rng.gen_range(1..8);
+            let mut walk = vec![0];
+            for _ in 0..rng.gen_range(1..20) {
+                walk.push(rng.gen_range(0..n));
+            }
+            walk.push(0);
+
+            let pairs: Vec<(usize, usize)> = walk.windows(2).map(|pair| (pair[0], pair[1])).collect();
+            let directed: Graph = Graph::directed_from_pairs(n, pairs.iter().copied());
+            let undirected: Graph = Graph::undirected_from_pairs(n, pairs.iter().copied());
+
+            assert!(is_trail_over_every_edge(&directed, &euler_circuit(&directed).unwrap()));
+            assert!(is_trail_over_every_edge(&undirected, &euler_circuit(&undirected).unwrap()));
+        }
+    }
+}
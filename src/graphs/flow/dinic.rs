@@ -0,0 +1,215 @@
+//! This module contains Dinic's maximum flow algorithm.
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Sub};
+
+use num::Zero;
+
+use crate::graphs::flow::network::{EdgeId, FlowNetwork};
+
+/// Maximum flow between two nodes of a network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow<C> {
+    /// The amount of flow from the source to the sink.
+    pub value: C,
+    /// The flow along every added edge, indexed by `EdgeId`.
+    pub edge_flows: Vec<C>,
+    /// Nodes still reachable from the source in the residual network, the source side of a minimum cut.
+    pub source_side: Vec<bool>,
+}
+
+impl<C: Copy> MaxFlow<C> {
+    /// Returns the flow along the edge.
+    pub fn flow(&self, id: EdgeId) -> C {
+        self.edge_flows[id.0]
+    }
+
+    /// Returns the edges crossing the minimum cut, their capacities sum up to the flow value.
+    pub fn cut_edges(&self, network: &FlowNetwork<C>) -> Vec<EdgeId> {
+        (0..network.edge_count())
+            .map(EdgeId)
+            .filter(|&id| {
+                let (from, to, _) = network.edge(id);
+                self.source_side[from] && !self.source_side[to]
+            })
+            .collect()
+    }
+}
+
+/// Finds a maximum flow from the source to the sink.
+///
+/// Note:
+///     This function uses Dinic's algorithm: it repeatedly builds the level graph of
+///     shortest residual paths with a breadth-first search and saturates it with a
+///     blocking flow. Runs in O(V^2 * E), and O(E * sqrt(V)) on unit networks.
+///
+/// Panics when the source and the sink are the same node.
+pub fn dinic<C>(network: &FlowNetwork<C>, source: usize, sink: usize) -> MaxFlow<C>
+where
+    C: Copy + Ord + Add<Output = C> + Sub<Output = C> + Zero,
+{
+    assert_ne!(source, sink, "The source and the sink must differ");
+
+    let node_count = network.node_count();
+    let mut residual: Vec<C> = network.arcs.iter().map(|arc| arc.capacity).collect();
+    let mut value = C::zero();
+
+    loop {
+        let levels = levels(network, &residual, source);
+        if levels[sink] == usize::MAX {
+            break;
+        }
+
+        // next[v] is the first arc of v that may still lead to the sink in the level graph.
+        let mut next = vec![0; node_count];
+        let mut path: Vec<usize> = Vec::new();
+        let mut node = source;
+        loop {
+            if node == sink {
+                let bottleneck = path.iter().map(|&arc| residual[arc]).min().unwrap();
+                for &arc in &path {
+                    residual[arc] = residual[arc] - bottleneck;
+                    residual[arc ^ 1] = residual[arc ^ 1] + bottleneck;
+                }
+
+                value = value + bottleneck;
+                path.clear();
+                node = source;
+                continue;
+            }
+
+            let advance = network.adjacency[node][next[node]..]
+                .iter()
+                .position(|&arc| residual[arc] > C::zero() && levels[network.arcs[arc].to] == levels[node] + 1);
+
+            match advance {
+                Some(offset) => {
+                    next[node] += offset;
+                    let arc = network.adjacency[node][next[node]];
+                    path.push(arc);
+                    node = network.arcs[arc].to;
+                }
+                None => {
+                    // A dead end: step back and never try the arc leading here again.
+                    next[node] = network.adjacency[node].len();
+                    let Some(arc) = path.pop() else {
+                        break;
+                    };
+
+                    node = network.arcs[arc ^ 1].to;
+                    next[node] += 1;
+                }
+            }
+        }
+    }
+
+    let edge_flows = (0..network.edge_count()).map(|id| residual[2 * id + 1] - network.arcs[2 * id + 1].capacity).collect();
+    let levels = levels(network, &residual, source);
+    let source_side = levels.iter().map(|&level| level != usize::MAX).collect();
+
+    MaxFlow { value, edge_flows, source_side }
+}
+
+/// Distances from the source in edges of the residual network, usize::MAX when unreachable.
+fn levels<C: Copy + Ord + Zero>(network: &FlowNetwork<C>, residual: &[C], source: usize) -> Vec<usize> {
+    let mut levels = vec![usize::MAX; network.node_count()];
+    levels[source] = 0;
+
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        for &arc in &network.adjacency[node] {
+            let to = network.arcs[arc].to;
+            if residual[arc] > C::zero() && levels[to] == usize::MAX {
+                levels[to] = levels[node] + 1;
+                queue.push_back(to);
+            }
+        }
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn sample() -> FlowNetwork<u32> {
+        let mut network = FlowNetwork::new(6);
+        for (from, to, capacity) in [(0, 1, 16), (0, 2, 13), (1, 2, 10), (2, 1, 4), (1, 3, 12), (3, 2, 9), (2, 4, 14), (4, 3, 7), (3, 5, 20), (4, 5, 4)] {
+            network.add_edge(from, to, capacity);
+        }
+
+        network
+    }
+
+    fn check_flow(network: &FlowNetwork<i64>, flow: &MaxFlow<i64>, source: usize, sink: usize) {
+        let mut balance = vec![0i64; network.node_count()];
+        for id in 0..network.edge_count() {
+            let (from, to, capacity) = network.edge(EdgeId(id));
+            let edge_flow = flow.flow(EdgeId(id));
+
+            assert!((0..=capacity).contains(&edge_flow));
+            balance[from] -= edge_flow;
+            balance[to] += edge_flow;
+        }
+
+        for (node, &node_balance) in balance.iter().enumerate() {
+            let expected = if node == source { -flow.value } else if node == sink { flow.value } else { 0 };
+            assert_eq!(node_balance, expected);
+        }
+
+        let cut: i64 = flow.cut_edges(network).iter().map(|&id| network.edge(id).2).sum();
+        assert_eq!(cut, flow.value);
+    }
+
+    #[test]
+    fn maximum_flow_of_classic_network_should_be_23() {
+        let network = sample();
+
+        let flow = dinic(&network, 0, 5);
+
+        assert_eq!(flow.value, 23);
+    }
+
+    #[test]
+    fn minimum_cut_should_separate_source_and_sink() {
+        let network = sample();
+
+        let flow = dinic(&network, 0, 5);
+        let cut: u32 = flow.cut_edges(&network).iter().map(|&id| network.edge(id).2).sum();
+
+        assert!(flow.source_side[0] && !flow.source_side[5]);
+        assert_eq!(cut, 23);
+    }
+
+    #[test]
+    fn disconnected_sink_should_get_no_flow() {
+        let mut network = FlowNetwork::new(3);
+        network.add_edge(0, 1, 5);
+
+        let flow = dinic(&network, 0, 2);
+
+        assert_eq!(flow.value, 0);
+        assert_eq!(flow.source_side, vec![true, true, false]);
+    }
+
+    #[test]
+    fn flows_should_be_valid_on_random_networks() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let n = rng.gen_range(2..15);
+            let mut network = FlowNetwork::new(n);
+            for _ in 0..rng.gen_range(0..50) {
+                network.add_edge(rng.gen_range(0..n), rng.gen_range(0..n), rng.gen_range(0..20));
+            }
+
+            let flow = dinic(&network, 0, n - 1);
+
+            check_flow(&network, &flow, 0, n - 1);
+        }
+    }
+}
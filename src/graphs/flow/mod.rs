@@ -0,0 +1,5 @@
+pub mod network;
+pub mod dinic;
+
+pub use network::{EdgeId, FlowNetwork};
+pub use dinic::{dinic, MaxFlow};
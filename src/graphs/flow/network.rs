@@ -0,0 +1,121 @@
+//! This module contains the flow network that maximum flow algorithms run on.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Sub};
+
+use num::Zero;
+
+use crate::graphs::{GraphBase, NodeId};
+
+/// Identifier of an edge added to a flow network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeId(pub usize);
+
+/// An edge of the residual network; edges come in pairs, 2i is the added one, 2i + 1 its reverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Arc<C> {
+    pub(crate) to: usize,
+    pub(crate) capacity: C,
+}
+
+/// Directed network of nodes 0..n with edge capacities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowNetwork<C> {
+    pub(crate) arcs: Vec<Arc<C>>,
+    pub(crate) adjacency: Vec<Vec<usize>>,
+}
+
+impl<C: Copy> FlowNetwork<C> {
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Returns the number of added edges.
+    pub fn edge_count(&self) -> usize {
+        self.arcs.len() / 2
+    }
+
+    /// Returns the endpoints and the capacity of the edge.
+    pub fn edge(&self, id: EdgeId) -> (usize, usize, C) {
+        let arc = self.arcs[2 * id.0];
+        (self.arcs[2 * id.0 + 1].to, arc.to, arc.capacity)
+    }
+}
+
+impl<C> FlowNetwork<C>
+where
+    C: Copy + Ord + Add<Output = C> + Sub<Output = C> + Zero,
+{
+    /// Creates a network with the given number of nodes and no edges.
+    pub fn new(node_count: usize) -> Self {
+        FlowNetwork { arcs: Vec::new(), adjacency: vec![Vec::new(); node_count] }
+    }
+
+    /// Creates a network from a graph, using edge weights as capacities.
+    ///
+    /// Note: an undirected edge becomes two opposite edges with the same capacity.
+    pub fn from_graph<G: GraphBase<Weight = C>>(graph: &G) -> Self {
+        let mut network = Self::new(graph.node_count());
+        for edge in graph.edges() {
+            network.add_edge(edge.from.index(), edge.to.index(), edge.weight);
+            if !graph.is_directed() {
+                network.add_edge(edge.to.index(), edge.from.index(), edge.weight);
+            }
+        }
+
+        network
+    }
+
+    /// Adds a node and returns it.
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(Vec::new());
+        self.adjacency.len() - 1
+    }
+
+    /// Adds an edge with the given capacity.
+    ///
+    /// Panics when some endpoint is not a node or the capacity is negative.
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: C) -> EdgeId {
+        assert!(from < self.node_count() && to < self.node_count(), "Edge {} -> {} has an endpoint outside of {} nodes", from, to, self.node_count());
+        assert!(capacity >= C::zero(), "Capacities must be non-negative");
+
+        let id = self.arcs.len();
+        self.arcs.push(Arc { to, capacity });
+        self.arcs.push(Arc { to: from, capacity: C::zero() });
+        self.adjacency[from].push(id);
+        self.adjacency[to].push(id + 1);
+
+        EdgeId(id / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+
+    #[test]
+    fn added_edges_should_be_retrievable() {
+        let mut network = FlowNetwork::new(2);
+        let node = network.add_node();
+
+        let first = network.add_edge(0, 1, 5);
+        let second = network.add_edge(1, node, 3);
+
+        assert_eq!(network.edge(first), (0, 1, 5));
+        assert_eq!(network.edge(second), (1, 2, 3));
+        assert_eq!(network.edge_count(), 2);
+    }
+
+    #[test]
+    fn undirected_graph_should_get_edges_in_both_directions() {
+        let graph: Graph<usize, u32> = Graph::undirected_from_edges(2, [(0, 1, 4)]);
+
+        let network = FlowNetwork::from_graph(&graph);
+
+        assert_eq!(network.edge(EdgeId(0)), (0, 1, 4));
+        assert_eq!(network.edge(EdgeId(1)), (1, 0, 4));
+    }
+}
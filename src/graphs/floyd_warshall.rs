@@ -0,0 +1,295 @@
+//! This module contains the Floyd–Warshall all-pairs shortest path algorithm.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use num::Zero;
+
+use crate::graphs::bellman_ford::NegativeCycle;
+use crate::graphs::{GraphBase, MatrixGraph, NodeId};
+
+/// Shortest paths between every pair of nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllPairsShortestPaths<N, W> {
+    node_count: usize,
+    distances: Vec<Option<W>>,
+    successors: Vec<Option<N>>,
+}
+
+impl<N: NodeId, W: Copy> AllPairsShortestPaths<N, W> {
+    /// Returns the distance between the nodes, `None` when `to` is unreachable from `from`.
+    ///
+    /// Panics when some node is not a node of the graph.
+    pub fn distance(&self, from: N, to: N) -> Option<W> {
+        self.distances[self.cell(from, to)]
+    }
+
+    /// Returns the node following `from` on a shortest path to `to`.
+    ///
+    /// Panics when some node is not a node of the graph.
+    pub fn successor(&self, from: N, to: N) -> Option<N> {
+        self.successors[self.cell(from, to)]
+    }
+
+    /// Returns the nodes of a shortest path between the nodes, both inclusive.
+    pub fn path(&self, from: N, to: N) -> Option<Vec<N>> {
+        self.distance(from, to)?;
+
+        let mut path = vec![from];
+        let mut current = from;
+        while current != to {
+            current = self.successor(current, to)?;
+            path.push(current);
+        }
+
+        Some(path)
+    }
+
+    fn cell(&self, from: N, to: N) -> usize {
+        assert!(from.index() < self.node_count && to.index() < self.node_count, "Pair {:?} -> {:?} has a node outside of {} nodes", from, to, self.node_count);
+        from.index() * self.node_count + to.index()
+    }
+}
+
+/// Finds shortest paths between every pair of nodes.
+///
+/// Note:
+///     This function uses the Floyd–Warshall algorithm and runs in O(V^3). Negative
+///     weights are allowed; the search stops as soon as the distance from a node to itself
+///     becomes negative, before distances can shrink further and overflow, and the negative
+///     cycle is read off the successors.
+///
+/// Returns:
+///
+/// The distance and successor matrices, or the nodes of some negative cycle.
+pub fn floyd_warshall<N, W>(graph: &MatrixGraph<N, W>) -> Result<AllPairsShortestPaths<N, W>, NegativeCycle<N>>
+where
+    N: NodeId,
+    W: Copy + PartialOrd + Add<Output = W> + Zero,
+{
+    let n = graph.node_count();
+    let mut distances: Vec<Option<W>> = vec![None; n * n];
+    let mut successors: Vec<Option<N>> = vec![None; n * n];
+
+    for edge in graph.edges() {
+        let mut set = |from: N, to: N| {
+            distances[from.index() * n + to.index()] = Some(edge.weight);
+            successors[from.index() * n + to.index()] = Some(to);
+        };
+
+        set(edge.from, edge.to);
+        if !graph.is_directed() {
+            set(edge.to, edge.from);
+        }
+    }
+
+    for node in 0..n {
+        let cell = node * n + node;
+        match distances[cell] {
+            Some(weight) if weight < W::zero() => return Err(NegativeCycle(vec![N::from_index(node)])),
+            Some(weight) if weight == W::zero() => {}
+            _ => {
+                distances[cell] = Some(W::zero());
+                successors[cell] = Some(N::from_index(node));
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(to_k) = distances[i * n + k] else {
+                continue;
+            };
+
+            // Checked before the row changes, while the paths through k still come from the previous rounds.
+            if distances[k * n + i].is_some_and(|from_k| to_k + from_k < W::zero()) {
+                return Err(negative_cycle(graph, &successors, i, k));
+            }
+
+            for j in 0..n {
+                let Some(from_k) = distances[k * n + j] else {
+                    continue;
+                };
+
+                let candidate = to_k + from_k;
+                if distances[i * n + j].is_none_or(|current| candidate < current) {
+                    distances[i * n + j] = Some(candidate);
+                    successors[i * n + j] = successors[i * n + k];
+                }
+            }
+        }
+    }
+
+    Ok(AllPairsShortestPaths { node_count: n, distances, successors })
+}
+
+// Splits the closed walk from the node to k and back, which has a negative weight, into simple
+// cycles and returns a negative one.
+fn negative_cycle<N, W>(graph: &MatrixGraph<N, W>, successors: &[Option<N>], node: usize, k: usize) -> NegativeCycle<N>
+where
+    N: NodeId,
+    W: Copy + PartialOrd + Add<Output = W> + Zero,
+{
+    let n = graph.node_count();
+    let path = |from: usize, to: usize| {
+        let mut current = from;
+        core::iter::from_fn(move || {
+            (current != to).then(|| {
+                current = successors[current * n + to].unwrap().index();
+                current
+            })
+        })
+    };
+    let weight = |nodes: &[usize], next: usize| {
+        let edge = |from: usize, to: usize| graph.weight(N::from_index(from), N::from_index(to)).unwrap();
+        nodes.windows(2).fold(edge(nodes[nodes.len() - 1], next), |total, pair| total + edge(pair[0], pair[1]))
+    };
+
+    // A repeated node closes a cycle, which is removed from the walk unless it is negative.
+    let mut cycle = vec![node];
+    let mut positions = vec![None; n];
+    positions[node] = Some(0);
+    for next in path(node, k).chain(path(k, node)) {
+        match positions[next] {
+            Some(position) => {
+                if weight(&cycle[position..], next) < W::zero() {
+                    return NegativeCycle(cycle[position..].iter().map(|&index| N::from_index(index)).collect());
+                }
+
+                for &removed in &cycle[position + 1..] {
+                    positions[removed] = None;
+                }
+                cycle.truncate(position + 1);
+            }
+            None => {
+                positions[next] = Some(cycle.len());
+                cycle.push(next);
+            }
+        }
+    }
+
+    unreachable!("Walk through node {} and {} must have a negative cycle", node, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::dijkstra::dijkstra;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    #[test]
+    fn all_pairs_distances_should_be_computed_correctly() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(4, [(0, 2, -2), (2, 3, 2), (3, 1, -1), (1, 0, 4), (1, 2, 3)]);
+
+        let paths = floyd_warshall(&graph).unwrap();
+
+        let expected = [[0, -1, -2, 0], [4, 0, 2, 4], [5, 1, 0, 2], [3, -1, 1, 0]];
+        for (from, row) in expected.iter().enumerate() {
+            for (to, &distance) in row.iter().enumerate() {
+                assert_eq!(paths.distance(from, to), Some(distance), "Wrong distance from {} to {}", from, to);
+            }
+        }
+
+        assert_eq!(paths.path(0, 1), Some(vec![0, 2, 3, 1]));
+        assert_eq!(paths.path(2, 2), Some(vec![2]));
+    }
+
+    #[test]
+    fn unreachable_pairs_should_have_no_distance() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(3, [(0, 1, 1)]);
+
+        let paths = floyd_warshall(&graph).unwrap();
+
+        assert_eq!(paths.distance(1, 0), None);
+        assert_eq!(paths.path(0, 2), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distance_outside_the_graph_should_panic() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(3, [(1, 0, 7)]);
+
+        floyd_warshall(&graph).unwrap().distance(0, 3);
+    }
+
+    #[test]
+    fn negative_cycle_should_be_reported() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(4, [(0, 1, 1), (1, 2, -1), (2, 1, -1), (2, 3, 1)]);
+
+        let NegativeCycle(mut cycle) = floyd_warshall(&graph).unwrap_err();
+
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2]);
+    }
+
+    #[test]
+    fn negative_undirected_edge_and_self_loop_should_be_cycles() {
+        let graph: MatrixGraph<usize, i32> = MatrixGraph::undirected_from_edges(3, [(0, 1, 2), (1, 2, -1)]);
+        let looped: MatrixGraph<usize, i32> = MatrixGraph::directed_from_edges(2, [(0, 1, 1), (1, 1, -1)]);
+
+        let NegativeCycle(mut cycle) = floyd_warshall(&graph).unwrap_err();
+
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2]);
+        assert_eq!(floyd_warshall(&looped), Err(NegativeCycle(vec![1])));
+    }
+
+    fn cycle_weight(graph: &MatrixGraph<usize, i64>, cycle: &[usize]) -> i64 {
+        (0..cycle.len()).map(|i| graph.weight(cycle[i], cycle[(i + 1) % cycle.len()]).expect("Cycle must follow edges")).sum()
+    }
+
+    #[test]
+    fn deeply_negative_cycle_should_be_reported_without_overflow() {
+        let n = 64;
+        let edges: Vec<(usize, usize, i64)> = (0..n).map(|i| (i, (i + 1) % n, -(1 << 56))).collect();
+        let graph = MatrixGraph::directed_from_edges(n, edges);
+
+        let NegativeCycle(cycle) = floyd_warshall(&graph).unwrap_err();
+
+        assert_eq!(cycle.len(), n);
+        assert!(cycle_weight(&graph, &cycle) < 0);
+    }
+
+    #[test]
+    fn negative_cycles_of_random_graphs_should_follow_edges() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let n = rng.gen_range(1..12);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.gen_range(0..40)).map(|_| (rng.gen_range(0..n), rng.gen_range(0..n), rng.gen_range(-5..20))).collect();
+            let graph = MatrixGraph::directed_from_edges(n, edges);
+
+            if let Err(NegativeCycle(cycle)) = floyd_warshall(&graph) {
+                let mut distinct = cycle.clone();
+                distinct.sort();
+                distinct.dedup();
+
+                assert_eq!(distinct.len(), cycle.len());
+                assert!(cycle_weight(&graph, &cycle) < 0, "Cycle {:?} is not negative", cycle);
+            }
+        }
+    }
+
+    #[test]
+    fn distances_should_match_dijkstra_on_random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..20 {
+            let n = rng.gen_range(1..15);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.gen_range(0..40)).map(|_| (rng.gen_range(0..n), rng.gen_range(0..n), rng.gen_range(0..20))).collect();
+
+            let matrix = MatrixGraph::directed_from_edges(n, edges.iter().copied());
+            let list = Graph::directed_from_edges(n, matrix.edges().map(|edge| (edge.from, edge.to, edge.weight)));
+            let paths = floyd_warshall(&matrix).unwrap();
+
+            for from in 0..n {
+                let single_source = dijkstra(&list, from);
+                for to in 0..n {
+                    assert_eq!(paths.distance(from, to), single_source.distance(to));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+pub mod base;
+pub mod adjacency_list;
+pub mod adjacency_matrix;
+pub mod traversal;
+pub mod components;
+pub mod paths;
+pub mod dijkstra;
+pub mod bellman_ford;
+pub mod floyd_warshall;
+pub mod astar;
+pub mod topological_sort;
+pub mod scc;
+pub mod mst;
+pub mod flow;
+pub mod bipartite;
+pub mod connectivity;
+pub mod euler_path;
+pub mod trees;
+pub mod two_sat;
+pub mod coloring;
+pub mod dot;
+
+pub use base::{Edge, GraphBase, NodeId};
+pub use adjacency_list::Graph;
+pub use adjacency_matrix::MatrixGraph;
+pub use traversal::{bfs, dfs, dfs_events, dfs_with_hooks, DfsEvent, Visit};
+pub use components::{connected_components, Components};
+pub use paths::ShortestPaths;
+pub use dijkstra::{dijkstra, dijkstra_to};
+pub use bellman_ford::{bellman_ford, BellmanFordResult, NegativeCycle};
+pub use floyd_warshall::{floyd_warshall, AllPairsShortestPaths};
+pub use astar::{astar, GridGraph, Heuristic};
+pub use topological_sort::{find_cycle, topological_sort, topological_sort_dfs, Cycle};
+pub use scc::{condensation, kosaraju_scc, tarjan_scc};
+pub use mst::{kruskal, prim, SpanningForest};
+pub use euler_path::{euler_circuit, euler_path};
+pub use two_sat::{two_sat, Literal, TwoSat, Unsatisfiable};
+pub use coloring::{chromatic_coloring, dsatur, k_coloring, welsh_powell, Coloring};
+pub use dot::{parse_dot, to_dot, to_dot_with, DotGraph, ParseDotError};
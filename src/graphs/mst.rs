@@ -0,0 +1,147 @@
+//! This module contains minimum spanning tree algorithms for undirected graphs.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use num::Zero;
+
+use crate::graphs::{Edge, GraphBase, NodeId};
+use crate::structures::{DisjointSet, IndexedHeap};
+
+/// Minimum spanning forest: a minimum spanning tree for every connected component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest<N, W> {
+    /// The chosen edges.
+    pub edges: Vec<Edge<N, W>>,
+    /// The sum of their weights.
+    pub total_weight: W,
+}
+
+/// Finds a minimum spanning forest with Kruskal's algorithm.
+///
+/// Note:
+///     Edges are taken in increasing weight order unless they close a cycle, which is
+///     checked with a disjoint-set structure. Runs in O(E log E).
+///
+/// Panics when the graph is directed.
+pub fn kruskal<G>(graph: &G) -> SpanningForest<G::Node, G::Weight>
+where
+    G: GraphBase,
+    G::Weight: Ord + Add<Output = G::Weight> + Zero,
+{
+    assert!(!graph.is_directed(), "Spanning trees are defined only for undirected graphs");
+
+    let mut edges: Vec<Edge<G::Node, G::Weight>> = graph.edges().collect();
+    edges.sort_by_key(|edge| edge.weight);
+
+    let mut sets = DisjointSet::new(graph.node_count());
+    let chosen: Vec<_> = edges.into_iter().filter(|edge| sets.union(edge.from.index(), edge.to.index())).collect();
+
+    forest(chosen)
+}
+
+/// Finds a minimum spanning forest with Prim's algorithm.
+///
+/// Note:
+///     Every tree grows from its smallest node by the lightest edge leaving it, found
+///     with an indexed binary heap. Runs in O((V + E) log V).
+///
+/// Panics when the graph is directed.
+pub fn prim<G>(graph: &G) -> SpanningForest<G::Node, G::Weight>
+where
+    G: GraphBase,
+    G::Weight: Ord + Add<Output = G::Weight> + Zero,
+{
+    assert!(!graph.is_directed(), "Spanning trees are defined only for undirected graphs");
+
+    let node_count = graph.node_count();
+    let mut in_tree = vec![false; node_count];
+    // The lightest known edge connecting every node outside of the trees.
+    let mut connections: Vec<Option<Edge<G::Node, G::Weight>>> = vec![None; node_count];
+    let mut heap = IndexedHeap::new(node_count);
+    let mut chosen = Vec::new();
+
+    for root in graph.nodes() {
+        if in_tree[root.index()] {
+            continue;
+        }
+
+        heap.push(root.index(), G::Weight::zero());
+        while let Some((index, _)) = heap.pop() {
+            in_tree[index] = true;
+            if let Some(edge) = connections[index] {
+                chosen.push(edge);
+            }
+
+            let node = G::Node::from_index(index);
+            for (neighbor, weight) in graph.neighbors(node) {
+                if !in_tree[neighbor.index()] && heap.push_decrease(neighbor.index(), weight) {
+                    connections[neighbor.index()] = Some(Edge::new(node, neighbor, weight));
+                }
+            }
+        }
+    }
+
+    forest(chosen)
+}
+
+fn forest<N, W: Copy + Add<Output = W> + Zero>(edges: Vec<Edge<N, W>>) -> SpanningForest<N, W> {
+    let total_weight = edges.iter().fold(W::zero(), |total, edge| total + edge.weight);
+    SpanningForest { edges, total_weight }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::components::connected_components;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn sample() -> Graph<usize, i32> {
+        Graph::undirected_from_edges(7, [(0, 1, 7), (0, 3, 5), (1, 2, 8), (1, 3, 9), (1, 4, 7), (2, 4, 5), (3, 4, 15), (3, 5, 6), (4, 5, 8), (4, 6, 9), (5, 6, 11)])
+    }
+
+    #[test]
+    fn kruskal_should_find_minimum_spanning_tree() {
+        let forest = kruskal(&sample());
+
+        assert_eq!(forest.total_weight, 39);
+        assert_eq!(forest.edges.len(), 6);
+    }
+
+    #[test]
+    fn prim_should_find_minimum_spanning_tree() {
+        let forest = prim(&sample());
+
+        assert_eq!(forest.total_weight, 39);
+        assert_eq!(forest.edges.len(), 6);
+    }
+
+    #[test]
+    fn disconnected_graph_should_get_spanning_forest() {
+        let graph: Graph<usize, i32> = Graph::undirected_from_edges(5, [(0, 1, 3), (1, 2, 1), (0, 2, 2), (3, 4, -4)]);
+
+        for forest in [kruskal(&graph), prim(&graph)] {
+            assert_eq!(forest.total_weight, -1);
+            assert_eq!(forest.edges.len(), 3);
+        }
+    }
+
+    #[test]
+    fn algorithms_should_agree_on_random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let n = rng.gen_range(1..25);
+            let graph: Graph<usize, i64> = Graph::undirected_from_edges(n, (0..rng.gen_range(0..60)).map(|_| (rng.gen_range(0..n), rng.gen_range(0..n), rng.gen_range(-10..30))));
+
+            let (kruskal, prim) = (kruskal(&graph), prim(&graph));
+            let expected_edges = n - connected_components(&graph).count;
+
+            assert_eq!(kruskal.total_weight, prim.total_weight);
+            assert_eq!(kruskal.edges.len(), expected_edges);
+            assert_eq!(prim.edges.len(), expected_edges);
+        }
+    }
+}
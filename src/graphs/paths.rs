@@ -0,0 +1,56 @@
+//! This module contains the result type shared by single-source shortest path algorithms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::NodeId;
+
+/// Shortest paths from a single source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths<N, W> {
+    source: N,
+    distances: Vec<Option<W>>,
+    predecessors: Vec<Option<N>>,
+}
+
+impl<N: NodeId, W: Copy> ShortestPaths<N, W> {
+    /// Wraps distances and predecessors indexed by node.
+    pub fn new(source: N, distances: Vec<Option<W>>, predecessors: Vec<Option<N>>) -> Self {
+        ShortestPaths { source, distances, predecessors }
+    }
+
+    /// Returns the source node.
+    pub fn source(&self) -> N {
+        self.source
+    }
+
+    /// Returns the distance to the node, `None` when it is unreachable.
+    pub fn distance(&self, node: N) -> Option<W> {
+        self.distances[node.index()]
+    }
+
+    /// Returns the distance to every node, indexed by node.
+    pub fn distances(&self) -> &[Option<W>] {
+        &self.distances
+    }
+
+    /// Returns the node preceding the given one on its shortest path, `None` for the source and unreachable nodes.
+    pub fn predecessor(&self, node: N) -> Option<N> {
+        self.predecessors[node.index()]
+    }
+
+    /// Returns the nodes of a shortest path from the source to the node, both inclusive.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        self.distance(node)?;
+
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(previous) = self.predecessor(current) {
+            path.push(previous);
+            current = previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
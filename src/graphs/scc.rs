@@ -0,0 +1,245 @@
+//! This module contains strongly connected components of directed graphs.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::components::Components;
+use crate::graphs::{Graph, GraphBase, NodeId};
+
+const UNVISITED: usize = usize::MAX;
+
+fn adjacency<G: GraphBase>(graph: &G) -> Vec<Vec<usize>> {
+    graph.nodes().map(|node| graph.neighbors(node).map(|(neighbor, _)| neighbor.index()).collect()).collect()
+}
+
+/// Labels the strongly connected components with Tarjan's algorithm.
+///
+/// Note:
+///     A single depth-first traversal tracks the lowest discovery index reachable from
+///     every node. Runs in O(V + E). Components are numbered in reverse topological
+///     order of the condensation: edges between components lead to smaller labels.
+pub fn tarjan_scc<G: GraphBase>(graph: &G) -> Components {
+    let adjacency = adjacency(graph);
+    let node_count = adjacency.len();
+
+    let mut discovery = vec![UNVISITED; node_count];
+    let mut low = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut stack = Vec::new();
+    let mut labels = vec![0; node_count];
+    let mut count = 0;
+    let mut next_discovery = 0;
+
+    for root in 0..node_count {
+        if discovery[root] != UNVISITED {
+            continue;
+        }
+
+        let mut path = vec![(root, 0)];
+        discovery[root] = next_discovery;
+        low[root] = next_discovery;
+        next_discovery += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut next)) = path.last_mut() {
+            if let Some(&neighbor) = adjacency[node].get(*next) {
+                *next += 1;
+
+                if discovery[neighbor] == UNVISITED {
+                    discovery[neighbor] = next_discovery;
+                    low[neighbor] = next_discovery;
+                    next_discovery += 1;
+                    stack.push(neighbor);
+                    on_stack[neighbor] = true;
+                    path.push((neighbor, 0));
+                } else if on_stack[neighbor] {
+                    low[node] = low[node].min(discovery[neighbor]);
+                }
+
+                continue;
+            }
+
+            path.pop();
+            if let Some(&(parent, _)) = path.last() {
+                low[parent] = low[parent].min(low[node]);
+            }
+
+            if low[node] == discovery[node] {
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member] = false;
+                    labels[member] = count;
+
+                    if member == node {
+                        break;
+                    }
+                }
+
+                count += 1;
+            }
+        }
+    }
+
+    Components { count, labels }
+}
+
+/// Labels the strongly connected components with Kosaraju's algorithm.
+///
+/// Note:
+///     The first traversal orders nodes by finishing time, the second one collects
+///     components on the reversed graph in reverse finishing order. Runs in O(V + E).
+///     Components are numbered in topological order of the condensation: edges between
+///     components lead to greater labels.
+pub fn kosaraju_scc<G: GraphBase>(graph: &G) -> Components {
+    let adjacency = adjacency(graph);
+    let node_count = adjacency.len();
+
+    let mut reversed = vec![Vec::new(); node_count];
+    for (node, neighbors) in adjacency.iter().enumerate() {
+        for &neighbor in neighbors {
+            reversed[neighbor].push(node);
+        }
+    }
+
+    let mut visited = vec![false; node_count];
+    let mut finish_order = Vec::with_capacity(node_count);
+    for root in 0..node_count {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        let mut path = vec![(root, 0)];
+        while let Some(&mut (node, ref mut next)) = path.last_mut() {
+            match adjacency[node].get(*next) {
+                Some(&neighbor) => {
+                    *next += 1;
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        path.push((neighbor, 0));
+                    }
+                }
+                None => {
+                    finish_order.push(node);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    let mut labels = vec![UNVISITED; node_count];
+    let mut count = 0;
+    for &root in finish_order.iter().rev() {
+        if labels[root] != UNVISITED {
+            continue;
+        }
+
+        labels[root] = count;
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            for &neighbor in &reversed[node] {
+                if labels[neighbor] == UNVISITED {
+                    labels[neighbor] = count;
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        count += 1;
+    }
+
+    Components { count, labels }
+}
+
+/// Builds the condensation of a directed graph: one node per component, and an edge
+/// between two components whenever some edge of the graph connects them.
+///
+/// Note: the result is acyclic, has no self-loops and no parallel edges.
+pub fn condensation<G: GraphBase>(graph: &G, components: &Components) -> Graph<G::Node, ()> {
+    let mut edges: Vec<(usize, usize)> = graph
+        .edges()
+        .map(|edge| (components.labels[edge.from.index()], components.labels[edge.to.index()]))
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    edges.sort_unstable();
+    edges.dedup();
+
+    Graph::directed_from_pairs(components.count, edges.into_iter().map(|(from, to)| (G::Node::from_index(from), G::Node::from_index(to))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::topological_sort::find_cycle;
+    use rand::Rng;
+
+    fn normalized(components: &Components) -> Vec<usize> {
+        // Renumber components in order of their smallest node.
+        let mut renumbering = vec![UNVISITED; components.count];
+        let mut next = 0;
+        components
+            .labels
+            .iter()
+            .map(|&label| {
+                if renumbering[label] == UNVISITED {
+                    renumbering[label] = next;
+                    next += 1;
+                }
+
+                renumbering[label]
+            })
+            .collect()
+    }
+
+    fn sample() -> Graph {
+        Graph::directed_from_pairs(8, [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3), (6, 5), (6, 7), (7, 6)])
+    }
+
+    #[test]
+    fn tarjan_should_find_components_in_reverse_topological_order() {
+        let components = tarjan_scc(&sample());
+
+        assert_eq!(components.count, 3);
+        assert_eq!(components.labels, vec![1, 1, 1, 0, 0, 0, 2, 2]);
+    }
+
+    #[test]
+    fn kosaraju_should_find_components_in_topological_order() {
+        let components = kosaraju_scc(&sample());
+
+        assert_eq!(components.count, 3);
+        assert_eq!(components.labels[3], components.labels[5]);
+        assert!(components.labels[0] < components.labels[3]);
+        assert!(components.labels[6] < components.labels[3]);
+    }
+
+    #[test]
+    fn condensation_should_connect_components() {
+        let graph = sample();
+        let components = tarjan_scc(&graph);
+
+        let dag = condensation(&graph, &components);
+
+        assert_eq!(dag.node_count(), 3);
+        assert_eq!(dag.edges().map(|edge| (edge.from, edge.to)).collect::<Vec<_>>(), vec![(1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn algorithms_should_agree_on_random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let n = rng.gen_range(1..30);
+            let graph: Graph = Graph::directed_from_pairs(n, (0..rng.gen_range(0..60)).map(|_| (rng.gen_range(0..n), rng.gen_range(0..n))));
+
+            let tarjan = tarjan_scc(&graph);
+            let kosaraju = kosaraju_scc(&graph);
+
+            assert_eq!(tarjan.count, kosaraju.count);
+            assert_eq!(normalized(&tarjan), normalized(&kosaraju));
+            assert_eq!(find_cycle(&condensation(&graph, &tarjan)), None);
+        }
+    }
+}
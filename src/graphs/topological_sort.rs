@@ -0,0 +1,206 @@
+//! This module contains topological sorting and cycle detection for directed graphs.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
+
+use crate::graphs::{GraphBase, NodeId};
+
+/// Error reported when a directed graph has a cycle, so no topological order exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N>(
+    /// Nodes of the cycle in the order its edges are followed, the last one leads back to the first.
+    pub Vec<N>,
+);
+
+impl<N: fmt::Debug> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle through nodes {:?}", self.0)
+    }
+}
+
+impl<N: fmt::Debug> core::error::Error for Cycle<N> {}
+
+/// Orders the nodes so that every edge goes from an earlier node to a later one.
+///
+/// Note:
+///     This function uses Kahn's algorithm, always taking the smallest ready node, so the
+///     result is the lexicographically smallest topological order. Runs in O((V + E) log V).
+///
+/// Returns:
+///
+/// The nodes in topological order, or some cycle of the graph.
+///
+/// Panics when the graph is undirected.
+pub fn topological_sort<G: GraphBase>(graph: &G) -> Result<Vec<G::Node>, Cycle<G::Node>> {
+    assert!(graph.is_directed(), "Topological order is defined only for directed graphs");
+
+    let mut in_degrees = vec![0usize; graph.node_count()];
+    for node in graph.nodes() {
+        for (neighbor, _) in graph.neighbors(node) {
+            in_degrees[neighbor.index()] += 1;
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<G::Node>> = graph.nodes().filter(|node| in_degrees[node.index()] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(graph.node_count());
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node);
+
+        for (neighbor, _) in graph.neighbors(node) {
+            in_degrees[neighbor.index()] -= 1;
+            if in_degrees[neighbor.index()] == 0 {
+                ready.push(Reverse(neighbor));
+            }
+        }
+    }
+
+    if order.len() < graph.node_count() {
+        return Err(Cycle(find_cycle(graph).expect("Nodes left unordered must lie behind a cycle")));
+    }
+
+    Ok(order)
+}
+
+/// Orders the nodes topologically with depth-first search.
+///
+/// Note:
+///     The order is the reverse post-order of a depth-first traversal started from every
+///     node in increasing order. Runs in O(V + E).
+///
+/// Returns:
+///
+/// The nodes in topological order, or the first cycle the traversal runs into.
+///
+/// Panics when the graph is undirected.
+pub fn topological_sort_dfs<G: GraphBase>(graph: &G) -> Result<Vec<G::Node>, Cycle<G::Node>> {
+    assert!(graph.is_directed(), "Topological order is defined only for directed graphs");
+
+    let mut post_order = Vec::with_capacity(graph.node_count());
+    if let Some(cycle) = search(graph, &mut post_order) {
+        return Err(Cycle(cycle));
+    }
+
+    post_order.reverse();
+    Ok(post_order)
+}
+
+/// Finds some cycle of a directed graph.
+///
+/// Returns:
+///
+/// Nodes of the cycle in the order its edges are followed, `None` when the graph is acyclic.
+pub fn find_cycle<G: GraphBase>(graph: &G) -> Option<Vec<G::Node>> {
+    search(graph, &mut Vec::new())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Unvisited,
+    OnStack,
+    Finished,
+}
+
+/// Depth-first search that records the post-order and stops at the first back edge.
+fn search<G: GraphBase>(graph: &G, post_order: &mut Vec<G::Node>) -> Option<Vec<G::Node>> {
+    let adjacency: Vec<Vec<G::Node>> = graph.nodes().map(|node| graph.neighbors(node).map(|(neighbor, _)| neighbor).collect()).collect();
+    let mut states = vec![State::Unvisited; graph.node_count()];
+
+    for root in graph.nodes() {
+        if states[root.index()] != State::Unvisited {
+            continue;
+        }
+
+        // The stack holds the current path together with the position of the next neighbor to try.
+        let mut stack = vec![(root, 0)];
+        states[root.index()] = State::OnStack;
+
+        while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+            let Some(&neighbor) = adjacency[node.index()].get(*next) else {
+                states[node.index()] = State::Finished;
+                post_order.push(node);
+                stack.pop();
+                continue;
+            };
+
+            *next += 1;
+            match states[neighbor.index()] {
+                State::Unvisited => {
+                    states[neighbor.index()] = State::OnStack;
+                    stack.push((neighbor, 0));
+                }
+                State::OnStack => {
+                    let start = stack.iter().position(|&(on_path, _)| on_path == neighbor).unwrap();
+                    return Some(stack[start..].iter().map(|&(on_path, _)| on_path).collect());
+                }
+                State::Finished => {}
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+
+    fn is_topological_order(graph: &Graph, order: &[usize]) -> bool {
+        let mut positions = vec![0; order.len()];
+        for (position, &node) in order.iter().enumerate() {
+            positions[node] = position;
+        }
+
+        order.len() == graph.node_count() && graph.edges().all(|edge| positions[edge.from] < positions[edge.to])
+    }
+
+    fn is_cycle(graph: &Graph, cycle: &[usize]) -> bool {
+        !cycle.is_empty() && (0..cycle.len()).all(|i| graph.has_edge(cycle[i], cycle[(i + 1) % cycle.len()]))
+    }
+
+    fn dag() -> Graph {
+        Graph::directed_from_pairs(6, [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)])
+    }
+
+    #[test]
+    fn kahn_should_produce_lexicographically_smallest_order() {
+        let order = topological_sort(&dag()).unwrap();
+
+        assert_eq!(order, vec![4, 5, 0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn dfs_variant_should_produce_valid_order() {
+        let graph = dag();
+
+        let order = topological_sort_dfs(&graph).unwrap();
+
+        assert!(is_topological_order(&graph, &order));
+    }
+
+    #[test]
+    fn cycle_should_be_reported_by_both_variants() {
+        let graph: Graph = Graph::directed_from_pairs(5, [(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+
+        let Cycle(kahn) = topological_sort(&graph).unwrap_err();
+        let Cycle(dfs) = topological_sort_dfs(&graph).unwrap_err();
+
+        assert_eq!(dfs, vec![1, 2, 3]);
+        assert!(is_cycle(&graph, &kahn));
+    }
+
+    #[test]
+    fn self_loop_should_be_a_cycle() {
+        let graph: Graph = Graph::directed_from_pairs(2, [(0, 1), (1, 1)]);
+
+        assert_eq!(find_cycle(&graph), Some(vec![1]));
+    }
+
+    #[test]
+    fn acyclic_graph_should_have_no_cycle() {
+        assert_eq!(find_cycle(&dag()), None);
+    }
+}
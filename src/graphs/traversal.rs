@@ -0,0 +1,229 @@
+//! This module contains lazy breadth-first and depth-first traversals.
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{GraphBase, NodeId};
+
+/// A node reached by a traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit<N> {
+    /// The reached node.
+    pub node: N,
+    /// The node it was reached from, `None` for the start.
+    pub parent: Option<N>,
+    /// The number of edges from the start along the traversal tree.
+    pub depth: usize,
+}
+
+/// Breadth-first traversal, created by `bfs`.
+pub struct Bfs<'a, G: GraphBase> {
+    graph: &'a G,
+    visited: Vec<bool>,
+    queue: VecDeque<Visit<G::Node>>,
+}
+
+/// Traverses the graph breadth-first from the start node.
+///
+/// Returns:
+///
+/// An iterator over reachable nodes in order of their distance from the start, in edges.
+pub fn bfs<G: GraphBase>(graph: &G, start: G::Node) -> Bfs<'_, G> {
+    let mut visited = vec![false; graph.node_count()];
+    visited[start.index()] = true;
+
+    Bfs {
+        graph,
+        visited,
+        queue: VecDeque::from([Visit { node: start, parent: None, depth: 0 }]),
+    }
+}
+
+impl<G: GraphBase> Iterator for Bfs<'_, G> {
+    type Item = Visit<G::Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let visit = self.queue.pop_front()?;
+
+        for (neighbor, _) in self.graph.neighbors(visit.node) {
+            if !self.visited[neighbor.index()] {
+                self.visited[neighbor.index()] = true;
+                self.queue.push_back(Visit { node: neighbor, parent: Some(visit.node), depth: visit.depth + 1 });
+            }
+        }
+
+        Some(visit)
+    }
+}
+
+/// A step of depth-first traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent<N> {
+    /// The node is entered for the first time (pre-order).
+    Discover(Visit<N>),
+    /// Every node reachable from the node has been discovered (post-order).
+    Finish(N),
+}
+
+type Neighbors<'a, G> = Box<dyn Iterator<Item = (<G as GraphBase>::Node, <G as GraphBase>::Weight)> + 'a>;
+
+/// Depth-first traversal reporting both discovery and finishing, created by `dfs_events`.
+pub struct DfsEvents<'a, G: GraphBase> {
+    graph: &'a G,
+    visited: Vec<bool>,
+    pending: Option<Visit<G::Node>>,
+    stack: Vec<(Visit<G::Node>, Neighbors<'a, G>)>,
+}
+
+/// Traverses the graph depth-first from the start node, reporting when nodes are entered and left.
+///
+/// Note: the traversal is iterative, so deep graphs do not overflow the call stack.
+pub fn dfs_events<G: GraphBase>(graph: &G, start: G::Node) -> DfsEvents<'_, G> {
+    let mut visited = vec![false; graph.node_count()];
+    visited[start.index()] = true;
+
+    DfsEvents {
+        graph,
+        visited,
+        pending: Some(Visit { node: start, parent: None, depth: 0 }),
+        stack: Vec::new(),
+    }
+}
+
+impl<'a, G: GraphBase> Iterator for DfsEvents<'a, G> {
+    type Item = DfsEvent<G::Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(visit) = self.pending.take() {
+            self.stack.push((visit, Box::new(self.graph.neighbors(visit.node))));
+            return Some(DfsEvent::Discover(visit));
+        }
+
+        let (visit, neighbors) = self.stack.last_mut()?;
+
+        match neighbors.find(|(neighbor, _)| !self.visited[neighbor.index()]) {
+            Some((neighbor, _)) => {
+                self.visited[neighbor.index()] = true;
+
+                let discovered = Visit { node: neighbor, parent: Some(visit.node), depth: visit.depth + 1 };
+                self.stack.push((discovered, Box::new(self.graph.neighbors(neighbor))));
+                Some(DfsEvent::Discover(discovered))
+            }
+            None => {
+                let (visit, _) = self.stack.pop().unwrap();
+                Some(DfsEvent::Finish(visit.node))
+            }
+        }
+    }
+}
+
+/// Traverses the graph depth-first from the start node.
+///
+/// Returns:
+///
+/// An iterator over reachable nodes in pre-order.
+pub fn dfs<G: GraphBase>(graph: &G, start: G::Node) -> impl Iterator<Item = Visit<G::Node>> + '_ {
+    dfs_events(graph, start).filter_map(|event| match event {
+        DfsEvent::Discover(visit) => Some(visit),
+        DfsEvent::Finish(_) => None,
+    })
+}
+
+/// Traverses the graph depth-first from the start node, calling the hooks in pre-order and post-order.
+///
+/// Arguments:
+///
+/// * `graph`: The graph to traverse.
+/// * `start`: The node to start from.
+/// * `on_discover`: Called when a node is entered for the first time.
+/// * `on_finish`: Called when every node reachable from a node has been discovered.
+pub fn dfs_with_hooks<G: GraphBase>(
+    graph: &G,
+    start: G::Node,
+    mut on_discover: impl FnMut(Visit<G::Node>),
+    mut on_finish: impl FnMut(G::Node),
+) {
+    for event in dfs_events(graph, start) {
+        match event {
+            DfsEvent::Discover(visit) => on_discover(visit),
+            DfsEvent::Finish(node) => on_finish(node),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+
+    fn tree() -> Graph {
+        //     0
+        //    / \
+        //   1   2
+        //  / \   \
+        // 3   4   5
+        Graph::undirected_from_pairs(6, [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)])
+    }
+
+    #[test]
+    fn bfs_should_visit_nodes_level_by_level() {
+        let graph = tree();
+
+        let nodes: Vec<usize> = bfs(&graph, 0).map(|visit| visit.node).collect();
+        let depths: Vec<usize> = bfs(&graph, 0).map(|visit| visit.depth).collect();
+
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(depths, vec![0, 1, 1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn bfs_should_report_parents() {
+        let graph = tree();
+
+        let visit = bfs(&graph, 0).find(|visit| visit.node == 5).unwrap();
+
+        assert_eq!(visit.parent, Some(2));
+        assert_eq!(bfs(&graph, 0).next().unwrap().parent, None);
+    }
+
+    #[test]
+    fn dfs_should_visit_nodes_in_pre_order() {
+        let graph = tree();
+
+        let nodes: Vec<usize> = dfs(&graph, 0).map(|visit| visit.node).collect();
+
+        assert_eq!(nodes, vec![0, 1, 3, 4, 2, 5]);
+    }
+
+    #[test]
+    fn dfs_hooks_should_be_called_in_pre_and_post_order() {
+        let graph = tree();
+        let mut pre_order = Vec::new();
+        let mut post_order = Vec::new();
+
+        dfs_with_hooks(&graph, 0, |visit| pre_order.push(visit.node), |node| post_order.push(node));
+
+        assert_eq!(pre_order, vec![0, 1, 3, 4, 2, 5]);
+        assert_eq!(post_order, vec![3, 4, 1, 5, 2, 0]);
+    }
+
+    #[test]
+    fn traversals_should_respect_edge_direction() {
+        let graph: Graph = Graph::directed_from_pairs(4, [(0, 1), (1, 2), (3, 0)]);
+
+        assert_eq!(bfs(&graph, 0).count(), 3);
+        assert_eq!(dfs(&graph, 1).map(|visit| visit.node).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn deep_path_should_not_overflow_the_stack() {
+        let length = 200_000;
+        let graph: Graph = Graph::directed_from_pairs(length, (1..length).map(|i| (i - 1, i)));
+
+        let deepest = dfs(&graph, 0).last().unwrap();
+
+        assert_eq!(deepest.depth, length - 1);
+    }
+}
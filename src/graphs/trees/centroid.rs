@@ -0,0 +1,255 @@
+//! This module contains tree centroids and the centroid decomposition.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::undirected_adjacency;
+use crate::graphs::{Graph, GraphBase, NodeId};
+
+/// Finds the centroids of a tree, the nodes whose removal leaves no part larger than half of the tree.
+///
+/// Note:
+///     A tree has either one centroid or two adjacent ones. Runs in O(n).
+///
+/// Arguments:
+///
+/// * `tree`: A connected tree, edge directions are ignored.
+///
+/// Returns:
+///
+/// The centroids in increasing order, empty for the empty tree.
+pub fn centroids<G: GraphBase>(tree: &G) -> Vec<G::Node> {
+    let node_count = tree.node_count();
+    if node_count == 0 {
+        return Vec::new();
+    }
+
+    let adjacency = undirected_adjacency(tree);
+    let removed = vec![false; node_count];
+    let mut parents = vec![usize::MAX; node_count];
+    let mut sizes = vec![0; node_count];
+    let order = component(&adjacency, &removed, 0, &mut parents);
+    subtree_sizes(&order, &parents, &mut sizes);
+
+    (0..node_count)
+        .filter(|&node| {
+            let largest_child = adjacency[node]
+                .iter()
+                .filter(|&&child| child != parents[node])
+                .map(|&child| sizes[child])
+                .max()
+                .unwrap_or(0);
+            largest_child.max(node_count - sizes[node]) <= node_count / 2
+        })
+        .map(G::Node::from_index)
+        .collect()
+}
+
+/// The centroid decomposition of a tree.
+///
+/// Note:
+///     The root of the decomposition is a centroid of the tree, and the children of every
+///     centroid are the centroids of the parts left after removing it. Every part is at most
+///     half of its parent part, so the decomposition has depth O(log n).
+#[derive(Debug, Clone)]
+pub struct CentroidDecomposition<N> {
+    root: Option<N>,
+    parents: Vec<Option<N>>,
+    levels: Vec<usize>,
+}
+
+impl<N: NodeId> CentroidDecomposition<N> {
+    /// Returns the top-level centroid, `None` for the empty tree.
+    pub fn root(&self) -> Option<N> {
+        self.root
+    }
+
+    /// Returns the centroid whose removal separated the part containing the node.
+    pub fn parent(&self, node: N) -> Option<N> {
+        self.parents[node.index()]
+    }
+
+    /// Returns the depth of the node in the decomposition, 0 for the root.
+    pub fn level(&self, node: N) -> usize {
+        self.levels[node.index()]
+    }
+
+    /// Returns the node followed by its ancestors in the decomposition up to the root.
+    pub fn ancestors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        core::iter::successors(Some(node), move |&current| self.parent(current))
+    }
+
+    /// Returns the decomposition as a directed tree with edges from every centroid to its children.
+    pub fn tree(&self) -> Graph<N> {
+        let pairs = self.parents.iter().enumerate().filter_map(|(node, parent)| parent.map(|parent| (parent, N::from_index(node))));
+
+        Graph::directed_from_pairs(self.parents.len(), pairs)
+    }
+}
+
+/// Builds the centroid decomposition of a tree in O(n log n).
+///
+/// Arguments:
+///
+/// * `tree`: A connected tree, edge directions are ignored.
+pub fn centroid_decomposition<G: GraphBase>(tree: &G) -> CentroidDecomposition<G::Node> {
+    let node_count = tree.node_count();
+    let adjacency = undirected_adjacency(tree);
+
+    let mut removed = vec![false; node_count];
+    let mut parents = vec![None; node_count];
+    let mut levels = vec![0; node_count];
+    let mut root = None;
+
+    // Shared by all parts, only the entries of the current part are set and reset afterwards.
+    let mut bfs_parents = vec![usize::MAX; node_count];
+    let mut sizes = vec![0; node_count];
+
+    // Every entry is some node of a part together with the centroid that separated it.
+    let mut stack: Vec<(usize, Option<usize>)> = if node_count > 0 { vec![(0, None)] } else { Vec::new() };
+    while let Some((start, separator)) = stack.pop() {
+        let order = component(&adjacency, &removed, start, &mut bfs_parents);
+        subtree_sizes(&order, &bfs_parents, &mut sizes);
+        let total = order.len();
+
+        let mut centroid = start;
+        while let Some(&heavy) = adjacency[centroid]
+            .iter()
+            .find(|&&child| !removed[child] && child != bfs_parents[centroid] && sizes[child] > total / 2)
+        {
+            centroid = heavy;
+        }
+
+        for &node in &order {
+            bfs_parents[node] = usize::MAX;
+            sizes[node] = 0;
+        }
+
+        removed[centroid] = true;
+        match separator {
+            Some(separator) => {
+                parents[centroid] = Some(G::Node::from_index(separator));
+                levels[centroid] = levels[separator] + 1;
+            }
+            None => root = Some(G::Node::from_index(centroid)),
+        }
+
+        for &neighbor in &adjacency[centroid] {
+            if !removed[neighbor] {
+                stack.push((neighbor, Some(centroid)));
+            }
+        }
+    }
+
+    CentroidDecomposition { root, parents, levels }
+}
+
+// Returns the BFS order of the part containing the start, skipping removed nodes, and sets the BFS
+// parents of its nodes, which must be unset before. The start is its own parent.
+fn component(adjacency: &[Vec<usize>], removed: &[bool], start: usize, parents: &mut [usize]) -> Vec<usize> {
+    parents[start] = start;
+
+    let mut order = vec![start];
+    let mut position = 0;
+    while let Some(&node) = order.get(position) {
+        position += 1;
+        for &neighbor in &adjacency[node] {
+            if !removed[neighbor] && parents[neighbor] == usize::MAX {
+                parents[neighbor] = node;
+                order.push(neighbor);
+            }
+        }
+    }
+
+    order
+}
+
+// Adds the subtree size of every node of the part to its zeroed size.
+fn subtree_sizes(order: &[usize], parents: &[usize], sizes: &mut [usize]) {
+    for &node in order.iter().rev() {
+        sizes[node] += 1;
+        if parents[node] != node {
+            sizes[parents[node]] += sizes[node];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn centroid_of_star_should_be_its_center() {
+        let tree: Graph = Graph::undirected_from_pairs(5, [(3, 0), (3, 1), (3, 2), (3, 4)]);
+
+        assert_eq!(centroids(&tree), vec![3]);
+    }
+
+    #[test]
+    fn even_path_should_have_two_centroids() {
+        let tree: Graph = Graph::undirected_from_pairs(6, [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+
+        assert_eq!(centroids(&tree), vec![2, 3]);
+    }
+
+    #[test]
+    fn path_decomposition_should_be_balanced() {
+        let tree: Graph = Graph::undirected_from_pairs(7, [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+
+        let decomposition = centroid_decomposition(&tree);
+
+        assert_eq!(decomposition.root(), Some(3));
+        assert_eq!(decomposition.parent(1), Some(3));
+        assert_eq!(decomposition.parent(5), Some(3));
+        assert_eq!(decomposition.parent(0), Some(1));
+        assert_eq!(decomposition.level(6), 2);
+        assert_eq!(decomposition.ancestors(4).collect::<Vec<_>>(), vec![4, 5, 3]);
+        assert_eq!(decomposition.tree().edge_count(), 6);
+    }
+
+    #[test]
+    fn long_path_should_be_decomposed_quickly() {
+        let n = 200_001;
+        let tree: Graph = Graph::undirected_from_pairs(n, (1..n).map(|node| (node - 1, node)));
+
+        let decomposition = centroid_decomposition(&tree);
+
+        assert_eq!(decomposition.root(), Some(n / 2));
+        assert!((0..n).all(|node| decomposition.level(node) <= n.ilog2() as usize));
+    }
+
+    #[test]
+    fn empty_tree_should_have_empty_decomposition() {
+        let tree: Graph = Graph::undirected(0);
+
+        assert_eq!(centroids(&tree), Vec::<usize>::new());
+        assert_eq!(centroid_decomposition(&tree).root(), None);
+    }
+
+    #[test]
+    fn decomposition_parts_should_halve_on_random_trees() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let n = rng.gen_range(1..80);
+            let tree: Graph = Graph::undirected_from_pairs(n, (1..n).map(|node| (rng.gen_range(0..node), node)));
+
+            let decomposition = centroid_decomposition(&tree);
+
+            let mut part_sizes = vec![0; n];
+            for node in 0..n {
+                for ancestor in decomposition.ancestors(node) {
+                    part_sizes[ancestor] += 1;
+                }
+            }
+            for node in 0..n {
+                assert!(decomposition.level(node) <= n.ilog2() as usize);
+                if let Some(parent) = decomposition.parent(node) {
+                    assert!(part_sizes[node] <= part_sizes[parent] / 2);
+                }
+            }
+            assert!(centroids(&tree).contains(&decomposition.root().unwrap()));
+        }
+    }
+}
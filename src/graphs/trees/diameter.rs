@@ -0,0 +1,117 @@
+//! This module contains the diameter of a tree.
+
+use super::undirected_adjacency;
+use crate::graphs::{GraphBase, NodeId};
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The longest path of a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diameter<N> {
+    /// The number of edges on the path.
+    pub length: usize,
+    /// The nodes of the path from one end to the other.
+    pub path: Vec<N>,
+}
+
+/// Finds the longest path of a tree with two breadth-first searches.
+///
+/// Note:
+///     The node farthest from any start is one end of a diameter, and the node farthest
+///     from that end is the other one. Runs in O(n).
+///
+/// Arguments:
+///
+/// * `tree`: A connected tree, edge directions are ignored.
+///
+/// Returns:
+///
+/// The diameter, with an empty path for the empty tree.
+pub fn diameter<G: GraphBase>(tree: &G) -> Diameter<G::Node> {
+    if tree.node_count() == 0 {
+        return Diameter { length: 0, path: Vec::new() };
+    }
+
+    let adjacency = undirected_adjacency(tree);
+    let (first_end, _) = farthest(&adjacency, 0);
+    let (second_end, parents) = farthest(&adjacency, first_end);
+
+    let mut path = vec![G::Node::from_index(second_end)];
+    let mut current = second_end;
+    while current != first_end {
+        current = parents[current];
+        path.push(G::Node::from_index(current));
+    }
+
+    Diameter { length: path.len() - 1, path }
+}
+
+// Returns the last node reached by a BFS from the start together with the BFS parents.
+fn farthest(adjacency: &[Vec<usize>], start: usize) -> (usize, Vec<usize>) {
+    let mut parents = vec![usize::MAX; adjacency.len()];
+    parents[start] = start;
+
+    let mut queue = VecDeque::from([start]);
+    let mut last = start;
+    while let Some(node) = queue.pop_front() {
+        last = node;
+        for &neighbor in &adjacency[node] {
+            if parents[neighbor] == usize::MAX {
+                parents[neighbor] = node;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    (last, parents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::traversal::bfs;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    #[test]
+    fn diameter_should_be_found() {
+        let tree: Graph = Graph::undirected_from_pairs(8, [(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (3, 6), (5, 7)]);
+
+        let diameter = diameter(&tree);
+
+        assert_eq!(diameter.length, 5);
+        assert_eq!(diameter.path.len(), 6);
+        let mut ends = [diameter.path[0], diameter.path[5]];
+        ends.sort();
+        assert_eq!(ends, [6, 7]);
+        assert!(diameter.path.windows(2).all(|pair| tree.has_edge(pair[0], pair[1])));
+    }
+
+    #[test]
+    fn edge_directions_should_be_ignored() {
+        let tree: Graph = Graph::directed_from_pairs(4, [(1, 0), (1, 2), (2, 3)]);
+
+        assert_eq!(diameter(&tree).length, 3);
+    }
+
+    #[test]
+    fn trivial_trees_should_be_supported() {
+        assert_eq!(diameter(&Graph::<usize, ()>::undirected(0)).path, Vec::<usize>::new());
+        assert_eq!(diameter(&Graph::<usize, ()>::undirected(1)).path, vec![0]);
+    }
+
+    #[test]
+    fn diameter_should_match_brute_force_on_random_trees() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let n = rng.gen_range(1..40);
+            let tree: Graph = Graph::undirected_from_pairs(n, (1..n).map(|node| (rng.gen_range(0..node), node)));
+
+            let longest = (0..n).flat_map(|start| bfs(&tree, start).map(|visit| visit.depth)).max().unwrap();
+
+            assert_eq!(diameter(&tree).length, longest);
+        }
+    }
+}
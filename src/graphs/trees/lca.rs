@@ -0,0 +1,280 @@
+//! This module contains lowest common ancestor queries on rooted trees.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::traversal::{bfs, dfs_events, DfsEvent};
+use crate::graphs::{GraphBase, NodeId};
+
+/// Lowest common ancestor queries with binary lifting.
+///
+/// Note:
+///     Every node stores its 2^k-th ancestors, built in O(n log n). Ancestor, LCA and
+///     distance queries take O(log n).
+#[derive(Debug, Clone)]
+pub struct Lca<N> {
+    root: N,
+    depths: Vec<usize>,
+    // ancestors[k][v] is the 2^k-th ancestor of v, the root is its own ancestor.
+    ancestors: Vec<Vec<usize>>,
+}
+
+impl<N: NodeId> Lca<N> {
+    /// Preprocesses the tree hanging from the root.
+    ///
+    /// Arguments:
+    ///
+    /// * `tree`: An undirected tree, or a directed one with edges leading away from the root.
+    /// * `root`: The root of the tree.
+    ///
+    /// Panics when some node of the graph is not reachable from the root.
+    pub fn new<G: GraphBase<Node = N>>(tree: &G, root: N) -> Self {
+        let node_count = tree.node_count();
+        let mut depths = vec![usize::MAX; node_count];
+        let mut parents = vec![root.index(); node_count];
+        for visit in bfs(tree, root) {
+            depths[visit.node.index()] = visit.depth;
+            if let Some(parent) = visit.parent {
+                parents[visit.node.index()] = parent.index();
+            }
+        }
+
+        assert!(depths.iter().all(|&depth| depth != usize::MAX), "Every node must be reachable from the root");
+
+        let levels = (usize::BITS - node_count.max(1).leading_zeros()) as usize;
+        let mut ancestors = vec![parents];
+        for k in 1..levels {
+            let previous = &ancestors[k - 1];
+            let next = previous.iter().map(|&ancestor| previous[ancestor]).collect();
+            ancestors.push(next);
+        }
+
+        Lca { root, depths, ancestors }
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> N {
+        self.root
+    }
+
+    /// Returns the number of edges between the node and the root.
+    pub fn depth(&self, node: N) -> usize {
+        self.depths[node.index()]
+    }
+
+    /// Returns the ancestor k edges above the node, `None` when the node is not that deep.
+    pub fn kth_ancestor(&self, node: N, k: usize) -> Option<N> {
+        if k > self.depth(node) {
+            return None;
+        }
+
+        let mut current = node.index();
+        for (level, ancestors) in self.ancestors.iter().enumerate() {
+            if k >> level & 1 == 1 {
+                current = ancestors[current];
+            }
+        }
+
+        Some(N::from_index(current))
+    }
+
+    /// Returns the deepest node that is an ancestor of both nodes.
+    pub fn lca(&self, a: N, b: N) -> N {
+        let (mut a, b) = if self.depth(a) >= self.depth(b) { (a, b) } else { (b, a) };
+        a = self.kth_ancestor(a, self.depth(a) - self.depth(b)).unwrap();
+
+        if a == b {
+            return a;
+        }
+
+        let (mut a, mut b) = (a.index(), b.index());
+        for ancestors in self.ancestors.iter().rev() {
+            if ancestors[a] != ancestors[b] {
+                a = ancestors[a];
+                b = ancestors[b];
+            }
+        }
+
+        N::from_index(self.ancestors[0][a])
+    }
+
+    /// Returns the number of edges on the path between the nodes.
+    pub fn distance(&self, a: N, b: N) -> usize {
+        self.depth(a) + self.depth(b) - 2 * self.depth(self.lca(a, b))
+    }
+}
+
+/// Lowest common ancestor queries with an Euler tour and a sparse table.
+///
+/// Note:
+///     The LCA of two nodes is the shallowest node of the tour between their first
+///     occurrences. The sparse table over the tour is built in O(n log n) and answers
+///     every query in O(1).
+#[derive(Debug, Clone)]
+pub struct EulerTourLca<N> {
+    tour: Vec<N>,
+    depths: Vec<usize>,
+    first_occurrences: Vec<usize>,
+    // table[k][i] is the position of the shallowest node of tour[i..i + 2^k].
+    table: Vec<Vec<usize>>,
+}
+
+impl<N: NodeId> EulerTourLca<N> {
+    /// Preprocesses the tree hanging from the root.
+    ///
+    /// Panics when some node of the graph is not reachable from the root.
+    pub fn new<G: GraphBase<Node = N>>(tree: &G, root: N) -> Self {
+        let node_count = tree.node_count();
+        let mut tour = Vec::with_capacity(2 * node_count);
+        let mut depths = vec![usize::MAX; node_count];
+        let mut first_occurrences = vec![usize::MAX; node_count];
+        let mut parents: Vec<Option<N>> = vec![None; node_count];
+
+        for event in dfs_events(tree, root) {
+            match event {
+                DfsEvent::Discover(visit) => {
+                    depths[visit.node.index()] = visit.depth;
+                    first_occurrences[visit.node.index()] = tour.len();
+                    parents[visit.node.index()] = visit.parent;
+                    tour.push(visit.node);
+                }
+                DfsEvent::Finish(node) => {
+                    if let Some(parent) = parents[node.index()] {
+                        tour.push(parent);
+                    }
+                }
+            }
+        }
+
+        assert!(depths.iter().all(|&depth| depth != usize::MAX), "Every node must be reachable from the root");
+
+        let shallower = |a: usize, b: usize| if depths[tour[a].index()] <= depths[tour[b].index()] { a } else { b };
+
+        let mut table = vec![(0..tour.len()).collect::<Vec<_>>()];
+        let mut width = 1;
+        while 2 * width <= tour.len() {
+            let previous = table.last().unwrap();
+            let next = (0..=tour.len() - 2 * width).map(|i| shallower(previous[i], previous[i + width])).collect();
+            table.push(next);
+            width *= 2;
+        }
+
+        EulerTourLca { tour, depths, first_occurrences, table }
+    }
+
+    /// Returns the number of edges between the node and the root.
+    pub fn depth(&self, node: N) -> usize {
+        self.depths[node.index()]
+    }
+
+    /// Returns the deepest node that is an ancestor of both nodes.
+    pub fn lca(&self, a: N, b: N) -> N {
+        let (mut from, mut to) = (self.first_occurrences[a.index()], self.first_occurrences[b.index()]);
+        if from > to {
+            core::mem::swap(&mut from, &mut to);
+        }
+
+        let level = (usize::BITS - 1 - (to - from + 1).leading_zeros()) as usize;
+        let left = self.table[level][from];
+        let right = self.table[level][to + 1 - (1 << level)];
+
+        let (left, right) = (self.tour[left], self.tour[right]);
+        if self.depth(left) <= self.depth(right) { left } else { right }
+    }
+
+    /// Returns the number of edges on the path between the nodes.
+    pub fn distance(&self, a: N, b: N) -> usize {
+        self.depth(a) + self.depth(b) - 2 * self.depth(self.lca(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::Graph;
+    use rand::Rng;
+
+    fn sample() -> Graph {
+        //       0
+        //     / | \
+        //    1  2  3
+        //   / \     \
+        //  4   5     6
+        //      |
+        //      7
+        Graph::undirected_from_pairs(8, [(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (3, 6), (5, 7)])
+    }
+
+    #[test]
+    fn lca_should_be_found_with_binary_lifting() {
+        let lca = Lca::new(&sample(), 0);
+
+        assert_eq!(lca.lca(4, 7), 1);
+        assert_eq!(lca.lca(7, 6), 0);
+        assert_eq!(lca.lca(5, 7), 5);
+        assert_eq!(lca.lca(2, 2), 2);
+    }
+
+    #[test]
+    fn distances_and_ancestors_should_be_computed() {
+        let lca = Lca::new(&sample(), 0);
+
+        assert_eq!(lca.distance(4, 7), 3);
+        assert_eq!(lca.distance(7, 6), 5);
+        assert_eq!(lca.kth_ancestor(7, 2), Some(1));
+        assert_eq!(lca.kth_ancestor(7, 3), Some(0));
+        assert_eq!(lca.kth_ancestor(7, 4), None);
+    }
+
+    #[test]
+    fn lca_should_be_found_with_euler_tour() {
+        let lca = EulerTourLca::new(&sample(), 0);
+
+        assert_eq!(lca.lca(4, 7), 1);
+        assert_eq!(lca.lca(7, 6), 0);
+        assert_eq!(lca.lca(5, 7), 5);
+        assert_eq!(lca.distance(7, 6), 5);
+    }
+
+    #[test]
+    fn single_node_tree_should_be_supported() {
+        let tree: Graph = Graph::undirected(1);
+
+        assert_eq!(Lca::new(&tree, 0).lca(0, 0), 0);
+        assert_eq!(EulerTourLca::new(&tree, 0).lca(0, 0), 0);
+    }
+
+    #[test]
+    fn both_structures_should_match_naive_lca_on_random_trees() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let n = rng.gen_range(1..60);
+            let parents: Vec<usize> = (0..n).map(|node| if node == 0 { 0 } else { rng.gen_range(0..node) }).collect();
+            let tree: Graph = Graph::undirected_from_pairs(n, (1..n).map(|node| (parents[node], node)));
+
+            let naive = |a: usize, b: usize| {
+                let mut ancestors = vec![a];
+                while *ancestors.last().unwrap() != 0 {
+                    ancestors.push(parents[*ancestors.last().unwrap()]);
+                }
+
+                let mut current = b;
+                while !ancestors.contains(&current) {
+                    current = parents[current];
+                }
+
+                current
+            };
+
+            let lifting = Lca::new(&tree, 0);
+            let euler = EulerTourLca::new(&tree, 0);
+            for _ in 0..50 {
+                let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+
+                assert_eq!(lifting.lca(a, b), naive(a, b));
+                assert_eq!(euler.lca(a, b), naive(a, b));
+            }
+        }
+    }
+}
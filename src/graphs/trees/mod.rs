@@ -0,0 +1,23 @@
+pub mod centroid;
+pub mod diameter;
+pub mod lca;
+
+pub use centroid::{centroid_decomposition, centroids, CentroidDecomposition};
+pub use diameter::{diameter, Diameter};
+pub use lca::{EulerTourLca, Lca};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::graphs::{GraphBase, NodeId};
+
+// Trees are walked in both directions, whichever way their edges point.
+fn undirected_adjacency<G: GraphBase>(tree: &G) -> Vec<Vec<usize>> {
+    let mut adjacency = vec![Vec::new(); tree.node_count()];
+    for edge in tree.edges() {
+        adjacency[edge.from.index()].push(edge.to.index());
+        adjacency[edge.to.index()].push(edge.from.index());
+    }
+
+    adjacency
+}
@@ -0,0 +1,214 @@
+//! This module contains a 2-SAT solver built on strongly connected components.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Not;
+
+use crate::graphs::scc::tarjan_scc;
+use crate::graphs::{Graph, GraphBase};
+
+/// A boolean variable or its negation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Literal {
+    /// The index of the variable.
+    pub variable: usize,
+    /// The value of the variable that makes the literal true.
+    pub value: bool,
+}
+
+impl Literal {
+    /// Returns the literal that is true when the variable is true.
+    pub fn positive(variable: usize) -> Self {
+        Literal { variable, value: true }
+    }
+
+    /// Returns the literal that is true when the variable is false.
+    pub fn negative(variable: usize) -> Self {
+        Literal { variable, value: false }
+    }
+
+    // Every variable owns two nodes of the implication graph, the true literal comes first.
+    fn node(self) -> usize {
+        2 * self.variable + usize::from(!self.value)
+    }
+}
+
+impl Not for Literal {
+    type Output = Literal;
+
+    fn not(self) -> Literal {
+        Literal { variable: self.variable, value: !self.value }
+    }
+}
+
+/// Error reported when no assignment satisfies every clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsatisfiable {
+    /// A variable whose literals imply each other, so neither value is possible.
+    pub variable: usize,
+}
+
+impl fmt::Display for Unsatisfiable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "clauses are unsatisfiable, variable {} implies its own negation and back", self.variable)
+    }
+}
+
+impl core::error::Error for Unsatisfiable {}
+
+/// A conjunction of clauses with at most two literals each.
+#[derive(Debug, Clone)]
+pub struct TwoSat {
+    implications: Graph,
+}
+
+impl TwoSat {
+    /// Creates a formula over the given number of variables without clauses.
+    pub fn new(variable_count: usize) -> Self {
+        TwoSat { implications: Graph::directed(2 * variable_count) }
+    }
+
+    /// Returns the number of variables.
+    pub fn variable_count(&self) -> usize {
+        self.implications.node_count() / 2
+    }
+
+    /// Adds a new variable and returns its index.
+    pub fn add_variable(&mut self) -> usize {
+        self.implications.add_node();
+        self.implications.add_node() / 2
+    }
+
+    /// Requires at least one of the literals to be true.
+    pub fn add_clause(&mut self, a: Literal, b: Literal) {
+        self.implications.add_edge((!a).node(), b.node(), ());
+        self.implications.add_edge((!b).node(), a.node(), ());
+    }
+
+    /// Requires the second literal to be true whenever the first one is.
+    pub fn add_implication(&mut self, from: Literal, to: Literal) {
+        self.add_clause(!from, to);
+    }
+
+    /// Requires the literal to be true.
+    pub fn force(&mut self, literal: Literal) {
+        self.add_clause(literal, literal);
+    }
+
+    /// Requires exactly one of the literals to be true.
+    pub fn add_exclusive(&mut self, a: Literal, b: Literal) {
+        self.add_clause(a, b);
+        self.add_clause(!a, !b);
+    }
+
+    /// Finds an assignment satisfying every clause.
+    ///
+    /// Note:
+    ///     The formula is unsatisfiable exactly when some variable and its negation share
+    ///     a strongly connected component of the implication graph. Otherwise every variable
+    ///     takes the value of its literal that comes later in topological order. Runs in
+    ///     O(V + C) for V variables and C clauses.
+    ///
+    /// Returns:
+    ///
+    /// The value of every variable, or a variable that makes the clauses contradict.
+    pub fn solve(&self) -> Result<Vec<bool>, Unsatisfiable> {
+        let components = tarjan_scc(&self.implications);
+
+        (0..self.variable_count())
+            .map(|variable| {
+                let positive = components.labels[Literal::positive(variable).node()];
+                let negative = components.labels[Literal::negative(variable).node()];
+
+                // Tarjan's labels follow reverse topological order.
+                match positive.cmp(&negative) {
+                    core::cmp::Ordering::Equal => Err(Unsatisfiable { variable }),
+                    ordering => Ok(ordering.is_lt()),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Solves a 2-SAT formula given as a list of clauses.
+///
+/// Arguments:
+///
+/// * `variable_count`: The number of variables, literals refer to indices below it.
+/// * `clauses`: Pairs of literals, at least one of every pair must be true.
+pub fn two_sat(variable_count: usize, clauses: impl IntoIterator<Item = (Literal, Literal)>) -> Result<Vec<bool>, Unsatisfiable> {
+    let mut formula = TwoSat::new(variable_count);
+    for (a, b) in clauses {
+        formula.add_clause(a, b);
+    }
+
+    formula.solve()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn satisfies(assignment: &[bool], clauses: &[(Literal, Literal)]) -> bool {
+        let holds = |literal: Literal| assignment[literal.variable] == literal.value;
+
+        clauses.iter().all(|&(a, b)| holds(a) || holds(b))
+    }
+
+    #[test]
+    fn satisfiable_formula_should_be_solved() {
+        let (x, y, z) = (Literal::positive(0), Literal::positive(1), Literal::positive(2));
+        let clauses = [(x, y), (!x, z), (!y, !z), (!x, !y)];
+
+        let assignment = two_sat(3, clauses).unwrap();
+
+        assert!(satisfies(&assignment, &clauses));
+    }
+
+    #[test]
+    fn contradiction_should_be_unsatisfiable() {
+        let (x, y) = (Literal::positive(0), Literal::positive(1));
+        let clauses = [(x, y), (x, !y), (!x, y), (!x, !y)];
+
+        let error = two_sat(2, clauses).unwrap_err();
+
+        assert!(error.variable < 2);
+    }
+
+    #[test]
+    fn forced_and_exclusive_literals_should_be_respected() {
+        let mut formula = TwoSat::new(2);
+        let x = Literal::positive(0);
+        let y = Literal::positive(formula.add_variable());
+        formula.force(!x);
+        formula.add_exclusive(x, y);
+        formula.add_implication(y, Literal::negative(1));
+
+        let assignment = formula.solve().unwrap();
+
+        assert_eq!(assignment, vec![false, false, true]);
+        assert_eq!(formula.implications.edge_count(), 8);
+    }
+
+    #[test]
+    fn solver_should_match_brute_force_on_random_formulas() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let variable_count = rng.gen_range(1..8);
+            let clause_count = rng.gen_range(0..16);
+            let mut literal = || Literal { variable: rng.gen_range(0..variable_count), value: rng.gen() };
+            let clauses: Vec<_> = (0..clause_count).map(|_| (literal(), literal())).collect();
+
+            let brute_force = (0..1u32 << variable_count)
+                .map(|mask| (0..variable_count).map(|variable| mask >> variable & 1 == 1).collect::<Vec<_>>())
+                .any(|assignment| satisfies(&assignment, &clauses));
+
+            match two_sat(variable_count, clauses.iter().copied()) {
+                Ok(assignment) => assert!(satisfies(&assignment, &clauses)),
+                Err(_) => assert!(!brute_force),
+            }
+        }
+    }
+}
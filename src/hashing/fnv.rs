@@ -0,0 +1,137 @@
+//! This module contains the Fowler–Noll–Vo FNV-1a hash functions.
+
+use core::hash::Hasher;
+
+use crate::hashing::StreamingHasher;
+
+const OFFSET_BASIS_32: u32 = 0x811c9dc5;
+const PRIME_32: u32 = 0x01000193;
+const OFFSET_BASIS_64: u64 = 0xcbf29ce484222325;
+const PRIME_64: u64 = 0x00000100000001b3;
+
+/// Streaming 32-bit FNV-1a hash.
+///
+/// Note: every byte is xored into the state, which is then multiplied by the FNV prime.
+/// It is fast and simple but mixes poorly, so it suits short keys such as identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fnv1a32 {
+    state: u32,
+}
+
+/// Streaming 64-bit FNV-1a hash, also usable as a `Hasher` for hash maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fnv1a64 {
+    state: u64,
+}
+
+impl Fnv1a32 {
+    /// Creates the hasher with the standard offset basis.
+    pub fn new() -> Self {
+        Fnv1a32 { state: OFFSET_BASIS_32 }
+    }
+}
+
+impl Default for Fnv1a32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamingHasher for Fnv1a32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = (self.state ^ byte as u32).wrapping_mul(PRIME_32);
+        }
+    }
+
+    fn finalize(self) -> u32 {
+        self.state
+    }
+}
+
+impl Fnv1a64 {
+    /// Creates the hasher with the standard offset basis.
+    pub fn new() -> Self {
+        Fnv1a64 { state: OFFSET_BASIS_64 }
+    }
+}
+
+impl Default for Fnv1a64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamingHasher for Fnv1a64 {
+    type Output = u64;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = (self.state ^ byte as u64).wrapping_mul(PRIME_64);
+        }
+    }
+
+    fn finalize(self) -> u64 {
+        self.state
+    }
+}
+
+impl Hasher for Fnv1a64 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// Computes the 32-bit FNV-1a hash of the bytes.
+pub fn fnv1a_32(bytes: &[u8]) -> u32 {
+    let mut hasher = Fnv1a32::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+/// Computes the 64-bit FNV-1a hash of the bytes.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a64::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::tests::hash_in_pieces;
+
+    #[test]
+    fn hashes_should_match_reference_values() {
+        assert_eq!(fnv1a_32(b""), 0x811c9dc5);
+        assert_eq!(fnv1a_32(b"a"), 0xe40c292c);
+        assert_eq!(fnv1a_32(b"foobar"), 0xbf9cf968);
+        assert_eq!(fnv1a_64(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn streamed_hash_should_match_one_shot_hash() {
+        let text = b"The quick brown fox jumps over the lazy dog, again and again and again";
+
+        assert_eq!(hash_in_pieces(Fnv1a32::new(), text), fnv1a_32(text));
+        assert_eq!(hash_in_pieces(Fnv1a64::new(), text), fnv1a_64(text));
+    }
+
+    #[test]
+    fn hasher_should_finish_with_the_hash() {
+        let mut hasher = Fnv1a64::default();
+
+        hasher.write(b"foo");
+        hasher.write(b"bar");
+
+        assert_eq!(hasher.finish(), fnv1a_64(b"foobar"));
+    }
+}
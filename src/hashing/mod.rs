@@ -0,0 +1,98 @@
+pub mod fnv;
+pub mod murmur3;
+pub mod xxhash;
+pub mod sha256;
+
+pub use fnv::{fnv1a_32, fnv1a_64, Fnv1a32, Fnv1a64};
+pub use murmur3::{murmur3_128, murmur3_32, Murmur3Hash128, Murmur3Hash32};
+pub use xxhash::{xxh32, xxh64, XxHash32, XxHash64};
+pub use sha256::{sha256, Sha256};
+
+/// Hash function computed incrementally over chunks of bytes.
+///
+/// Note: feeding the bytes in any split gives the same result as hashing them at once.
+pub trait StreamingHasher {
+    /// Type of the finished hash value.
+    type Output;
+
+    /// Feeds more bytes to the hash.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Finishes the hash of every byte fed so far.
+    fn finalize(self) -> Self::Output;
+}
+
+// Collects bytes into fixed-size blocks for hash functions that consume whole blocks.
+#[derive(Debug, Clone)]
+pub(crate) struct BlockBuffer<const N: usize> {
+    block: [u8; N],
+    filled: usize,
+}
+
+impl<const N: usize> BlockBuffer<N> {
+    pub(crate) fn new() -> Self {
+        BlockBuffer { block: [0; N], filled: 0 }
+    }
+
+    // Passes every completed block to `process` and keeps the incomplete rest.
+    pub(crate) fn feed(&mut self, mut bytes: &[u8], mut process: impl FnMut(&[u8; N])) {
+        if self.filled > 0 {
+            let taken = (N - self.filled).min(bytes.len());
+            self.block[self.filled..self.filled + taken].copy_from_slice(&bytes[..taken]);
+            self.filled += taken;
+            bytes = &bytes[taken..];
+
+            if self.filled < N {
+                return;
+            }
+            process(&self.block);
+            self.filled = 0;
+        }
+
+        let mut blocks = bytes.chunks_exact(N);
+        for block in &mut blocks {
+            process(block.try_into().unwrap());
+        }
+
+        let rest = blocks.remainder();
+        self.block[..rest.len()].copy_from_slice(rest);
+        self.filled = rest.len();
+    }
+
+    // Returns the bytes of the incomplete block.
+    pub(crate) fn remainder(&self) -> &[u8] {
+        &self.block[..self.filled]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand::Rng;
+
+    // Feeds the bytes in random pieces, including empty ones.
+    pub(crate) fn hash_in_pieces<H: StreamingHasher>(mut hasher: H, bytes: &[u8]) -> H::Output {
+        let mut rng = rand::thread_rng();
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let (piece, tail) = rest.split_at(rng.gen_range(0..=rest.len().min(40)));
+            hasher.update(piece);
+            rest = tail;
+        }
+        hasher.finalize()
+    }
+
+    #[test]
+    fn block_buffer_should_pass_whole_blocks_in_order() {
+        let mut buffer = BlockBuffer::<4>::new();
+        let mut blocks = Vec::new();
+
+        buffer.feed(b"ab", |block| blocks.push(*block));
+        buffer.feed(b"cdefghij", |block| blocks.push(*block));
+        buffer.feed(b"k", |block| blocks.push(*block));
+
+        assert_eq!(blocks, vec![*b"abcd", *b"efgh"]);
+        assert_eq!(buffer.remainder(), b"ijk");
+    }
+}
@@ -0,0 +1,191 @@
+//! This module contains Austin Appleby's MurmurHash3 in its x86 32-bit and x64 128-bit variants.
+
+use crate::hashing::{BlockBuffer, StreamingHasher};
+
+const C1_32: u32 = 0xcc9e2d51;
+const C2_32: u32 = 0x1b873593;
+const C1_64: u64 = 0x87c37b91114253d5;
+const C2_64: u64 = 0x4cf5ad432745937f;
+
+// Mixes the bits of the state so that every input bit affects every output bit.
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ (h >> 16)
+}
+
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^ (k >> 33)
+}
+
+fn scramble32(k: u32) -> u32 {
+    k.wrapping_mul(C1_32).rotate_left(15).wrapping_mul(C2_32)
+}
+
+// Reads up to eight bytes as a little-endian number.
+fn little_endian(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |value, &byte| value << 8 | byte as u64)
+}
+
+/// Streaming 32-bit MurmurHash3 (x86_32).
+///
+/// Note: four-byte blocks are scrambled by multiplications and rotations and then mixed
+/// into the state, and a final avalanche spreads the remaining bits.
+#[derive(Debug, Clone)]
+pub struct Murmur3Hash32 {
+    state: u32,
+    length: u64,
+    buffer: BlockBuffer<4>,
+}
+
+impl Murmur3Hash32 {
+    /// Creates the hasher with the given seed.
+    pub fn new(seed: u32) -> Self {
+        Murmur3Hash32 { state: seed, length: 0, buffer: BlockBuffer::new() }
+    }
+}
+
+impl StreamingHasher for Murmur3Hash32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.length += bytes.len() as u64;
+        let state = &mut self.state;
+        self.buffer.feed(bytes, |block| {
+            *state ^= scramble32(u32::from_le_bytes(*block));
+            *state = state.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+        });
+    }
+
+    fn finalize(self) -> u32 {
+        let tail = self.buffer.remainder();
+        let mut h = self.state;
+        if !tail.is_empty() {
+            h ^= scramble32(little_endian(tail) as u32);
+        }
+
+        // The reference implementation mixes in the length modulo 2^32.
+        fmix32(h ^ self.length as u32)
+    }
+}
+
+/// Streaming 128-bit MurmurHash3 (x64_128).
+///
+/// Note: sixteen-byte blocks feed two 64-bit lanes that are mixed into each other. The
+/// hash is returned as the little-endian number of its sixteen output bytes.
+#[derive(Debug, Clone)]
+pub struct Murmur3Hash128 {
+    h1: u64,
+    h2: u64,
+    length: u64,
+    buffer: BlockBuffer<16>,
+}
+
+impl Murmur3Hash128 {
+    /// Creates the hasher with the given seed.
+    pub fn new(seed: u32) -> Self {
+        Murmur3Hash128 { h1: seed as u64, h2: seed as u64, length: 0, buffer: BlockBuffer::new() }
+    }
+}
+
+fn scramble64_first(k: u64) -> u64 {
+    k.wrapping_mul(C1_64).rotate_left(31).wrapping_mul(C2_64)
+}
+
+fn scramble64_second(k: u64) -> u64 {
+    k.wrapping_mul(C2_64).rotate_left(33).wrapping_mul(C1_64)
+}
+
+impl StreamingHasher for Murmur3Hash128 {
+    type Output = u128;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.length += bytes.len() as u64;
+        let (h1, h2) = (&mut self.h1, &mut self.h2);
+        self.buffer.feed(bytes, |block| {
+            let k1 = u64::from_le_bytes(block[..8].try_into().unwrap());
+            let k2 = u64::from_le_bytes(block[8..].try_into().unwrap());
+
+            *h1 ^= scramble64_first(k1);
+            *h1 = h1.rotate_left(27).wrapping_add(*h2).wrapping_mul(5).wrapping_add(0x52dce729);
+            *h2 ^= scramble64_second(k2);
+            *h2 = h2.rotate_left(31).wrapping_add(*h1).wrapping_mul(5).wrapping_add(0x38495ab5);
+        });
+    }
+
+    fn finalize(self) -> u128 {
+        let tail = self.buffer.remainder();
+        let (mut h1, mut h2) = (self.h1, self.h2);
+        if tail.len() > 8 {
+            h2 ^= scramble64_second(little_endian(&tail[8..]));
+        }
+        if !tail.is_empty() {
+            h1 ^= scramble64_first(little_endian(&tail[..tail.len().min(8)]));
+        }
+
+        h1 ^= self.length;
+        h2 ^= self.length;
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        h1 = fmix64(h1);
+        h2 = fmix64(h2);
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+
+        (h2 as u128) << 64 | h1 as u128
+    }
+}
+
+/// Computes the 32-bit MurmurHash3 of the bytes.
+pub fn murmur3_32(bytes: &[u8], seed: u32) -> u32 {
+    let mut hasher = Murmur3Hash32::new(seed);
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+/// Computes the 128-bit MurmurHash3 of the bytes.
+pub fn murmur3_128(bytes: &[u8], seed: u32) -> u128 {
+    let mut hasher = Murmur3Hash128::new(seed);
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::tests::hash_in_pieces;
+
+    const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    #[test]
+    fn hash32_should_match_reference_values() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514e28b7);
+        assert_eq!(murmur3_32(b"", 0xffffffff), 0x81f16f39);
+        assert_eq!(murmur3_32(&[0, 0, 0, 0], 0), 0x2362f9de);
+        assert_eq!(murmur3_32(b"Hello, world!", 1234), 0xfaf6cdb3);
+        assert_eq!(murmur3_32(FOX, 0), 0x2e4ff723);
+    }
+
+    #[test]
+    fn hash128_should_match_reference_values() {
+        assert_eq!(murmur3_128(b"", 0), 0);
+        assert_eq!(murmur3_128(FOX, 0).to_le_bytes(), [0x6c, 0x1b, 0x07, 0xbc, 0x7b, 0xbc, 0x4b, 0xe3, 0x47, 0x93, 0x9a, 0xc4, 0xa9, 0x3c, 0x43, 0x7a]);
+    }
+
+    #[test]
+    fn streamed_hash_should_match_one_shot_hash() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+        for length in [0, 1, 3, 4, 5, 15, 16, 17, 31, 1000] {
+            assert_eq!(hash_in_pieces(Murmur3Hash32::new(9), &bytes[..length]), murmur3_32(&bytes[..length], 9));
+            assert_eq!(hash_in_pieces(Murmur3Hash128::new(9), &bytes[..length]), murmur3_128(&bytes[..length], 9));
+        }
+    }
+}
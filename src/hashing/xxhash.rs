@@ -0,0 +1,244 @@
+//! This module contains the XXH32 and XXH64 hash functions of the xxHash family.
+
+use core::hash::Hasher;
+
+use crate::hashing::{BlockBuffer, StreamingHasher};
+
+const PRIME32_1: u32 = 0x9e3779b1;
+const PRIME32_2: u32 = 0x85ebca77;
+const PRIME32_3: u32 = 0xc2b2ae3d;
+const PRIME32_4: u32 = 0x27d4eb2f;
+const PRIME32_5: u32 = 0x165667b1;
+
+const PRIME64_1: u64 = 0x9e3779b185ebca87;
+const PRIME64_2: u64 = 0xc2b2ae3d27d4eb4f;
+const PRIME64_3: u64 = 0x165667b19e3779f9;
+const PRIME64_4: u64 = 0x85ebca77c2b2ae63;
+const PRIME64_5: u64 = 0x27d4eb2f165667c5;
+
+fn round32(accumulator: u32, lane: u32) -> u32 {
+    accumulator.wrapping_add(lane.wrapping_mul(PRIME32_2)).rotate_left(13).wrapping_mul(PRIME32_1)
+}
+
+fn round64(accumulator: u64, lane: u64) -> u64 {
+    accumulator.wrapping_add(lane.wrapping_mul(PRIME64_2)).rotate_left(31).wrapping_mul(PRIME64_1)
+}
+
+fn merge64(accumulator: u64, lane: u64) -> u64 {
+    (accumulator ^ round64(0, lane)).wrapping_mul(PRIME64_1).wrapping_add(PRIME64_4)
+}
+
+// Four accumulators start from the seed and each takes every fourth lane of a stripe.
+fn initial_lanes32(seed: u32) -> [u32; 4] {
+    [seed.wrapping_add(PRIME32_1).wrapping_add(PRIME32_2), seed.wrapping_add(PRIME32_2), seed, seed.wrapping_sub(PRIME32_1)]
+}
+
+fn initial_lanes64(seed: u64) -> [u64; 4] {
+    [seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2), seed.wrapping_add(PRIME64_2), seed, seed.wrapping_sub(PRIME64_1)]
+}
+
+/// Streaming 32-bit xxHash (XXH32).
+///
+/// Note: sixteen-byte stripes are spread over four independent accumulators, which lets
+/// the processor work on them in parallel, and the tail is folded in four and one bytes
+/// at a time before the final avalanche.
+#[derive(Debug, Clone)]
+pub struct XxHash32 {
+    seed: u32,
+    lanes: [u32; 4],
+    length: u64,
+    buffer: BlockBuffer<16>,
+}
+
+impl XxHash32 {
+    /// Creates the hasher with the given seed.
+    pub fn new(seed: u32) -> Self {
+        XxHash32 { seed, lanes: initial_lanes32(seed), length: 0, buffer: BlockBuffer::new() }
+    }
+}
+
+impl StreamingHasher for XxHash32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.length += bytes.len() as u64;
+        let lanes = &mut self.lanes;
+        self.buffer.feed(bytes, |stripe| {
+            for (lane, word) in lanes.iter_mut().zip(stripe.chunks_exact(4)) {
+                *lane = round32(*lane, u32::from_le_bytes(word.try_into().unwrap()));
+            }
+        });
+    }
+
+    fn finalize(self) -> u32 {
+        let [v1, v2, v3, v4] = self.lanes;
+        let mut h = if self.length >= 16 {
+            v1.rotate_left(1).wrapping_add(v2.rotate_left(7)).wrapping_add(v3.rotate_left(12)).wrapping_add(v4.rotate_left(18))
+        } else {
+            self.seed.wrapping_add(PRIME32_5)
+        };
+
+        // The reference implementation mixes in the length modulo 2^32.
+        h = h.wrapping_add(self.length as u32);
+
+        let mut words = self.buffer.remainder().chunks_exact(4);
+        for word in &mut words {
+            h = h.wrapping_add(u32::from_le_bytes(word.try_into().unwrap()).wrapping_mul(PRIME32_3));
+            h = h.rotate_left(17).wrapping_mul(PRIME32_4);
+        }
+        for &byte in words.remainder() {
+            h = h.wrapping_add((byte as u32).wrapping_mul(PRIME32_5));
+            h = h.rotate_left(11).wrapping_mul(PRIME32_1);
+        }
+
+        h ^= h >> 15;
+        h = h.wrapping_mul(PRIME32_2);
+        h ^= h >> 13;
+        h = h.wrapping_mul(PRIME32_3);
+        h ^ (h >> 16)
+    }
+}
+
+/// Streaming 64-bit xxHash (XXH64), also usable as a `Hasher` for hash maps.
+///
+/// Note: the same scheme as `XxHash32` over 32-byte stripes of 64-bit lanes, with the
+/// accumulators merged into the result one by one.
+#[derive(Debug, Clone)]
+pub struct XxHash64 {
+    seed: u64,
+    lanes: [u64; 4],
+    length: u64,
+    buffer: BlockBuffer<32>,
+}
+
+impl XxHash64 {
+    /// Creates the hasher with the given seed.
+    pub fn new(seed: u64) -> Self {
+        XxHash64 { seed, lanes: initial_lanes64(seed), length: 0, buffer: BlockBuffer::new() }
+    }
+
+    // Computes the hash without consuming the hasher.
+    fn digest(&self) -> u64 {
+        let [v1, v2, v3, v4] = self.lanes;
+        let mut h = if self.length >= 32 {
+            let h = v1.rotate_left(1).wrapping_add(v2.rotate_left(7)).wrapping_add(v3.rotate_left(12)).wrapping_add(v4.rotate_left(18));
+            self.lanes.iter().fold(h, |h, &lane| merge64(h, lane))
+        } else {
+            self.seed.wrapping_add(PRIME64_5)
+        };
+
+        h = h.wrapping_add(self.length);
+
+        let mut words = self.buffer.remainder().chunks_exact(8);
+        for word in &mut words {
+            h ^= round64(0, u64::from_le_bytes(word.try_into().unwrap()));
+            h = h.rotate_left(27).wrapping_mul(PRIME64_1).wrapping_add(PRIME64_4);
+        }
+        let mut rest = words.remainder();
+        if rest.len() >= 4 {
+            h ^= (u32::from_le_bytes(rest[..4].try_into().unwrap()) as u64).wrapping_mul(PRIME64_1);
+            h = h.rotate_left(23).wrapping_mul(PRIME64_2).wrapping_add(PRIME64_3);
+            rest = &rest[4..];
+        }
+        for &byte in rest {
+            h ^= (byte as u64).wrapping_mul(PRIME64_5);
+            h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+
+        h ^= h >> 33;
+        h = h.wrapping_mul(PRIME64_2);
+        h ^= h >> 29;
+        h = h.wrapping_mul(PRIME64_3);
+        h ^ (h >> 32)
+    }
+}
+
+impl Default for XxHash64 {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl StreamingHasher for XxHash64 {
+    type Output = u64;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.length += bytes.len() as u64;
+        let lanes = &mut self.lanes;
+        self.buffer.feed(bytes, |stripe| {
+            for (lane, word) in lanes.iter_mut().zip(stripe.chunks_exact(8)) {
+                *lane = round64(*lane, u64::from_le_bytes(word.try_into().unwrap()));
+            }
+        });
+    }
+
+    fn finalize(self) -> u64 {
+        self.digest()
+    }
+}
+
+impl Hasher for XxHash64 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.digest()
+    }
+}
+
+/// Computes the 32-bit xxHash of the bytes.
+pub fn xxh32(bytes: &[u8], seed: u32) -> u32 {
+    let mut hasher = XxHash32::new(seed);
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+/// Computes the 64-bit xxHash of the bytes.
+pub fn xxh64(bytes: &[u8], seed: u64) -> u64 {
+    let mut hasher = XxHash64::new(seed);
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::tests::hash_in_pieces;
+
+    #[test]
+    fn hash32_should_match_reference_values() {
+        assert_eq!(xxh32(b"", 0), 0x02cc5d05);
+        assert_eq!(xxh32(b"a", 0), 0x550d7456);
+        assert_eq!(xxh32(b"abc", 0), 0x32d153ff);
+    }
+
+    #[test]
+    fn hash64_should_match_reference_values() {
+        assert_eq!(xxh64(b"", 0), 0xef46db3751d8e999);
+        assert_eq!(xxh64(b"a", 0), 0xd24ec4f1a98c6e5b);
+        assert_eq!(xxh64(b"abc", 0), 0x44bc2cf5ad770999);
+    }
+
+    #[test]
+    fn streamed_hash_should_match_one_shot_hash() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+        for length in [0, 1, 4, 7, 15, 16, 17, 31, 32, 33, 63, 1000] {
+            assert_eq!(hash_in_pieces(XxHash32::new(3), &bytes[..length]), xxh32(&bytes[..length], 3));
+            assert_eq!(hash_in_pieces(XxHash64::new(3), &bytes[..length]), xxh64(&bytes[..length], 3));
+        }
+    }
+
+    #[test]
+    fn hasher_should_finish_without_consuming() {
+        let mut hasher = XxHash64::default();
+
+        hasher.write(b"ab");
+        let partial = hasher.finish();
+        hasher.write(b"c");
+
+        assert_eq!(partial, xxh64(b"ab", 0));
+        assert_eq!(hasher.finish(), xxh64(b"abc", 0));
+    }
+}
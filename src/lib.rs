@@ -0,0 +1,23 @@
+//! Algorithms and data structures.
+//!
+//! Without the default `std` feature the crate builds under `no_std` with `alloc`, leaving
+//! out the pieces that need `thread_rng`, hash maps or floating point square roots.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod numbers;
+pub mod strings;
+pub mod compression;
+pub mod graphs;
+pub mod structures;
+pub mod combinatorics;
+pub mod dp;
+pub mod geometry;
+pub mod random;
+pub mod hashing;
+pub mod crypto;
+pub mod queries;
+pub mod streaming;
+pub mod optimization;
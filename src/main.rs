@@ -1,5 +1,3 @@
-pub mod numbers;
-
 fn main()  {
 
-}
\ No newline at end of file
+}
@@ -0,0 +1,225 @@
+//! This module contains digits of unsigned integers in any base: iterators, conversion to and
+//! from text, digit sums and palindromes.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use num::{PrimInt, Unsigned};
+
+const ALPHANUMERIC: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+// Converts the base into the number type.
+fn base_of<T: PrimInt>(base: u32) -> T {
+    assert!(base >= 2, "Base must be at least 2, got {}", base);
+    T::from(base).expect("Base must fit into the number type")
+}
+
+/// Returns the digits of the value from the least significant one.
+///
+/// Note: zero has the single digit 0.
+///
+/// Panics when the base is below 2 or does not fit into the number type.
+pub fn digits_le<T: PrimInt + Unsigned>(value: T, base: u32) -> impl Iterator<Item = u32> {
+    let base = base_of::<T>(base);
+    let mut rest = Some(value);
+    core::iter::from_fn(move || {
+        let value = rest?;
+        let quotient = value / base;
+        rest = (!quotient.is_zero()).then_some(quotient);
+        (value % base).to_u32()
+    })
+}
+
+/// Returns the digits of the value from the most significant one.
+///
+/// Note: the highest power of the base not above the value is found first, then every digit
+/// is read off with one division, so no digits are buffered.
+///
+/// Panics when the base is below 2 or does not fit into the number type.
+pub fn digits_be<T: PrimInt + Unsigned>(value: T, base: u32) -> impl Iterator<Item = u32> {
+    let base = base_of::<T>(base);
+    let mut power = T::one();
+    while power <= value / base {
+        power = power * base;
+    }
+
+    let mut power = Some(power);
+    core::iter::from_fn(move || {
+        let current = power?;
+        power = (current > T::one()).then(|| current / base);
+        (value / current % base).to_u32()
+    })
+}
+
+/// Writes the value in the given base with the digits 0-9 and a-z.
+///
+/// Panics when the base is not within 2..=36 or does not fit into the number type.
+pub fn to_base<T: PrimInt + Unsigned>(value: T, base: u32) -> String {
+    assert!(base <= 36, "Base must be within 2..=36, got {}", base);
+    digits_be(value, base).map(|digit| ALPHANUMERIC[digit as usize] as char).collect()
+}
+
+// Joins digits given from the most significant one, none when a digit is invalid or the value overflows.
+fn from_digits<T: PrimInt>(digits: impl Iterator<Item = Option<u32>>, base: T) -> Option<T> {
+    let mut value = T::zero();
+    let mut count = 0;
+    for digit in digits {
+        value = value.checked_mul(&base)?.checked_add(&T::from(digit?)?)?;
+        count += 1;
+    }
+
+    (count > 0).then_some(value)
+}
+
+/// Reads a value written in the given base with the digits 0-9 and a-z, in either case.
+///
+/// Returns:
+///
+/// The value, none when the text is empty, has a character that is not a digit of the base
+/// or the value does not fit into the number type.
+///
+/// Panics when the base is not within 2..=36 or does not fit into the number type.
+pub fn from_base<T: PrimInt + Unsigned>(text: &str, base: u32) -> Option<T> {
+    assert!(base <= 36, "Base must be within 2..=36, got {}", base);
+    from_digits(text.chars().map(|symbol| symbol.to_digit(base)), base_of::<T>(base))
+}
+
+/// Writes the value with the given digits, the base being the number of digits.
+///
+/// Panics when there are fewer than two digits or their number does not fit into the number type.
+pub fn to_alphabet<T: PrimInt + Unsigned>(value: T, alphabet: &[char]) -> String {
+    digits_be(value, alphabet.len() as u32).map(|digit| alphabet[digit as usize]).collect()
+}
+
+/// Reads a value written with the given digits, the base being the number of digits.
+///
+/// Returns:
+///
+/// The value, none when the text is empty, has a character that is not one of the digits
+/// or the value does not fit into the number type.
+///
+/// Panics when there are fewer than two digits or their number does not fit into the number type.
+pub fn from_alphabet<T: PrimInt + Unsigned>(text: &str, alphabet: &[char]) -> Option<T> {
+    let base = base_of::<T>(alphabet.len() as u32);
+    from_digits(text.chars().map(|symbol| alphabet.iter().position(|&digit| digit == symbol).map(|p| p as u32)), base)
+}
+
+/// Adds up the digits of the value in the given base.
+///
+/// Panics when the base is below 2 or does not fit into the number type.
+pub fn digit_sum<T: PrimInt + Unsigned>(value: T, base: u32) -> u64 {
+    digits_le(value, base).map(u64::from).sum()
+}
+
+/// Repeats summing the digits of the value in the given base until a single digit remains.
+///
+/// Note: the result is found directly, as a number and its digit sum leave the same remainder
+/// modulo base - 1.
+///
+/// Panics when the base is below 2 or does not fit into the number type.
+pub fn digital_root<T: PrimInt + Unsigned>(value: T, base: u32) -> u32 {
+    if value.is_zero() {
+        return 0;
+    }
+
+    let below_base = base_of::<T>(base) - T::one();
+    ((value - T::one()) % below_base + T::one()).to_u32().unwrap()
+}
+
+/// Tells whether the digits of the value in the given base read the same in both directions.
+///
+/// Panics when the base is below 2 or does not fit into the number type.
+pub fn is_palindrome<T: PrimInt + Unsigned>(value: T, base: u32) -> bool {
+    let digits: Vec<u32> = digits_le(value, base).collect();
+    digits.iter().eq(digits.iter().rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use rand::Rng;
+
+    #[test]
+    fn digits_should_come_in_both_orders() {
+        assert_eq!(digits_le(1234u32, 10).collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        assert_eq!(digits_be(1234u32, 10).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(digits_be(0u8, 2).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(digits_be(u64::MAX, 2).count(), 64);
+        assert_eq!(digits_be(255u8, 16).collect::<Vec<_>>(), vec![15, 15]);
+    }
+
+    #[test]
+    fn conversion_should_match_standard_formatting() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..1000 {
+            let value: u64 = rng.gen::<u64>() >> rng.gen_range(0..64);
+
+            assert_eq!(to_base(value, 2), format!("{:b}", value));
+            assert_eq!(to_base(value, 8), format!("{:o}", value));
+            assert_eq!(to_base(value, 10), format!("{}", value));
+            assert_eq!(to_base(value, 16), format!("{:x}", value));
+
+            let base = rng.gen_range(2..=36);
+            assert_eq!(from_base::<u64>(&to_base(value, base), base), Some(value));
+            assert_eq!(from_base::<u64>(&to_base(value, base).to_uppercase(), base), Some(value));
+        }
+        assert_eq!(to_base(u128::MAX, 36), "f5lxx1zz5pnorynqglhzmsp33");
+    }
+
+    #[test]
+    fn invalid_text_should_not_be_read() {
+        assert_eq!(from_base::<u32>("", 10), None);
+        assert_eq!(from_base::<u32>("12a", 10), None);
+        assert_eq!(from_base::<u8>("256", 10), None);
+        assert_eq!(from_base::<u8>("255", 10), Some(255));
+        assert_eq!(from_base::<u16>("zz", 36), Some(1295));
+    }
+
+    #[test]
+    fn alphabets_should_round_trip() {
+        let dna = ['A', 'C', 'G', 'T'];
+        let mut rng = rand::thread_rng();
+
+        assert_eq!(to_alphabet(27u32, &dna), "CGT");
+        assert_eq!(from_alphabet::<u32>("CGT", &dna), Some(27));
+        assert_eq!(from_alphabet::<u32>("CGU", &dna), None);
+        for _ in 0..100 {
+            let value: u32 = rng.gen();
+            assert_eq!(from_alphabet(&to_alphabet(value, &dna), &dna), Some(value));
+        }
+    }
+
+    #[test]
+    fn digit_sums_and_roots_should_match_repeated_summing() {
+        assert_eq!(digit_sum(9875u32, 10), 29);
+        assert_eq!(digit_sum(u64::MAX, 2), 64);
+
+        for base in [2, 3, 10, 16] {
+            for value in 0u32..2000 {
+                let mut root = value as u64;
+                while root >= base as u64 {
+                    root = digit_sum(root, base);
+                }
+
+                assert_eq!(digital_root(value, base) as u64, root, "{} in base {}", value, base);
+            }
+        }
+    }
+
+    #[test]
+    fn palindromes_should_read_the_same_backwards() {
+        assert!(is_palindrome(12321u32, 10));
+        assert!(!is_palindrome(12320u32, 10));
+        assert!(is_palindrome(0b1001u8, 2));
+        assert!(is_palindrome(0u8, 7));
+        assert!(is_palindrome(585u16, 2) && is_palindrome(585u16, 10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn base_one_should_panic() {
+        digit_sum(5u32, 1);
+    }
+}
@@ -0,0 +1,99 @@
+//! This module contains decompositions of even numbers into sums of two primes.
+
+use alloc::vec::Vec;
+
+use crate::numbers::primes::{is_prime, prime_bitset};
+use crate::numbers::segmented_sieve::prime_stream;
+
+/// Bound of the first primes tried by `goldbach_pair`, which are sieved into a bitset.
+pub const SMALL_PRIMES: u64 = 1 << 16;
+
+/// Finds every way to write an even number as a sum of two primes.
+///
+/// Note: This function sieves the numbers up to n once into a bitset and checks every prime
+/// p up to n / 2 for n - p being a prime too.
+///
+/// Returns:
+///
+/// The pairs (p, q) with p <= q and p + q = n, in increasing order of p.
+///
+/// Panics when the number is odd.
+pub fn goldbach_pairs(n: usize) -> Vec<(usize, usize)> {
+    assert!(n.is_multiple_of(2), "Goldbach pairs exist for even numbers only, got {}", n);
+
+    let primes = prime_bitset(n);
+    primes.iter_ones().take_while(|&p| p <= n / 2).filter(|&p| primes.test(n - p)).map(|p| (p, n - p)).collect()
+}
+
+/// Finds the decomposition of an even number into two primes with the smallest first prime.
+///
+/// Note: the first primes come from a bitset sieved up to `SMALL_PRIMES`, then from the
+/// segmented sieve, and the second ones are checked with `is_prime`. A pair turns up after
+/// a few hundred primes even for the largest 64-bit numbers, so this is much faster than
+/// listing all pairs.
+///
+/// Returns:
+///
+/// The pair (p, q) with p <= q and the smallest p, none when there is no pair.
+///
+/// Panics when the number is odd.
+pub fn goldbach_pair(n: u64) -> Option<(u64, u64)> {
+    assert!(n.is_multiple_of(2), "Goldbach pairs exist for even numbers only, got {}", n);
+
+    let half = n / 2;
+    let small = prime_bitset(half.min(SMALL_PRIMES) as usize);
+    let large = (half > SMALL_PRIMES).then(|| prime_stream().skip_while(|&p| p <= SMALL_PRIMES)).into_iter().flatten();
+
+    let first = small.iter_ones().map(|p| p as u64).chain(large).take_while(|&p| p <= half).find(|&p| is_prime(n - p));
+    first.map(|p| (p, n - p))
+}
+
+/// Tells whether an even number is a sum of two primes, which Goldbach conjectured for every even number above 2.
+///
+/// Panics when the number is odd.
+pub fn goldbach_exists(n: u64) -> bool {
+    goldbach_pair(n).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_of_small_numbers_should_be_known_values() {
+        assert_eq!(goldbach_pairs(2), vec![]);
+        assert_eq!(goldbach_pairs(4), vec![(2, 2)]);
+        assert_eq!(goldbach_pairs(28), vec![(5, 23), (11, 17)]);
+        assert_eq!(goldbach_pairs(100).len(), 6);
+    }
+
+    #[test]
+    fn smallest_pair_should_start_every_pair_list() {
+        for n in (2..500).step_by(2) {
+            assert_eq!(goldbach_pair(n as u64), goldbach_pairs(n).first().map(|&(p, q)| (p as u64, q as u64)));
+        }
+    }
+
+    #[test]
+    fn existence_should_match_pair_lists() {
+        for n in (2..4000).step_by(2) {
+            let exists = goldbach_exists(n as u64);
+
+            assert_eq!(exists, !goldbach_pairs(n).is_empty(), "{}", n);
+            assert_eq!(exists, n >= 4, "{}", n);
+        }
+    }
+
+    #[test]
+    fn large_numbers_should_have_a_pair() {
+        assert!(!goldbach_exists(2));
+        assert!(goldbach_exists(u64::MAX - 1));
+        assert_eq!(goldbach_pair(1 << 62).map(|(p, q)| p + q), Some(1 << 62));
+    }
+
+    #[test]
+    #[should_panic]
+    fn odd_number_should_panic() {
+        goldbach_pairs(7);
+    }
+}
@@ -0,0 +1,166 @@
+//! This module contains a dense matrix with row-major storage.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Index, IndexMut, Mul};
+
+use num::{One, Zero};
+
+/// Dense matrix stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Copy> Matrix<T> {
+    /// Creates a matrix from its entries listed row by row.
+    ///
+    /// Panics when the number of entries is not `rows * cols`.
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "Matrix needs exactly rows * cols entries");
+
+        Matrix { rows, cols, data }
+    }
+
+    /// Creates a matrix from a list of rows.
+    ///
+    /// Panics when the rows have different lengths.
+    pub fn from_rows(rows: &[Vec<T>]) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols), "All rows must have the same length");
+
+        Matrix { rows: rows.len(), cols, data: rows.concat() }
+    }
+
+    /// Creates a matrix with every entry equal to the value.
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
+        Matrix { rows, cols, data: vec![value; rows * cols] }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the entries of a row.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the matrix with rows and columns swapped.
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols).flat_map(|col| (0..self.rows).map(move |row| self[(row, col)])).collect();
+
+        Matrix { rows: self.cols, cols: self.rows, data }
+    }
+}
+
+impl<T: Copy + Zero> Matrix<T> {
+    /// Creates a matrix of zeros.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::filled(rows, cols, T::zero())
+    }
+}
+
+impl<T: Copy + Zero + One> Matrix<T> {
+    /// Creates the square identity matrix.
+    pub fn identity(size: usize) -> Self {
+        let mut identity = Self::zeros(size, size);
+        for i in 0..size {
+            identity[(i, i)] = T::one();
+        }
+
+        identity
+    }
+}
+
+impl<T: Copy + Zero + Add<Output = T> + Mul<Output = T>> Matrix<T> {
+    /// Multiplies two matrices in O(rows * inner * cols).
+    ///
+    /// Panics when the columns of the left matrix do not match the rows of the right one.
+    pub fn multiply(&self, other: &Self) -> Self {
+        assert_eq!(self.cols, other.rows, "Matrix dimensions do not match for multiplication");
+
+        let mut product = Self::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            for inner in 0..self.cols {
+                let left = self[(row, inner)];
+                for col in 0..other.cols {
+                    product[(row, col)] = product[(row, col)] + left * other[(inner, col)];
+                }
+            }
+        }
+
+        product
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols, "Matrix index out of range");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.rows && col < self.cols, "Matrix index out of range");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Copy + Zero + Add<Output = T> + Mul<Output = T>> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.multiply(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrices_should_be_multiplied() {
+        let a = Matrix::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from_rows(&[vec![7, 8], vec![9, 10], vec![11, 12]]);
+
+        let product = &a * &b;
+
+        assert_eq!(product, Matrix::from_rows(&[vec![58, 64], vec![139, 154]]));
+    }
+
+    #[test]
+    fn identity_should_be_neutral() {
+        let a = Matrix::new(2, 2, vec![2, -1, 0, 3]);
+
+        assert_eq!(&a * &Matrix::identity(2), a);
+        assert_eq!(&Matrix::identity(2) * &a, a);
+    }
+
+    #[test]
+    fn transpose_should_swap_rows_and_columns() {
+        let a = Matrix::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(a.transpose(), Matrix::from_rows(&[vec![1, 4], vec![2, 5], vec![3, 6]]));
+        assert_eq!(a.transpose().row(2), &[3, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_dimensions_should_panic() {
+        let a: Matrix<i32> = Matrix::zeros(2, 3);
+
+        let _ = &a * &a;
+    }
+}
@@ -1,2 +1,8 @@
 pub mod primes;
-pub mod operations;
\ No newline at end of file
+pub mod operations;
+pub mod matrix;
+pub mod segmented_sieve;
+pub mod primitive_root;
+pub mod prime_gaps;
+pub mod goldbach;
+pub mod digits;
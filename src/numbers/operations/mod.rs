@@ -1 +1,3 @@
-pub mod mod_exp;
\ No newline at end of file
+pub mod mod_exp;
+pub mod mod_inverse;
+pub mod montgomery;
@@ -8,6 +8,11 @@ use num::{Num};
 pub trait ModExp: Num + PartialOrd + Copy {
     /// Performs modular exponentiation.
     ///
+    /// Note:
+    ///     This function uses exponentiation by squaring, so it takes O(log exponent)
+    ///     multiplications. Products of two residues must fit into the type, so moduli
+    ///     should stay below the square root of its maximum, e.g. 2^32 for `u64`.
+    ///
     /// Arguments:
     ///
     /// * `base`: The base of the exponentiation.
@@ -22,15 +27,22 @@ pub trait ModExp: Num + PartialOrd + Copy {
             return Self::zero();
         }
 
+        let two = Self::one() + Self::one();
         let mut result = Self::one();
+        let mut base = base % modulus;
+        let mut exponent = exponent;
+
+        // Every bit of the exponent squares the base, and the set bits multiply it in.
+        while exponent > Self::zero() {
+            if exponent % two == Self::one() {
+                result = (result * base) % modulus;
+            }
 
-        let mut i = Self::zero();
-        while i < exponent {
-            result = (result * base) % modulus;
-            i = i + Self::one();
+            base = (base * base) % modulus;
+            exponent = exponent / two;
         }
 
-        return result
+        result
     }
 }
 
@@ -74,4 +86,13 @@ mod tests {
 
         assert_eq!(ans, 8);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn modular_exponentiation_with_large_exponent_should_follow_fermat() {
+        let prime: u128 = 1_000_000_007;
+
+        let ans = ModExp::mod_exp(123_456_789, prime - 1, prime);
+
+        assert_eq!(ans, 1);
+    }
+}
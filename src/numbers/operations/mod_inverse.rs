@@ -0,0 +1,55 @@
+//! This module contains the modular multiplicative inverse.
+
+use num::{Integer, Signed};
+
+/// Finds the number that gives one when multiplied by the value modulo the modulus.
+///
+/// Note: This function uses the extended Euclidean algorithm, which finds x and y with
+/// value * x + modulus * y = gcd(value, modulus).
+///
+/// Returns:
+///
+/// The inverse in 0..modulus, `None` when the value and the modulus are not coprime.
+///
+/// Panics when the modulus is not positive.
+pub fn mod_inverse<T: Integer + Signed + Copy>(value: T, modulus: T) -> Option<T> {
+    assert!(modulus.is_positive(), "Modulus must be positive");
+
+    let solution = value.mod_floor(&modulus).extended_gcd(&modulus);
+    if !solution.gcd.is_one() {
+        return None;
+    }
+
+    Some(solution.x.mod_floor(&modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_of_3_modulo_11_should_be_4() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+    }
+
+    #[test]
+    fn negative_value_should_be_reduced_first() {
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+    }
+
+    #[test]
+    fn values_sharing_a_factor_with_modulus_should_have_no_inverse() {
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 7), None);
+    }
+
+    #[test]
+    fn every_unit_should_have_an_inverse() {
+        let modulus: i128 = 1_000_000_007;
+
+        for value in 1..1000 {
+            let inverse = mod_inverse(value, modulus).unwrap();
+            assert_eq!(value * inverse % modulus, 1);
+        }
+    }
+}
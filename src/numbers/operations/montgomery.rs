@@ -0,0 +1,150 @@
+//! This module contains Montgomery multiplication, modular arithmetic without divisions.
+
+/// Precomputed values for arithmetic modulo an odd 64-bit number in Montgomery form.
+///
+/// Note:
+///     A residue a is kept as a * 2^64 mod n. The product of two such residues is then
+///     brought back into the form by Montgomery reduction, which needs only multiplications
+///     and shifts instead of the slow 128-bit division, with any modulus below 2^64.
+///     Values are converted once with `to_montgomery` and back with `from_montgomery`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontgomeryCtx {
+    modulus: u64,
+    // Inverse of the modulus modulo 2^64.
+    inverse: u64,
+    // 2^128 mod modulus, which converts values into the form.
+    r_squared: u64,
+}
+
+impl MontgomeryCtx {
+    /// Prepares the arithmetic modulo the given number.
+    ///
+    /// Panics when the modulus is even.
+    pub fn new(modulus: u64) -> Self {
+        assert!(!modulus.is_multiple_of(2), "Montgomery modulus must be odd");
+
+        // Every Newton step doubles the number of correct low bits, three of which the modulus
+        // gets right as its own inverse modulo 8.
+        let mut inverse = modulus;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+        }
+
+        let r = (1u128 << 64) % modulus as u128;
+        MontgomeryCtx { modulus, inverse, r_squared: (r * r % modulus as u128) as u64 }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    // Returns value / 2^64 mod modulus for values below modulus * 2^64.
+    fn reduce(&self, value: u128) -> u64 {
+        // Subtracting this multiple of the modulus clears the low half exactly.
+        let multiple = (value as u64).wrapping_mul(self.inverse) as u128 * self.modulus as u128;
+        let (high, multiple_high) = ((value >> 64) as u64, (multiple >> 64) as u64);
+
+        if high >= multiple_high {
+            high - multiple_high
+        } else {
+            high.wrapping_sub(multiple_high).wrapping_add(self.modulus)
+        }
+    }
+
+    /// Converts a value into Montgomery form.
+    pub fn to_montgomery(&self, value: u64) -> u64 {
+        self.reduce((value % self.modulus) as u128 * self.r_squared as u128)
+    }
+
+    /// Converts a residue in Montgomery form back into a value in 0..modulus.
+    pub fn from_montgomery(&self, residue: u64) -> u64 {
+        self.reduce(residue as u128)
+    }
+
+    /// Returns one in Montgomery form.
+    pub fn one(&self) -> u64 {
+        self.to_montgomery(1)
+    }
+
+    /// Multiplies two residues in Montgomery form.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    /// Raises a residue in Montgomery form to a power, by squaring.
+    pub fn pow(&self, base: u64, exponent: u64) -> u64 {
+        let mut result = self.one();
+        let mut base = base;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Computes base^exponent mod modulus for ordinary values.
+    pub fn mod_exp(&self, base: u64, exponent: u64) -> u64 {
+        self.from_montgomery(self.pow(self.to_montgomery(base), exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numbers::operations::mod_exp::ModExp;
+    use rand::Rng;
+
+    #[test]
+    fn products_should_match_wide_arithmetic() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..1000 {
+            let modulus = rng.gen::<u64>() | 1;
+            let ctx = MontgomeryCtx::new(modulus);
+            let (a, b) = (rng.gen::<u64>(), rng.gen::<u64>());
+
+            let product = ctx.from_montgomery(ctx.mul(ctx.to_montgomery(a), ctx.to_montgomery(b)));
+
+            assert_eq!(product as u128, a as u128 * b as u128 % modulus as u128);
+        }
+    }
+
+    #[test]
+    fn powers_should_match_mod_exp() {
+        let mut rng = rand::thread_rng();
+
+        for modulus in [1, 3, u64::MAX, (1 << 63) + 1, rng.gen::<u64>() | 1] {
+            let ctx = MontgomeryCtx::new(modulus);
+            for _ in 0..100 {
+                let (base, exponent) = (rng.gen::<u64>(), rng.gen::<u64>());
+
+                let expected = ModExp::mod_exp(base as u128, exponent as u128, modulus as u128) as u64;
+
+                assert_eq!(ctx.mod_exp(base, exponent), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn small_cases_should_be_exact() {
+        let ctx = MontgomeryCtx::new(13);
+
+        assert_eq!(ctx.mod_exp(2, 12), 1);
+        assert_eq!(ctx.mod_exp(5, 0), 1);
+        assert_eq!(ctx.from_montgomery(ctx.one()), 1);
+        assert_eq!(ctx.modulus(), 13);
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_modulus_should_panic() {
+        MontgomeryCtx::new(10);
+    }
+}
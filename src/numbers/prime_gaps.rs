@@ -0,0 +1,111 @@
+//! This module contains gaps between consecutive primes and pairs of primes a fixed distance apart.
+
+use alloc::collections::VecDeque;
+
+use crate::numbers::segmented_sieve::prime_stream;
+
+/// Returns the endless stream of gaps between consecutive primes.
+///
+/// Returns:
+///
+/// Pairs of every prime and the distance to the next prime: (2, 1), (3, 2), (5, 2) and so on.
+pub fn prime_gaps() -> impl Iterator<Item = (u64, u64)> {
+    let mut primes = prime_stream().peekable();
+    core::iter::from_fn(move || {
+        let prime = primes.next()?;
+        Some((prime, primes.peek()? - prime))
+    })
+}
+
+/// Returns the endless stream of pairs of primes p and p + distance, not necessarily consecutive.
+///
+/// Note: primes within the distance behind the latest one are kept in a queue, so every
+/// prime is checked against the one that may complete a pair with it.
+///
+/// Panics when the distance is zero.
+pub fn prime_pairs(distance: u64) -> impl Iterator<Item = (u64, u64)> {
+    assert!(distance > 0, "Distance between primes must be positive");
+
+    let mut recent = VecDeque::new();
+    prime_stream().filter_map(move |prime| {
+        while recent.front().is_some_and(|&front| front + distance < prime) {
+            recent.pop_front();
+        }
+
+        let pair = prime.checked_sub(distance).filter(|low| recent.front() == Some(low)).map(|low| (low, prime));
+        recent.push_back(prime);
+        pair
+    })
+}
+
+/// Returns the endless stream of twin primes, primes two apart: (3, 5), (5, 7), (11, 13) and so on.
+pub fn twin_primes() -> impl Iterator<Item = (u64, u64)> {
+    prime_pairs(2)
+}
+
+/// Returns the endless stream of cousin primes, primes four apart: (3, 7), (7, 11), (13, 17) and so on.
+pub fn cousin_primes() -> impl Iterator<Item = (u64, u64)> {
+    prime_pairs(4)
+}
+
+/// Returns the endless stream of sexy primes, primes six apart: (5, 11), (7, 13), (11, 17) and so on.
+pub fn sexy_primes() -> impl Iterator<Item = (u64, u64)> {
+    prime_pairs(6)
+}
+
+/// Finds the largest gap between consecutive primes below the given number.
+///
+/// Returns:
+///
+/// The first pair of consecutive primes below the number with the largest distance, none
+/// when there are fewer than two such primes.
+pub fn max_gap_below(n: u64) -> Option<(u64, u64)> {
+    prime_gaps()
+        .take_while(|&(prime, gap)| prime + gap < n)
+        .fold(None, |best: Option<(u64, u64)>, (prime, gap)| match best {
+            Some((low, high)) if high - low >= gap => best,
+            _ => Some((prime, prime + gap)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numbers::primes::is_prime;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn first_gaps_should_be_known_values() {
+        let gaps: Vec<u64> = prime_gaps().take(10).map(|(_, gap)| gap).collect();
+
+        assert_eq!(gaps, vec![1, 2, 2, 4, 2, 4, 2, 4, 6, 2]);
+    }
+
+    #[test]
+    fn prime_pairs_should_match_primality_checks() {
+        for distance in [2, 4, 6, 8] {
+            let expected: Vec<(u64, u64)> =
+                (2..10_000).filter(|&p| is_prime(p) && is_prime(p + distance)).map(|p| (p, p + distance)).collect();
+
+            let pairs: Vec<(u64, u64)> = prime_pairs(distance).take_while(|&(p, _)| p < 10_000).collect();
+
+            assert_eq!(pairs, expected);
+        }
+    }
+
+    #[test]
+    fn named_pairs_should_start_with_known_values() {
+        assert_eq!(twin_primes().take(3).collect::<Vec<_>>(), vec![(3, 5), (5, 7), (11, 13)]);
+        assert_eq!(cousin_primes().take(3).collect::<Vec<_>>(), vec![(3, 7), (7, 11), (13, 17)]);
+        assert_eq!(sexy_primes().take(3).collect::<Vec<_>>(), vec![(5, 11), (7, 13), (11, 17)]);
+        assert_eq!(twin_primes().nth(1000), Some((79_631, 79_633)));
+    }
+
+    #[test]
+    fn maximal_gaps_should_be_known_records() {
+        assert_eq!(max_gap_below(3), None);
+        assert_eq!(max_gap_below(4), Some((2, 3)));
+        assert_eq!(max_gap_below(100), Some((89, 97)));
+        assert_eq!(max_gap_below(1_000_000), Some((492_113, 492_227)));
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Author: Denis Tsvikevich
 
+use alloc::vec;
+use alloc::vec::Vec;
+
 use rand::Rng;
-use crate::numbers::operations::mod_exp::{ModExp};
+use crate::numbers::operations::montgomery::MontgomeryCtx;
+use crate::structures::BitSet;
 
 /// Generates a vector of prime numbers smaller than or equal to given number.
 ///
@@ -41,6 +45,43 @@ pub fn generate(upto: usize) -> Vec<usize> {
     prime_flags.into_iter().enumerate().filter_map(|(index, is_prime)| if is_prime { Some(index + 1) } else { None }).collect()
 }
 
+/// Marks which indices of the bitset are prime numbers.
+///
+/// Note: This function uses the sieve of Eratosthenes in place, without allocating,
+/// so callers choose where the bits live.
+///
+/// Arguments:
+///
+/// * `bits`: The bitset to fill, bit i becomes set when i is a prime.
+pub fn sieve_into(bits: &mut BitSet) {
+    bits.fill(true);
+    for index in 0..bits.len().min(2) {
+        bits.clear(index);
+    }
+
+    let mut prime = 2;
+    while prime * prime < bits.len() {
+        if bits.test(prime) {
+            bits.clear_step(prime * prime, prime);
+        }
+
+        prime += 1;
+    }
+}
+
+/// Marks the prime numbers up to given number in a bitset.
+///
+/// Note: This function uses the sieve of Eratosthenes from `sieve_into`.
+///
+/// Returns:
+///
+/// The bitset of length upto + 1 where bit i is set when i is a prime.
+pub fn prime_bitset(upto: usize) -> BitSet {
+    let mut bits = BitSet::new(upto + 1);
+    sieve_into(&mut bits);
+    bits
+}
+
 /// Determine if a number is a prime.
 ///
 /// Note: This function uses trial division.
@@ -49,14 +90,14 @@ pub fn is_prime_trial(n: u64) -> bool {
         return false;
     }
 
-    let square_root = (n as f64).sqrt().trunc() as u64;
-    return !(2..=square_root).any(|i| n % i == 0);
+    !(2..).take_while(|&i| i <= n / i).any(|i| n.is_multiple_of(i))
 }
 
 /// Determine if a number is a prime.
 ///
 /// Note:
-///     This function uses probabilistic method.
+///     This function uses probabilistic method with random numbers from `thread_rng`,
+///     so it needs the `std` feature.
 ///     To make it more precise, you can enlarge repeats count.
 ///
 /// Arguments:
@@ -67,7 +108,28 @@ pub fn is_prime_trial(n: u64) -> bool {
 /// Returns:
 ///
 /// When given number is prime - returns true, false otherwise.
+#[cfg(feature = "std")]
 pub fn fermat_primality_test(n: u64, repeats_count: u32) -> bool {
+    fermat_primality_test_with_rng(n, repeats_count, &mut rand::thread_rng())
+}
+
+/// Determine if a number is a prime.
+///
+/// Note:
+///     This function uses probabilistic method, drawing bases from the given generator,
+///     so a seeded generator makes the result reproducible.
+///     To make it more precise, you can enlarge repeats count.
+///
+/// Arguments:
+///
+/// * `n`: The number to test for primality.
+/// * `repeats_count`: The number of times to repeat the test.
+/// * `rng`: The source of random bases.
+///
+/// Returns:
+///
+/// When given number is prime - returns true, false otherwise.
+pub fn fermat_primality_test_with_rng(n: u64, repeats_count: u32, rng: &mut impl Rng) -> bool {
     if n < 2 {
         return false;
     }
@@ -76,24 +138,145 @@ pub fn fermat_primality_test(n: u64, repeats_count: u32) -> bool {
         return true;
     }
 
-    let mut rng = rand::thread_rng();
+    if n.is_multiple_of(2) {
+        return false;
+    }
 
+    let ctx = MontgomeryCtx::new(n);
     let mut i = 0;
     while i < repeats_count {
         let random_number = rng.gen_range(2..=(n - 2));
-        if ModExp::mod_exp(random_number, n-1, n) != 1 {
+        if ctx.mod_exp(random_number, n-1) != 1 {
             return false;
         }
 
         i += 1;
     }
 
-    return true;
+    true
+}
+
+// Tells whether the base proves the odd number n composite, where n - 1 = odd * 2^twos.
+// Residues stay in the Montgomery form of the context, which is modulo n.
+fn is_witness(ctx: &MontgomeryCtx, base: u64, odd: u64, twos: u32) -> bool {
+    let (one, minus_one) = (ctx.one(), ctx.to_montgomery(ctx.modulus() - 1));
+    let mut x = ctx.pow(ctx.to_montgomery(base), odd);
+    if x == one || x == minus_one {
+        return false;
+    }
+
+    for _ in 1..twos {
+        x = ctx.mul(x, x);
+        if x == minus_one {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Splits n - 1 into an odd part and a power of two.
+fn split_power_of_two(n: u64) -> (u64, u32) {
+    let twos = (n - 1).trailing_zeros();
+    ((n - 1) >> twos, twos)
+}
+
+/// Determine if a number is a prime.
+///
+/// Note:
+///     This function uses the Miller–Rabin test with random bases from `thread_rng`,
+///     so it needs the `std` feature. A composite number passes a single round with
+///     probability at most 1/4.
+///
+/// Arguments:
+///
+/// * `n`: The number to test for primality.
+/// * `repeats_count`: The number of random bases to try.
+///
+/// Returns:
+///
+/// When given number is prime - returns true, false otherwise.
+#[cfg(feature = "std")]
+pub fn miller_rabin_test(n: u64, repeats_count: u32) -> bool {
+    miller_rabin_test_with_rng(n, repeats_count, &mut rand::thread_rng())
+}
+
+/// Determine if a number is a prime.
+///
+/// Note:
+///     This function uses the Miller–Rabin test, drawing bases from the given generator.
+///     Unlike the Fermat test it is not fooled by Carmichael numbers: a composite number
+///     passes a single round with probability at most 1/4.
+///
+/// Arguments:
+///
+/// * `n`: The number to test for primality.
+/// * `repeats_count`: The number of random bases to try.
+/// * `rng`: The source of random bases.
+///
+/// Returns:
+///
+/// When given number is prime - returns true, false otherwise.
+pub fn miller_rabin_test_with_rng(n: u64, repeats_count: u32, rng: &mut impl Rng) -> bool {
+    if n < 4 {
+        return n >= 2;
+    }
+
+    if n.is_multiple_of(2) {
+        return false;
+    }
+
+    let (odd, twos) = split_power_of_two(n);
+    let ctx = MontgomeryCtx::new(n);
+    (0..repeats_count).all(|_| !is_witness(&ctx, rng.gen_range(2..=(n - 2)), odd, twos))
+}
+
+/// Determine if a number is a prime.
+///
+/// Note:
+///     This function uses the Miller–Rabin test with the first twelve primes as bases,
+///     which is known to be exact for every 64-bit number. Powers are computed with
+///     Montgomery multiplication, avoiding 128-bit divisions.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    if let Some(&prime) = BASES.iter().find(|&&prime| n.is_multiple_of(prime)) {
+        return n == prime;
+    }
+
+    let (odd, twos) = split_power_of_two(n);
+    let ctx = MontgomeryCtx::new(n);
+    BASES.iter().all(|&base| !is_witness(&ctx, base, odd, twos))
+}
+
+/// Generates a random prime number with exactly the given number of bits.
+///
+/// Note: odd candidates with the highest bit set are drawn until `is_prime` accepts one,
+/// which takes about ln(2^bits) / 2 tries on average by the prime number theorem.
+///
+/// Panics when the number of bits is not within 2..=64.
+pub fn random_prime(bits: u32, rng: &mut impl Rng) -> u64 {
+    assert!((2..=64).contains(&bits), "Number of bits must be within 2..=64");
+
+    let low = 1 << (bits - 1);
+    let high = u64::MAX >> (64 - bits);
+    loop {
+        let candidate = rng.gen_range(low..=high) | 1;
+        if is_prime(candidate) {
+            return candidate;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn primes_upto_thirty_should_be_generated_correctly() {
@@ -104,11 +287,30 @@ mod tests {
         assert_eq!(actual_generated_prime_numbers, expected_prime_numbers);
     }
 
+    #[test]
+    fn sieve_into_buffer_should_match_generated_primes() {
+        let mut bits = BitSet::new(100);
+
+        sieve_into(&mut bits);
+
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), generate(99));
+    }
+
+    #[test]
+    fn prime_bitset_should_match_generated_primes() {
+        for upto in [0, 1, 2, 63, 64, 1000] {
+            let bits = prime_bitset(upto);
+
+            let expected = if upto == 0 { vec![] } else { generate(upto) };
+            assert_eq!(bits.iter_ones().collect::<Vec<_>>(), expected);
+        }
+    }
+
     #[test]
     fn all_is_prime() {
         let prime_numbers = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
 
-        let is_every_number_in_vector_prime = prime_numbers.into_iter().all(|x| is_prime_trial(x));
+        let is_every_number_in_vector_prime = prime_numbers.into_iter().all(is_prime_trial);
 
         assert!(is_every_number_in_vector_prime);
     }
@@ -128,7 +330,7 @@ mod tests {
 
         let is_prime_number = is_prime_trial(not_prime_number);
 
-        assert_eq!(is_prime_number, false);
+        assert!(!is_prime_number);
     }
 
     #[test]
@@ -137,18 +339,20 @@ mod tests {
 
         let is_prime_number = is_prime_trial(not_prime_number);
 
-        assert_eq!(is_prime_number, false);
+        assert!(!is_prime_number);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn zero_is_not_prime_fermat() {
         let not_prime_number = 0;
 
         let is_prime_number = fermat_primality_test(not_prime_number, 10);
 
-        assert_eq!(is_prime_number, false, "Fermat primality test counted 0 as prime number");
+        assert!(!is_prime_number, "Fermat primality test counted 0 as prime number");
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn all_is_prime_fermat() {
         let prime_numbers = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
@@ -158,6 +362,7 @@ mod tests {
         assert!(is_every_number_in_vector_prime, "Fermat primality test counted some number in the given vector as a composite");
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn all_is_not_prime_fermat() {
         let not_prime_numbers = vec![1, 4, 6, 8, 9, 10, 12, 14, 15, 16, 18, 20, 21, 22];
@@ -166,4 +371,66 @@ mod tests {
 
         assert!(is_every_number_in_vector_not_prime, "Fermat primality test counted some number in the given vector as a prime");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn seeded_fermat_should_be_reproducible() {
+        let carmichael_number = 561;
+
+        let verdicts = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20).map(|_| fermat_primality_test_with_rng(carmichael_number, 1, &mut rng)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(verdicts(5), verdicts(5));
+        assert!(!fermat_primality_test_with_rng(561, 50, &mut StdRng::seed_from_u64(5)));
+    }
+
+    #[test]
+    fn miller_rabin_should_agree_with_trial_division() {
+        let mut rng = StdRng::seed_from_u64(17);
+
+        for n in 0..5000 {
+            assert_eq!(is_prime(n), is_prime_trial(n), "n = {}", n);
+            assert_eq!(miller_rabin_test_with_rng(n, 10, &mut rng), is_prime_trial(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn miller_rabin_should_reject_carmichael_and_strong_pseudoprimes() {
+        let mut rng = StdRng::seed_from_u64(17);
+
+        for n in [561, 41041, 3215031751, 3825123056546413051] {
+            assert!(!is_prime(n), "{} is composite", n);
+            assert!(!miller_rabin_test_with_rng(n, 20, &mut rng), "{} is composite", n);
+        }
+    }
+
+    #[test]
+    fn large_primes_should_be_recognized() {
+        for n in [(1 << 61) - 1, 18446744073709551557, 4294967291] {
+            assert!(is_prime(n), "{} is prime", n);
+        }
+        assert!(!is_prime(((1 << 31) - 1) * ((1 << 31) - 1)));
+    }
+
+    #[test]
+    fn fermat_test_should_handle_moduli_above_two_to_the_32() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        assert!(fermat_primality_test_with_rng(18446744073709551557, 10, &mut rng));
+        assert!(!fermat_primality_test_with_rng(18446744073709551559, 10, &mut rng));
+        assert!(!fermat_primality_test_with_rng(1 << 40, 10, &mut rng));
+    }
+
+    #[test]
+    fn random_primes_should_have_requested_size() {
+        let mut rng = StdRng::seed_from_u64(23);
+
+        for bits in [2, 5, 16, 32, 63, 64] {
+            let prime = random_prime(bits, &mut rng);
+
+            assert!(is_prime(prime));
+            assert_eq!(64 - prime.leading_zeros(), bits);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! This module contains the search for primitive roots modulo a prime.
+
+use alloc::vec::Vec;
+
+use crate::numbers::operations::mod_exp::ModExp;
+use crate::numbers::primes::is_prime;
+
+// Lists the distinct prime factors of n in increasing order.
+//
+// Trial division stops as soon as the rest is prime, so numbers with at most one large
+// prime factor, such as p - 1 for a safe prime p, are factored quickly.
+fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut rest_is_prime = is_prime(n);
+    let mut divisor = 2;
+    while !rest_is_prime && divisor * divisor <= n {
+        if n.is_multiple_of(divisor) {
+            factors.push(divisor);
+            while n.is_multiple_of(divisor) {
+                n /= divisor;
+            }
+            rest_is_prime = is_prime(n);
+        }
+        divisor += 1;
+    }
+
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Finds the smallest primitive root modulo a prime, a number whose powers give every nonzero residue.
+///
+/// Note:
+///     A candidate g is a primitive root exactly when g^((p - 1) / f) is not 1 for any
+///     prime factor f of p - 1, so only a few powers are checked per candidate. Factoring
+///     p - 1 by trial division takes O(sqrt p) in the worst case, but it is immediate when
+///     p - 1 has at most one large prime factor.
+///
+/// Returns:
+///
+/// The smallest primitive root, `None` when the modulus is not a prime.
+pub fn primitive_root(prime: u64) -> Option<u64> {
+    if !is_prime(prime) {
+        return None;
+    }
+
+    if prime == 2 {
+        return Some(1);
+    }
+
+    let factors = distinct_prime_factors(prime - 1);
+    (2..prime).find(|&candidate| factors.iter().all(|&factor| ModExp::mod_exp(candidate as u128, ((prime - 1) / factor) as u128, prime as u128) != 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the multiplicative order of g modulo p by repeated multiplication.
+    fn order(g: u64, p: u64) -> u64 {
+        let mut power = g % p;
+        let mut order = 1;
+        while power != 1 {
+            power = power * g % p;
+            order += 1;
+        }
+        order
+    }
+
+    #[test]
+    fn smallest_primitive_roots_should_match_known_values() {
+        let known = [(2, 1), (3, 2), (5, 2), (7, 3), (11, 2), (23, 5), (41, 6), (71, 7), (191, 19), (409, 21)];
+
+        for (prime, root) in known {
+            assert_eq!(primitive_root(prime), Some(root), "prime {}", prime);
+        }
+    }
+
+    #[test]
+    fn roots_should_have_full_order_and_be_smallest() {
+        for p in (3..2000).filter(|&n| is_prime(n)) {
+            let root = primitive_root(p).unwrap();
+
+            assert_eq!(order(root, p), p - 1);
+            assert!((2..root).all(|g| order(g, p) < p - 1));
+        }
+    }
+
+    #[test]
+    fn composite_moduli_should_have_no_root() {
+        assert_eq!(primitive_root(0), None);
+        assert_eq!(primitive_root(1), None);
+        assert_eq!(primitive_root(561), None);
+    }
+
+    #[test]
+    fn safe_prime_should_be_handled_quickly() {
+        // The largest safe prime below 2^63, with (p - 1) / 2 also prime.
+        let prime = 9223372036854771239;
+
+        assert!(is_prime((prime - 1) / 2));
+        assert_eq!(primitive_root(prime), Some(11));
+    }
+
+    #[test]
+    fn factors_should_be_distinct_primes() {
+        assert_eq!(distinct_prime_factors(360), vec![2, 3, 5]);
+        assert_eq!(distinct_prime_factors(97), vec![97]);
+        assert_eq!(distinct_prime_factors(1), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,286 @@
+//! This module contains a fast segmented sieve of Eratosthenes over packed odd numbers,
+//! with an optional multithreaded path.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::numbers::primes::prime_bitset;
+use crate::structures::BitSet;
+
+// Bits per segment, 32 KiB so that a segment stays in the first level cache.
+const SEGMENT_BITS: u64 = 4096 * 64;
+
+// Bit j of the sieve stands for the odd number 2j + 1.
+#[derive(Debug, Clone)]
+struct Sieve {
+    limit: u64,
+    // Odd primes up to the square root of the limit.
+    base_primes: Vec<u64>,
+}
+
+impl Sieve {
+    fn new(limit: u64) -> Self {
+        let base_primes = prime_bitset(limit.isqrt() as usize).iter_ones().skip(1).map(|i| i as u64).collect();
+
+        Sieve { limit, base_primes }
+    }
+
+    // Number of bits covering the odd numbers up to the limit.
+    fn bit_count(&self) -> u64 {
+        self.limit.div_ceil(2)
+    }
+
+    fn segment_count(&self) -> u64 {
+        self.bit_count().div_ceil(SEGMENT_BITS)
+    }
+
+    // Leaves set exactly the bits of odd primes in the given segment, the last one being shorter.
+    fn sieve_segment(&self, segment: u64, bits: &mut BitSet) {
+        let start = segment * SEGMENT_BITS;
+        let end = (start + SEGMENT_BITS).min(self.bit_count());
+        let used = (end - start) as usize;
+        if bits.len() == used {
+            bits.fill(true);
+        } else {
+            *bits = BitSet::full(used);
+        }
+
+        for &prime in &self.base_primes {
+            // Odd multiples of the prime are `prime` bits apart, starting from its square.
+            let square = prime * prime / 2;
+            if square >= end {
+                break;
+            }
+
+            let first = if square >= start { square } else { start + (prime - (start - square) % prime) % prime };
+            bits.clear_step((first - start) as usize, prime as usize);
+        }
+
+        // One is not a prime number.
+        if segment == 0 {
+            bits.clear(0);
+        }
+    }
+
+    fn count_segments(&self, segments: impl Iterator<Item = u64>) -> u64 {
+        let mut bits = BitSet::new(SEGMENT_BITS as usize);
+        segments
+            .map(|segment| {
+                self.sieve_segment(segment, &mut bits);
+                bits.count_ones() as u64
+            })
+            .sum()
+    }
+
+    fn collect_segments(&self, segments: impl Iterator<Item = u64>, primes: &mut Vec<u64>) {
+        let mut bits = BitSet::new(SEGMENT_BITS as usize);
+        for segment in segments {
+            self.sieve_segment(segment, &mut bits);
+
+            let first_number = 2 * segment * SEGMENT_BITS + 1;
+            primes.extend(bits.iter_ones().map(|bit| first_number + 2 * bit as u64));
+        }
+    }
+
+    // Accounts for the only even prime.
+    fn even_primes(&self) -> u64 {
+        u64::from(self.limit >= 2)
+    }
+}
+
+/// Counts the prime numbers smaller than or equal to given number.
+///
+/// Note:
+///     This function sieves only odd numbers, packed 64 to a word, in segments that fit into
+///     the processor cache, and counts the survivors a word at a time. Memory stays at one
+///     segment besides the primes up to the square root.
+pub fn count_primes(upto: u64) -> u64 {
+    let sieve = Sieve::new(upto);
+    sieve.even_primes() + sieve.count_segments(0..sieve.segment_count())
+}
+
+/// Generates a vector of prime numbers smaller than or equal to given number.
+///
+/// Note: This function uses the same segmented sieve as `count_primes`.
+pub fn segmented_primes(upto: u64) -> Vec<u64> {
+    let sieve = Sieve::new(upto);
+    let mut primes = Vec::new();
+    if sieve.even_primes() == 1 {
+        primes.push(2);
+    }
+
+    sieve.collect_segments(0..sieve.segment_count(), &mut primes);
+    primes
+}
+
+/// Endless iterator over the prime numbers in increasing order.
+///
+/// Note:
+///     Primes are sieved a segment at a time, with the same segmented sieve as `count_primes`.
+///     The primes used for sieving are recomputed for a four times larger bound whenever the
+///     segments outgrow them, which costs little next to the segments themselves.
+#[derive(Debug, Clone)]
+pub struct PrimeStream {
+    sieve: Sieve,
+    next_segment: u64,
+    // Primes of the last sieved segment, in reverse order.
+    pending: Vec<u64>,
+}
+
+impl PrimeStream {
+    /// Creates a stream starting from 2.
+    pub fn new() -> Self {
+        PrimeStream { sieve: Sieve::new(2 * SEGMENT_BITS), next_segment: 0, pending: vec![2] }
+    }
+}
+
+impl Default for PrimeStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for PrimeStream {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.pending.is_empty() {
+            // Limits stay multiples of the numbers a segment covers, so no segment is cut short.
+            if self.next_segment >= self.sieve.segment_count() {
+                self.sieve = Sieve::new(self.sieve.limit.saturating_mul(4));
+            }
+
+            self.sieve.collect_segments(self.next_segment..self.next_segment + 1, &mut self.pending);
+            self.pending.reverse();
+            self.next_segment += 1;
+        }
+
+        self.pending.pop()
+    }
+}
+
+/// Returns the endless stream of prime numbers, see `PrimeStream`.
+pub fn prime_stream() -> PrimeStream {
+    PrimeStream::new()
+}
+
+// Splits the segments into contiguous runs, one for each thread.
+#[cfg(feature = "std")]
+fn runs(segment_count: u64, threads: usize) -> impl Iterator<Item = core::ops::Range<u64>> {
+    let threads = threads.max(1) as u64;
+    let run = segment_count.div_ceil(threads).max(1);
+    (0..segment_count).step_by(run as usize).map(move |start| start..(start + run).min(segment_count))
+}
+
+/// Counts the prime numbers smaller than or equal to given number on several threads.
+///
+/// Note: every thread sieves its own run of segments with its own buffer. Needs the `std` feature.
+///
+/// Arguments:
+///
+/// * `upto`: The largest number to consider.
+/// * `threads`: The number of threads to use, at least one is always used.
+#[cfg(feature = "std")]
+pub fn parallel_count_primes(upto: u64, threads: usize) -> u64 {
+    let sieve = Sieve::new(upto);
+
+    let counted: u64 = std::thread::scope(|scope| {
+        let handles: Vec<_> = runs(sieve.segment_count(), threads).map(|run| scope.spawn(|| sieve.count_segments(run))).collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    });
+
+    sieve.even_primes() + counted
+}
+
+/// Generates a vector of prime numbers smaller than or equal to given number on several threads.
+///
+/// Note: the runs of segments are collected separately and joined in order. Needs the `std` feature.
+///
+/// Arguments:
+///
+/// * `upto`: The largest number to consider.
+/// * `threads`: The number of threads to use, at least one is always used.
+#[cfg(feature = "std")]
+pub fn parallel_primes(upto: u64, threads: usize) -> Vec<u64> {
+    let sieve = Sieve::new(upto);
+    let mut primes = Vec::new();
+    if sieve.even_primes() == 1 {
+        primes.push(2);
+    }
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = runs(sieve.segment_count(), threads)
+            .map(|run| {
+                scope.spawn(|| {
+                    let mut primes = Vec::new();
+                    sieve.collect_segments(run, &mut primes);
+                    primes
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            primes.extend(handle.join().unwrap());
+        }
+    });
+
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numbers::primes::generate;
+
+    fn expected(upto: u64) -> Vec<u64> {
+        if upto == 0 {
+            return Vec::new();
+        }
+        generate(upto as usize).into_iter().map(|prime| prime as u64).collect()
+    }
+
+    #[test]
+    fn small_bounds_should_match_simple_sieve() {
+        for upto in 0..200 {
+            assert_eq!(segmented_primes(upto), expected(upto), "upto {}", upto);
+            assert_eq!(count_primes(upto), expected(upto).len() as u64);
+        }
+    }
+
+    #[test]
+    fn segment_boundaries_should_be_handled() {
+        let boundary = 2 * SEGMENT_BITS;
+
+        for upto in [boundary - 1, boundary, boundary + 1, 2 * boundary + 3] {
+            assert_eq!(segmented_primes(upto), expected(upto), "upto {}", upto);
+        }
+    }
+
+    #[test]
+    fn prime_counts_should_be_known_values() {
+        assert_eq!(count_primes(1_000_000), 78_498);
+        assert_eq!(count_primes(10_000_000), 664_579);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parallel_sieve_should_match_sequential_sieve() {
+        let upto = 5 * SEGMENT_BITS + 12345;
+
+        for threads in [0, 1, 3, 8] {
+            assert_eq!(parallel_count_primes(upto, threads), count_primes(upto));
+            assert_eq!(parallel_primes(upto, threads), segmented_primes(upto));
+        }
+        assert_eq!(parallel_primes(10, 4), vec![2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn prime_stream_should_match_segmented_sieve_across_bounds() {
+        let upto = 9 * SEGMENT_BITS;
+
+        let streamed: Vec<u64> = prime_stream().take_while(|&prime| prime <= upto).collect();
+
+        assert_eq!(streamed, segmented_primes(upto));
+        assert_eq!(prime_stream().nth(1_000_000), Some(15_485_867));
+    }
+}
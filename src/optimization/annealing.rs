@@ -0,0 +1,220 @@
+//! This module contains simulated annealing, a randomized search for states of low energy.
+//! It needs the `std` feature for the exponential function.
+
+use rand::Rng;
+
+/// Best state found by simulated annealing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annealed<S> {
+    /// The state of the lowest energy seen.
+    pub state: S,
+    /// The energy of the state.
+    pub energy: f64,
+    /// The number of proposed moves that were taken.
+    pub accepted: usize,
+}
+
+/// Searches for a state of low energy with simulated annealing.
+///
+/// Note:
+///     Every step proposes a neighbor of the current state. A neighbor of lower energy is
+///     always taken, one of higher energy with probability exp(-increase / temperature), so
+///     the search can leave local minima while the temperature is high and settles as the
+///     schedule cools it down.
+///
+/// Arguments:
+///
+/// * `initial`: The state to start from.
+/// * `steps`: The number of moves to propose.
+/// * `neighbor`: Proposes a random state next to the given one.
+/// * `energy`: The value to minimize.
+/// * `temperature`: The cooling schedule, giving the temperature at every step.
+/// * `rng`: The source of proposals and acceptance decisions.
+///
+/// Returns:
+///
+/// The state of the lowest energy seen, which may be the initial one.
+pub fn simulated_annealing<S: Clone, R: Rng>(
+    initial: S,
+    steps: usize,
+    mut neighbor: impl FnMut(&S, &mut R) -> S,
+    mut energy: impl FnMut(&S) -> f64,
+    mut temperature: impl FnMut(usize) -> f64,
+    rng: &mut R,
+) -> Annealed<S> {
+    let mut current_energy = energy(&initial);
+    let mut best = Annealed { state: initial.clone(), energy: current_energy, accepted: 0 };
+    let mut current = initial;
+
+    for step in 0..steps {
+        let candidate = neighbor(&current, rng);
+        let candidate_energy = energy(&candidate);
+        let increase = candidate_energy - current_energy;
+
+        let heat = temperature(step);
+        if increase <= 0.0 || (heat > 0.0 && rng.gen::<f64>() < (-increase / heat).exp()) {
+            current = candidate;
+            current_energy = candidate_energy;
+            best.accepted += 1;
+
+            if current_energy < best.energy {
+                best.state = current.clone();
+                best.energy = current_energy;
+            }
+        }
+    }
+
+    best
+}
+
+/// Searches for a state of low energy with simulated annealing, scoring moves by their energy change.
+///
+/// Note:
+///     This is `simulated_annealing` for states too large to copy at every step: moves are
+///     scored by how much they change the energy, often in O(1), and accepted moves are
+///     applied to the state in place. The energy is kept up to date by adding the changes,
+///     and the best state is copied only when a move leaves it.
+///
+/// Arguments:
+///
+/// * `initial`: The state to start from.
+/// * `initial_energy`: The energy of the initial state.
+/// * `steps`: The number of moves to propose.
+/// * `propose`: Picks a random move from the given state.
+/// * `change`: The change of the energy the move would make.
+/// * `apply`: Makes the move on the state.
+/// * `temperature`: The cooling schedule, giving the temperature at every step.
+/// * `rng`: The source of proposals and acceptance decisions.
+///
+/// Returns:
+///
+/// The state of the lowest energy seen, which may be the initial one.
+#[allow(clippy::too_many_arguments)]
+pub fn simulated_annealing_moves<S: Clone, M, R: Rng>(
+    initial: S,
+    initial_energy: f64,
+    steps: usize,
+    mut propose: impl FnMut(&S, &mut R) -> M,
+    mut change: impl FnMut(&S, &M) -> f64,
+    mut apply: impl FnMut(&mut S, M),
+    mut temperature: impl FnMut(usize) -> f64,
+    rng: &mut R,
+) -> Annealed<S> {
+    let mut current = initial;
+    let mut current_energy = initial_energy;
+    // None while the current state is the best one.
+    let mut best: Option<S> = None;
+    let mut best_energy = initial_energy;
+    let mut accepted = 0;
+
+    for step in 0..steps {
+        let proposed = propose(&current, rng);
+        let increase = change(&current, &proposed);
+
+        let heat = temperature(step);
+        if increase <= 0.0 || (heat > 0.0 && rng.gen::<f64>() < (-increase / heat).exp()) {
+            if increase > 0.0 && best.is_none() {
+                best = Some(current.clone());
+            }
+
+            apply(&mut current, proposed);
+            current_energy += increase;
+            accepted += 1;
+
+            if current_energy < best_energy {
+                best = None;
+                best_energy = current_energy;
+            }
+        }
+    }
+
+    Annealed { state: best.unwrap_or(current), energy: best_energy, accepted }
+}
+
+/// Returns the schedule multiplying the temperature by the factor at every step.
+pub fn exponential_cooling(initial: f64, factor: f64) -> impl Fn(usize) -> f64 {
+    move |step| initial * factor.powi(step.min(i32::MAX as usize) as i32)
+}
+
+/// Returns the schedule lowering the temperature evenly to zero over the given number of steps.
+pub fn linear_cooling(initial: f64, steps: usize) -> impl Fn(usize) -> f64 {
+    move |step| initial * (1.0 - step as f64 / steps.max(1) as f64).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // A bumpy function with many local minima and the global one at 70.
+    fn bumpy(x: &i64) -> f64 {
+        let x = *x as f64;
+        (x - 70.0).powi(2) / 100.0 + 5.0 * (1.0 - (x - 70.0).cos())
+    }
+
+    #[test]
+    fn annealing_should_escape_local_minima() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let annealed = simulated_annealing(
+            -200i64,
+            20_000,
+            |&x, rng| x + if rng.gen_bool(0.5) { 1 } else { -1 },
+            bumpy,
+            exponential_cooling(50.0, 0.9995),
+            &mut rng,
+        );
+
+        assert_eq!(annealed.state, 70);
+        assert_eq!(annealed.energy, 0.0);
+    }
+
+    #[test]
+    fn cold_annealing_should_only_descend() {
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let annealed = simulated_annealing(-200i64, 5_000, |&x, rng| x + rng.gen_range(-1..=1), bumpy, |_| 0.0, &mut rng);
+
+        // The first local minimum on the way stops a search that never climbs.
+        assert!(annealed.state < 0);
+        assert!(annealed.energy < bumpy(&-200));
+    }
+
+    #[test]
+    fn moves_should_anneal_like_states() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let annealed = simulated_annealing_moves(
+            -200i64,
+            bumpy(&-200),
+            20_000,
+            |_, rng| if rng.gen_bool(0.5) { 1 } else { -1 },
+            |&x, &step| bumpy(&(x + step)) - bumpy(&x),
+            |x, step| *x += step,
+            exponential_cooling(50.0, 0.9995),
+            &mut rng,
+        );
+
+        assert_eq!(annealed.state, 70);
+        assert!(annealed.energy.abs() < 1e-9);
+    }
+
+    #[test]
+    fn best_state_should_be_kept_when_annealing_moves_climb() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        // Every move climbs and is taken at an infinite temperature.
+        let annealed = simulated_annealing_moves(0i64, 0.0, 10, |_, _| 1, |_, &step| step as f64, |x, step| *x += step, |_| f64::INFINITY, &mut rng);
+
+        assert_eq!((annealed.state, annealed.energy, annealed.accepted), (0, 0.0, 10));
+    }
+
+    #[test]
+    fn schedules_should_cool_down() {
+        let (exponential, linear) = (exponential_cooling(10.0, 0.5), linear_cooling(10.0, 4));
+
+        assert_eq!((exponential(0), exponential(3)), (10.0, 1.25));
+        assert_eq!((linear(0), linear(1), linear(4), linear(9)), (10.0, 7.5, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,150 @@
+//! This module contains steepest-ascent hill climbing.
+
+use num::Zero;
+
+/// Climbs to a local maximum by always moving to the best neighbor.
+///
+/// Note:
+///     Every step scores all neighbors of the current state and moves to the best one while
+///     it improves the score. The result is a local maximum unless the step limit is hit
+///     first. The search is deterministic, so restarts from different states are the usual
+///     way to look beyond the nearest hill.
+///
+/// Arguments:
+///
+/// * `initial`: The state to start from.
+/// * `max_steps`: The largest number of moves to make.
+/// * `neighbors`: Lists the states next to the given one.
+/// * `score`: The value to maximize.
+///
+/// Returns:
+///
+/// The last state reached and its score.
+pub fn hill_climb<S, V, I>(
+    initial: S,
+    max_steps: usize,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut score: impl FnMut(&S) -> V,
+) -> (S, V)
+where
+    V: PartialOrd,
+    I: IntoIterator<Item = S>,
+{
+    let mut current_score = score(&initial);
+    let mut current = initial;
+
+    for _ in 0..max_steps {
+        let mut best: Option<(S, V)> = None;
+        for candidate in neighbors(&current) {
+            let candidate_score = score(&candidate);
+            if best.as_ref().is_none_or(|(_, best_score)| candidate_score > *best_score) {
+                best = Some((candidate, candidate_score));
+            }
+        }
+
+        match best {
+            Some((state, state_score)) if state_score > current_score => {
+                current = state;
+                current_score = state_score;
+            }
+            _ => break,
+        }
+    }
+
+    (current, current_score)
+}
+
+/// Climbs to a local maximum by always making the best move, scoring moves by their gain.
+///
+/// Note:
+///     This is `hill_climb` for states too large to copy for every neighbor: moves are
+///     scored by how much they raise the score, often in O(1), and only the best one is
+///     applied to the state in place.
+///
+/// Arguments:
+///
+/// * `initial`: The state to start from.
+/// * `max_steps`: The largest number of moves to make.
+/// * `moves`: Lists the moves possible from the given state.
+/// * `gain`: The change of the score the move would make.
+/// * `apply`: Makes the move on the state.
+///
+/// Returns:
+///
+/// The last state reached and the number of moves made.
+pub fn hill_climb_moves<S, M, V, I>(
+    initial: S,
+    max_steps: usize,
+    mut moves: impl FnMut(&S) -> I,
+    mut gain: impl FnMut(&S, &M) -> V,
+    mut apply: impl FnMut(&mut S, M),
+) -> (S, usize)
+where
+    V: PartialOrd + Zero,
+    I: IntoIterator<Item = M>,
+{
+    let mut current = initial;
+
+    for step in 0..max_steps {
+        let mut best: Option<(M, V)> = None;
+        for candidate in moves(&current) {
+            let candidate_gain = gain(&current, &candidate);
+            if best.as_ref().is_none_or(|(_, best_gain)| candidate_gain > *best_gain) {
+                best = Some((candidate, candidate_gain));
+            }
+        }
+
+        match best {
+            Some((best_move, best_gain)) if best_gain > V::zero() => apply(&mut current, best_move),
+            _ => return (current, step),
+        }
+    }
+
+    (current, max_steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn climb_should_reach_the_top_of_a_hill() {
+        let (x, height) = hill_climb(0i32, 100, |&x| [x - 1, x + 1], |&x| -(x - 17) * (x - 17));
+
+        assert_eq!((x, height), (17, 0));
+    }
+
+    #[test]
+    fn climb_should_stop_at_a_local_maximum() {
+        let heights = [1, 3, 5, 4, 2, 8, 9, 7];
+
+        let (position, height) = hill_climb(0usize, 100, |&i| [i.wrapping_sub(1), i + 1].into_iter().filter(|&j| j < heights.len()), |&i| heights[i]);
+
+        assert_eq!((position, height), (2, 5));
+    }
+
+    #[test]
+    fn climb_should_respect_the_step_limit() {
+        let (x, _) = hill_climb(0i32, 5, |&x| [x + 1], |&x| x);
+
+        assert_eq!(x, 5);
+    }
+
+    #[test]
+    fn steepest_neighbor_should_be_chosen() {
+        // From 0 the moves +1 and +3 both improve, and the larger one wins.
+        let (x, _) = hill_climb(0i32, 1, |&x| [x + 1, x + 3, x - 2], |&x| x);
+
+        assert_eq!(x, 3);
+    }
+
+    #[test]
+    fn moves_should_climb_like_states() {
+        let height = |x: i32| -(x - 17) * (x - 17);
+
+        let (x, steps) = hill_climb_moves(0i32, 100, |_| [-1, 1, 2], |&x, &step| height(x + step) - height(x), |x, step| *x += step);
+
+        assert_eq!((x, steps), (17, 9));
+        assert_eq!(hill_climb_moves(0i32, 3, |_| [1], |_, &step| step, |x, step| *x += step), (3, 3));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod hill_climbing;
+#[cfg(feature = "std")]
+pub mod annealing;
+#[cfg(feature = "std")]
+pub mod tsp;
+
+pub use hill_climbing::{hill_climb, hill_climb_moves};
+#[cfg(feature = "std")]
+pub use annealing::{exponential_cooling, linear_cooling, simulated_annealing, simulated_annealing_moves, Annealed};
+#[cfg(feature = "std")]
+pub use tsp::{anneal_tour, tour_length, two_opt};
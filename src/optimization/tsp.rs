@@ -0,0 +1,168 @@
+//! This module contains heuristics for the traveling salesman problem over points of the plane,
+//! built on the optimization drivers. It needs the `std` feature for distances.
+
+use alloc::vec::Vec;
+
+use num::ToPrimitive;
+use rand::Rng;
+
+use crate::geometry::point::{Coordinate, Point, EPSILON};
+use crate::optimization::annealing::{exponential_cooling, simulated_annealing_moves};
+use crate::optimization::hill_climbing::hill_climb_moves;
+
+/// Returns the length of the closed tour visiting the points in the given order.
+pub fn tour_length<T: Coordinate + ToPrimitive>(points: &[Point<T>], tour: &[usize]) -> f64 {
+    (0..tour.len()).map(|i| points[tour[i]].distance(points[tour[(i + 1) % tour.len()]])).sum()
+}
+
+// Change of the tour length when the part between the two positions is reversed, which
+// replaces the edges (a, b) and (c, d) around it by (a, c) and (b, d).
+fn reversal_change<T: Coordinate + ToPrimitive>(points: &[Point<T>], tour: &[usize], i: usize, j: usize) -> f64 {
+    let distance = |from: usize, to: usize| points[tour[from]].distance(points[tour[to]]);
+    let next = (j + 1) % tour.len();
+
+    distance(i - 1, j) + distance(i, next) - distance(i - 1, i) - distance(j, next)
+}
+
+/// Improves a tour with 2-opt moves by steepest-ascent hill climbing until no move shortens it.
+///
+/// Note: every step scores all O(n^2) reversals of a part of the tour by the four edges they
+/// change, in O(1) each, and applies the best one. The result has no two crossing edges.
+///
+/// Arguments:
+///
+/// * `points`: The points to visit.
+/// * `tour`: The order to start from, a permutation of the point indices.
+///
+/// Returns:
+///
+/// The improved order of the points.
+pub fn two_opt<T: Coordinate + ToPrimitive>(points: &[Point<T>], tour: &[usize]) -> Vec<usize> {
+    let n = tour.len();
+    let reversals = |_: &Vec<usize>| (1..n).flat_map(move |i| (i + 1..n).map(move |j| (i, j)));
+
+    // Gains within rounding of the tour length would let equally long tours alternate forever.
+    let tolerance = EPSILON * tour_length(points, tour);
+    let gain = |tour: &Vec<usize>, &(i, j): &(usize, usize)| {
+        let gain = -reversal_change(points, tour, i, j);
+        if gain > tolerance { gain } else { 0.0 }
+    };
+
+    hill_climb_moves(tour.to_vec(), usize::MAX, reversals, gain, |tour, (i, j)| tour[i..=j].reverse()).0
+}
+
+/// Searches for a short tour through the points with simulated annealing over random 2-opt moves.
+///
+/// Note: the temperature starts at the average distance between consecutive points of the
+/// initial tour and cools exponentially to a thousandth of it over the given number of steps.
+/// Moves are scored by the four edges they change, in O(1).
+///
+/// Returns:
+///
+/// The shortest order of the points found, starting from point 0.
+pub fn anneal_tour<T: Coordinate + ToPrimitive, R: Rng>(points: &[Point<T>], steps: usize, rng: &mut R) -> Vec<usize> {
+    let n = points.len();
+    let initial: Vec<usize> = (0..n).collect();
+    if n < 4 {
+        return initial;
+    }
+
+    let length = tour_length(points, &initial);
+    let factor = 0.001f64.powf(1.0 / steps.max(1) as f64);
+    let propose = |_: &Vec<usize>, rng: &mut R| {
+        let i = rng.gen_range(1..n - 1);
+        (i, rng.gen_range(i + 1..n))
+    };
+
+    simulated_annealing_moves(
+        initial,
+        length,
+        steps,
+        propose,
+        |tour, &(i, j)| reversal_change(points, tour, i, j),
+        |tour, (i, j)| tour[i..=j].reverse(),
+        exponential_cooling(length / n as f64, factor),
+        rng,
+    )
+    .state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinatorics::permutations::permutations;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn shortest_tour_length(points: &[Point<f64>]) -> f64 {
+        let rest: Vec<usize> = (1..points.len()).collect();
+        permutations(&rest)
+            .map(|order| {
+                let tour: Vec<usize> = core::iter::once(0).chain(order).collect();
+                tour_length(points, &tour)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn random_points(rng: &mut impl Rng, n: usize) -> Vec<Point<f64>> {
+        (0..n).map(|_| Point::new(rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0))).collect()
+    }
+
+    #[test]
+    fn tour_length_should_close_the_loop() {
+        let square = [Point::new(0, 0), Point::new(3, 0), Point::new(3, 4), Point::new(0, 4)];
+
+        assert_eq!(tour_length(&square, &[0, 1, 2, 3]), 14.0);
+        assert_eq!(tour_length(&square, &[0, 2, 1, 3]), 18.0);
+    }
+
+    #[test]
+    fn two_opt_should_untangle_a_convex_polygon() {
+        let octagon: Vec<Point<f64>> = (0..8)
+            .map(|i| {
+                let angle = i as f64 * core::f64::consts::PI / 4.0;
+                Point::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        let tangled = [0, 5, 2, 7, 4, 1, 6, 3];
+
+        let mut tour = two_opt(&octagon, &tangled);
+
+        // The perimeter order, in either direction.
+        let start = tour.iter().position(|&i| i == 0).unwrap();
+        tour.rotate_left(start);
+        if tour[1] != 1 {
+            tour[1..].reverse();
+        }
+        assert_eq!(tour, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn annealing_should_find_optimal_tours_of_few_points() {
+        let mut rng = StdRng::seed_from_u64(5);
+
+        for _ in 0..5 {
+            let points = random_points(&mut rng, 8);
+
+            let tour = anneal_tour(&points, 20_000, &mut rng);
+
+            let mut sorted = tour.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..8).collect::<Vec<_>>());
+            assert!(tour_length(&points, &tour) <= shortest_tour_length(&points) + 1e-9);
+        }
+    }
+
+    #[test]
+    fn annealing_should_shorten_a_random_tour() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let points = random_points(&mut rng, 60);
+        let initial: Vec<usize> = (0..60).collect();
+
+        let annealed = anneal_tour(&points, 50_000, &mut rng);
+        let improved = two_opt(&points, &annealed);
+
+        assert!(tour_length(&points, &annealed) < tour_length(&points, &initial) / 2.0);
+        assert!(tour_length(&points, &improved) <= tour_length(&points, &annealed));
+    }
+}
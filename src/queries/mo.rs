@@ -0,0 +1,183 @@
+//! This module contains Mo's algorithm, which answers offline range queries by moving a window.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Answers range queries offline by visiting them in an order that keeps window moves short.
+///
+/// Note:
+///     The positions are cut into blocks of about √n. Queries are sorted by the block of their
+///     left end, then by their right end, which goes up and down in turn between blocks. Moving
+///     the window from one query to the next adds or removes a position at a time, O((n + q)√n)
+///     moves in total for n positions and q queries.
+#[derive(Debug, Clone)]
+pub struct MoSolver {
+    // Inclusive ranges in the order given.
+    queries: Vec<(usize, usize)>,
+    // Indices of the queries in the order they are answered.
+    order: Vec<usize>,
+}
+
+impl MoSolver {
+    /// Prepares the queries for solving.
+    ///
+    /// Arguments:
+    ///
+    /// * `queries`: The inclusive ranges [l, r] of positions.
+    ///
+    /// Panics when a range starts after its end.
+    pub fn new(queries: &[(usize, usize)]) -> Self {
+        for &(left, right) in queries {
+            assert!(left <= right, "Query range [{}, {}] is empty", left, right);
+        }
+
+        let positions = queries.iter().map(|&(_, right)| right + 1).max().unwrap_or(0);
+        let block = positions.isqrt().max(1);
+
+        let mut order: Vec<usize> = (0..queries.len()).collect();
+        order.sort_unstable_by_key(|&index| {
+            let (left, right) = queries[index];
+            let block_index = left / block;
+            (block_index, if block_index % 2 == 0 { right } else { usize::MAX - right })
+        });
+
+        MoSolver { queries: queries.to_vec(), order }
+    }
+
+    /// Answers every query, moving the window over the positions.
+    ///
+    /// Arguments:
+    ///
+    /// * `state`: The state describing the window, initially describing no positions.
+    /// * `add`: Updates the state when a position enters the window.
+    /// * `remove`: Updates the state when a position leaves the window.
+    /// * `answer`: Computes the answer for the current window.
+    ///
+    /// Returns:
+    ///
+    /// The answers in the order the queries were given.
+    pub fn solve<S, A>(
+        &self,
+        state: &mut S,
+        mut add: impl FnMut(&mut S, usize),
+        mut remove: impl FnMut(&mut S, usize),
+        mut answer: impl FnMut(&S) -> A,
+    ) -> Vec<A> {
+        let mut answers: Vec<Option<A>> = (0..self.queries.len()).map(|_| None).collect();
+
+        // The window covers positions start..end, growing before it shrinks so it is never negative.
+        let (mut start, mut end) = (0, 0);
+        for &index in &self.order {
+            let (left, right) = self.queries[index];
+            while start > left {
+                start -= 1;
+                add(state, start);
+            }
+            while end <= right {
+                add(state, end);
+                end += 1;
+            }
+            while start < left {
+                remove(state, start);
+                start += 1;
+            }
+            while end > right + 1 {
+                end -= 1;
+                remove(state, end);
+            }
+
+            answers[index] = Some(answer(state));
+        }
+
+        answers.into_iter().map(Option::unwrap).collect()
+    }
+}
+
+/// Counts the distinct values in every inclusive range of positions, with Mo's algorithm.
+///
+/// Panics when a range starts after its end or ends past the values.
+pub fn distinct_elements<T: Ord>(values: &[T], queries: &[(usize, usize)]) -> Vec<usize> {
+    // Values are replaced by their indices in sorted order of distinct values.
+    let mut sorted: Vec<&T> = values.iter().collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let ids: Vec<usize> = values.iter().map(|value| sorted.binary_search(&value).unwrap()).collect();
+
+    for &(_, right) in queries {
+        assert!(right < values.len(), "Query range ends at {} past {} values", right, values.len());
+    }
+
+    // Copies of every value in the window, and the number of values with copies.
+    let mut state = (vec![0usize; sorted.len()], 0usize);
+    MoSolver::new(queries).solve(
+        &mut state,
+        |(counts, distinct), position| {
+            counts[ids[position]] += 1;
+            if counts[ids[position]] == 1 {
+                *distinct += 1;
+            }
+        },
+        |(counts, distinct), position| {
+            counts[ids[position]] -= 1;
+            if counts[ids[position]] == 0 {
+                *distinct -= 1;
+            }
+        },
+        |&(_, distinct)| distinct,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn range_sums_should_be_answered_in_query_order() {
+        let values = [3, 1, 4, 1, 5, 9, 2, 6];
+        let queries = [(0, 7), (2, 4), (5, 5), (0, 0), (1, 6)];
+
+        let mut sum = 0;
+        let sums = MoSolver::new(&queries).solve(
+            &mut sum,
+            |sum, position| *sum += values[position],
+            |sum, position| *sum -= values[position],
+            |&sum| sum,
+        );
+
+        assert_eq!(sums, vec![31, 10, 9, 3, 22]);
+    }
+
+    #[test]
+    fn distinct_elements_should_match_naive_count() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..50 {
+            let values: Vec<u8> = (0..rng.gen_range(1..60)).map(|_| rng.gen_range(0..8)).collect();
+            let queries: Vec<(usize, usize)> = (0..rng.gen_range(0..40))
+                .map(|_| {
+                    let left = rng.gen_range(0..values.len());
+                    (left, rng.gen_range(left..values.len()))
+                })
+                .collect();
+
+            let expected: Vec<usize> = queries
+                .iter()
+                .map(|&(left, right)| {
+                    let mut window = values[left..=right].to_vec();
+                    window.sort_unstable();
+                    window.dedup();
+                    window.len()
+                })
+                .collect();
+
+            assert_eq!(distinct_elements(&values, &queries), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_range_should_panic() {
+        MoSolver::new(&[(3, 2)]);
+    }
+}
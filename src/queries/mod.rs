@@ -0,0 +1,3 @@
+pub mod mo;
+
+pub use mo::{distinct_elements, MoSolver};
@@ -0,0 +1,130 @@
+//! This module contains the alias method for sampling indices with given weights.
+
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+/// Table drawing index i with probability proportional to its weight in O(1).
+///
+/// Note:
+///     Vose's construction splits the total weight into n columns of equal height, each
+///     holding at most two indices: its own, kept with probability `probabilities[i]`, and
+///     an alias taking the rest. Building runs in O(n) and sampling needs one index and one
+///     coin toss.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliasTable {
+    probabilities: Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Builds the table for the given weights.
+    ///
+    /// Panics when there are no weights, some weight is negative or not finite, or all
+    /// weights are zero.
+    pub fn new(weights: &[f64]) -> Self {
+        assert!(!weights.is_empty(), "Alias table needs at least one weight");
+        assert!(weights.iter().all(|weight| weight.is_finite() && *weight >= 0.0), "Weights must be finite and non-negative");
+
+        let total: f64 = weights.iter().sum();
+        assert!(total > 0.0, "Weights must not all be zero");
+
+        let n = weights.len();
+        let mut probabilities: Vec<f64> = weights.iter().map(|weight| weight * n as f64 / total).collect();
+        let mut aliases: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| probabilities[i] < 1.0);
+
+        // Every short column is topped up from a tall one, which may then become short itself.
+        while let (Some(&short), Some(&tall)) = (small.last(), large.last()) {
+            small.pop();
+            aliases[short] = tall;
+            probabilities[tall] -= 1.0 - probabilities[short];
+            if probabilities[tall] < 1.0 {
+                large.pop();
+                small.push(tall);
+            }
+        }
+
+        // Whatever is left is full up to rounding.
+        for i in small.into_iter().chain(large) {
+            probabilities[i] = 1.0;
+        }
+
+        AliasTable { probabilities, aliases }
+    }
+
+    /// Returns the number of indices.
+    pub fn len(&self) -> usize {
+        self.probabilities.len()
+    }
+
+    /// Returns false, since a table always has at least one index.
+    pub fn is_empty(&self) -> bool {
+        self.probabilities.is_empty()
+    }
+
+    /// Draws an index with probability proportional to its weight.
+    pub fn sample(&self, rng: &mut impl Rng) -> usize {
+        let column = rng.gen_range(0..self.len());
+        if rng.gen::<f64>() < self.probabilities[column] {
+            column
+        } else {
+            self.aliases[column]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn frequencies(weights: &[f64], draws: usize) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(11);
+        let table = AliasTable::new(weights);
+        let mut counts = vec![0; weights.len()];
+
+        for _ in 0..draws {
+            counts[table.sample(&mut rng)] += 1;
+        }
+
+        counts.into_iter().map(|count| count as f64 / draws as f64).collect()
+    }
+
+    #[test]
+    fn frequencies_should_follow_weights() {
+        let weights = [1.0, 2.0, 3.0, 4.0];
+
+        let frequencies = frequencies(&weights, 100000);
+
+        for (frequency, weight) in frequencies.iter().zip(weights) {
+            assert!((frequency - weight / 10.0).abs() < 0.01, "{:?}", frequencies);
+        }
+    }
+
+    #[test]
+    fn zero_weights_should_never_be_drawn() {
+        let frequencies = frequencies(&[0.0, 5.0, 0.0, 1.0, 0.0], 10000);
+
+        assert_eq!((frequencies[0], frequencies[2], frequencies[4]), (0.0, 0.0, 0.0));
+        assert!((frequencies[1] - 5.0 / 6.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn single_weight_should_always_be_drawn() {
+        assert_eq!(frequencies(&[0.5], 100), vec![1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_total_weight_should_panic() {
+        AliasTable::new(&[0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_weight_should_panic() {
+        AliasTable::new(&[1.0, -1.0]);
+    }
+}
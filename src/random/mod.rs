@@ -0,0 +1,7 @@
+pub mod shuffle;
+pub mod reservoir;
+pub mod alias;
+
+pub use shuffle::shuffle;
+pub use reservoir::sample_k;
+pub use alias::AliasTable;
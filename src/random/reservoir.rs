@@ -0,0 +1,84 @@
+//! This module contains reservoir sampling of a uniform sample from a stream of unknown length.
+
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+/// Picks k items uniformly at random from the iterator in a single pass.
+///
+/// Note: the first k items fill the reservoir, then the i-th item (counting from 1) replaces
+/// a random slot with probability k / i, so every k-subset is equally likely to remain.
+/// Runs in O(n) time and O(k) memory, without knowing n in advance.
+///
+/// Returns:
+///
+/// The sample in no particular order, every item when there are at most k of them. Nothing is
+/// read from the iterator when k is zero, so it may be endless.
+pub fn sample_k<I: IntoIterator>(items: I, k: usize, rng: &mut impl Rng) -> Vec<I::Item> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut items = items.into_iter();
+    let mut reservoir: Vec<I::Item> = items.by_ref().take(k).collect();
+    if reservoir.len() < k {
+        return reservoir;
+    }
+
+    for (seen, item) in (k + 1..).zip(items) {
+        let slot = rng.gen_range(0..seen);
+        if slot < k {
+            reservoir[slot] = item;
+        }
+    }
+
+    reservoir
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn short_streams_should_be_taken_whole() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert_eq!(sample_k(1..4, 5, &mut rng), vec![1, 2, 3]);
+        assert_eq!(sample_k(1..4, 0, &mut rng), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn empty_sample_of_endless_stream_should_return() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert_eq!(sample_k(core::iter::repeat(7), 0, &mut rng), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn sample_should_hold_distinct_items_of_the_stream() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut sample = sample_k("reservoir".chars().enumerate(), 4, &mut rng);
+
+        sample.sort_unstable();
+        sample.dedup();
+        assert_eq!(sample.len(), 4);
+        assert!(sample.iter().all(|&(index, letter)| "reservoir".chars().nth(index) == Some(letter)));
+    }
+
+    #[test]
+    fn every_item_should_be_about_equally_likely() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut counts = [0; 10];
+
+        for _ in 0..20000 {
+            for item in sample_k(0..10, 3, &mut rng) {
+                counts[item] += 1;
+            }
+        }
+
+        assert!(counts.iter().all(|&count| (5700..6300).contains(&count)), "{:?}", counts);
+    }
+}
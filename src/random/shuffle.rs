@@ -0,0 +1,66 @@
+//! This module contains the Fisher–Yates shuffle.
+
+use rand::Rng;
+
+/// Shuffles the items in place, every order being equally likely.
+///
+/// Note: going from the back, every item is swapped with a uniformly chosen item at or
+/// before it, which draws each of the n! orders with the same probability. Runs in O(n).
+pub fn shuffle<T>(items: &mut [T], rng: &mut impl Rng) {
+    for i in (1..items.len()).rev() {
+        let j = rng.gen_range(0..=i);
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn shuffled_items_should_be_a_permutation() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut items: Vec<u32> = (0..100).collect();
+
+        shuffle(&mut items, &mut rng);
+
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn short_slices_should_be_left_alone() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (mut empty, mut single): ([u8; 0], [u8; 1]) = ([], [5]);
+
+        shuffle(&mut empty, &mut rng);
+        shuffle(&mut single, &mut rng);
+
+        assert_eq!(single, [5]);
+    }
+
+    #[test]
+    fn every_order_should_be_about_equally_likely() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0; 6];
+
+        for _ in 0..60000 {
+            let mut items = [0, 1, 2];
+            shuffle(&mut items, &mut rng);
+            let order = match items {
+                [0, 1, 2] => 0,
+                [0, 2, 1] => 1,
+                [1, 0, 2] => 2,
+                [1, 2, 0] => 3,
+                [2, 0, 1] => 4,
+                _ => 5,
+            };
+            counts[order] += 1;
+        }
+
+        assert!(counts.iter().all(|&count| (9500..10500).contains(&count)), "{:?}", counts);
+    }
+}
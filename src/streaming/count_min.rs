@@ -0,0 +1,179 @@
+//! This module contains the count-min sketch, which estimates how often items occur in a stream.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use crate::hashing::XxHash64;
+
+/// Frequency estimates of the items of a stream in fixed memory.
+///
+/// Note:
+///     Every row of counters has its own hash function picking one counter per item, and an
+///     update adds to the picked counter of every row. Other items sharing counters only add
+///     to them, so the smallest picked counter never underestimates the true count. With width
+///     w and depth d it overestimates by more than 2n / w after n updates with probability at
+///     most 2^-d. Updates and queries take O(d).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountMinSketch {
+    width: usize,
+    depth: usize,
+    // Row after row of counters.
+    counters: Vec<u64>,
+    total: u64,
+}
+
+impl CountMinSketch {
+    /// Creates a sketch with the given number of counters in each of the given number of rows.
+    ///
+    /// Panics when the width or the depth is zero.
+    pub fn new(width: usize, depth: usize) -> Self {
+        assert!(width > 0 && depth > 0, "Sketch must have at least one counter");
+        CountMinSketch { width, depth, counters: vec![0; width * depth], total: 0 }
+    }
+
+    /// Creates a sketch whose estimates exceed the true counts by more than `epsilon` times
+    /// the total count with probability at most `delta`.
+    ///
+    /// Note: the width is e / epsilon and the depth is ln(1 / delta), rounded up. Needs the `std` feature.
+    ///
+    /// Panics when `epsilon` or `delta` is not within (0, 1).
+    #[cfg(feature = "std")]
+    pub fn with_error(epsilon: f64, delta: f64) -> Self {
+        assert!(epsilon > 0.0 && epsilon < 1.0, "Error must be within (0, 1)");
+        assert!(delta > 0.0 && delta < 1.0, "Probability must be within (0, 1)");
+
+        let width = (std::f64::consts::E / epsilon).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        Self::new(width, depth)
+    }
+
+    /// Returns the number of counters in every row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the sum of all counts added.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    // Yields the index of the counter picked by the item in every row.
+    fn positions<T: Hash + ?Sized>(width: usize, depth: usize, item: &T) -> impl Iterator<Item = usize> {
+        let mut hasher = XxHash64::new(0);
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        // Row hashes are combined from two halves of one hash, as Kirsch and Mitzenmacher proposed.
+        let (low, high) = (hash & 0xffff_ffff, (hash >> 32) | 1);
+        (0..depth).map(move |row| row * width + (low.wrapping_add(row as u64 * high) % width as u64) as usize)
+    }
+
+    /// Adds the count to the item.
+    pub fn update<T: Hash + ?Sized>(&mut self, item: &T, count: u64) {
+        for position in Self::positions(self.width, self.depth, item) {
+            self.counters[position] = self.counters[position].saturating_add(count);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+
+    /// Returns an estimate of the total count of the item, never below the true one.
+    pub fn query<T: Hash + ?Sized>(&self, item: &T) -> u64 {
+        Self::positions(self.width, self.depth, item).map(|position| self.counters[position]).min().unwrap()
+    }
+
+    /// Adds the counts of another sketch of the same shape, as if its updates were made to this one.
+    ///
+    /// Panics when the sketches have different widths or depths.
+    pub fn merge(&mut self, other: &CountMinSketch) {
+        assert!(self.width == other.width && self.depth == other.depth, "Sketches must have the same shape");
+
+        for (counter, &other) in self.counters.iter_mut().zip(&other.counters) {
+            *counter = counter.saturating_add(other);
+        }
+        self.total = self.total.saturating_add(other.total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashMap;
+
+    #[test]
+    fn estimates_should_never_be_below_true_counts() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut sketch = CountMinSketch::new(64, 4);
+        let mut counts = HashMap::new();
+
+        for _ in 0..10_000 {
+            let item: u32 = rng.gen_range(0..1000);
+            sketch.update(&item, 1);
+            *counts.entry(item).or_insert(0u64) += 1;
+        }
+
+        assert_eq!(sketch.total(), 10_000);
+        assert!(counts.iter().all(|(item, &count)| sketch.query(item) >= count));
+    }
+
+    #[test]
+    fn most_estimates_should_be_within_the_error_bound() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut sketch = CountMinSketch::new(272, 5);
+        let mut counts = HashMap::new();
+
+        // A few heavy items among many light ones.
+        for _ in 0..50_000 {
+            let item: u64 = if rng.gen_bool(0.2) { rng.gen_range(0..5) } else { rng.gen_range(0..100_000) };
+            sketch.update(&item, 1);
+            *counts.entry(item).or_insert(0u64) += 1;
+        }
+
+        let bound = 2 * sketch.total() / sketch.width() as u64;
+        let exceeding = counts.iter().filter(|(item, &count)| sketch.query(*item) > count + bound).count();
+        assert!(exceeding * 100 <= counts.len(), "{} of {} estimates are off", exceeding, counts.len());
+        for heavy in 0..5u64 {
+            assert!(sketch.query(&heavy) <= counts[&heavy] + bound);
+        }
+    }
+
+    #[test]
+    fn unseen_items_of_an_empty_sketch_should_count_zero() {
+        let sketch = CountMinSketch::new(10, 3);
+
+        assert_eq!(sketch.query("missing"), 0);
+    }
+
+    #[test]
+    fn merged_sketch_should_match_one_sketch() {
+        let (mut left, mut right, mut whole) = (CountMinSketch::new(50, 3), CountMinSketch::new(50, 3), CountMinSketch::new(50, 3));
+
+        for word in ["a", "b", "a", "c"] {
+            left.update(word, 2);
+            whole.update(word, 2);
+        }
+        for word in ["b", "d"] {
+            right.update(word, 1);
+            whole.update(word, 1);
+        }
+        left.merge(&right);
+
+        assert_eq!(left, whole);
+        assert!(left.query("a") >= 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_parameters_should_give_the_shape() {
+        let sketch = CountMinSketch::with_error(0.01, 0.001);
+
+        assert_eq!((sketch.width(), sketch.depth()), (272, 7));
+    }
+}
@@ -0,0 +1,96 @@
+//! This module contains the Boyer–Moore majority vote algorithm.
+
+/// Candidate for the item occurring in more than half of a stream, found in one pass.
+///
+/// Note:
+///     The candidate gains a vote for every copy of itself and loses one for every other item,
+///     and is replaced when it runs out of votes. Pairs of different items cancel out, so a
+///     true majority survives, but the candidate must be checked by a second pass when there
+///     may be no majority. Uses O(1) memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MajorityVote<T> {
+    candidate: Option<T>,
+    votes: u64,
+}
+
+impl<T: PartialEq> MajorityVote<T> {
+    /// Creates the vote of an empty stream.
+    pub fn new() -> Self {
+        MajorityVote { candidate: None, votes: 0 }
+    }
+
+    /// Counts an item of the stream.
+    pub fn update(&mut self, item: T) {
+        if self.votes == 0 {
+            self.candidate = Some(item);
+            self.votes = 1;
+        } else if self.candidate.as_ref() == Some(&item) {
+            self.votes += 1;
+        } else {
+            self.votes -= 1;
+        }
+    }
+
+    /// Returns the only item that can be a majority of the stream seen so far, none when
+    /// there is certainly no majority.
+    pub fn query(&self) -> Option<&T> {
+        self.candidate.as_ref().filter(|_| self.votes > 0)
+    }
+}
+
+impl<T: PartialEq> Default for MajorityVote<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Finds the item occurring in more than half of the items, with the majority vote and a check.
+pub fn majority<T: PartialEq>(items: &[T]) -> Option<&T> {
+    let mut vote = MajorityVote::new();
+    for item in items {
+        vote.update(item);
+    }
+
+    let candidate = *vote.query()?;
+    (items.iter().filter(|&item| item == candidate).count() * 2 > items.len()).then_some(candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand::Rng;
+
+    #[test]
+    fn majority_should_survive_the_vote() {
+        let mut vote = MajorityVote::new();
+
+        for item in [1, 2, 1, 3, 1, 4, 1] {
+            vote.update(item);
+        }
+
+        assert_eq!(vote.query(), Some(&1));
+        assert_eq!(majority(&[1, 2, 1, 3, 1, 4, 1]), Some(&1));
+    }
+
+    #[test]
+    fn missing_majority_should_be_detected() {
+        assert_eq!(majority::<u8>(&[]), None);
+        assert_eq!(majority(&[1, 2]), None);
+        assert_eq!(majority(&[1, 2, 3, 1]), None);
+        assert_eq!(majority(&["a", "a", "b"]), Some(&"a"));
+    }
+
+    #[test]
+    fn majority_should_match_naive_counting() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let items: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(0..3)).collect();
+
+            let expected = items.iter().find(|&item| items.iter().filter(|&other| other == item).count() * 2 > items.len());
+
+            assert_eq!(majority(&items), expected);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod welford;
+pub mod count_min;
+pub mod majority;
+
+pub use welford::RunningStats;
+pub use count_min::CountMinSketch;
+pub use majority::{majority, MajorityVote};
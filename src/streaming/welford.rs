@@ -0,0 +1,159 @@
+//! This module contains Welford's online algorithm for the mean and variance of a stream.
+
+/// Running count, mean and variance of a stream of numbers, updated one value at a time.
+///
+/// Note:
+///     Welford's update moves the mean towards every new value and adds the product of its
+///     distances from the old and the new mean to the sum of squared deviations. Unlike
+///     summing squares, this stays accurate when the values are large and close together.
+///     Every update takes O(1) time and the state takes O(1) memory.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    // Sum of squared deviations from the mean.
+    squares: f64,
+}
+
+impl RunningStats {
+    /// Creates the statistics of an empty stream.
+    pub fn new() -> Self {
+        RunningStats { count: 0, mean: 0.0, squares: 0.0 }
+    }
+
+    /// Adds a value of the stream.
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.squares += delta * (value - self.mean);
+    }
+
+    /// Combines the statistics with the ones of another stream, as if both streams were one.
+    ///
+    /// Note: Chan's formula corrects the sum of squares by the distance between the two means.
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.squares += other.squares + delta * delta * self.count as f64 * weight;
+        self.count = count;
+    }
+
+    /// Returns the number of values seen.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the values, none for an empty stream.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance, the mean squared deviation, none for an empty stream.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.squares / self.count as f64)
+    }
+
+    /// Returns the unbiased sample variance, none for fewer than two values.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.squares / (self.count - 1) as f64)
+    }
+
+    /// Returns the population standard deviation, none for an empty stream.
+    ///
+    /// Note: Needs the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand::Rng;
+
+    fn two_pass(values: &[f64]) -> (f64, f64) {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        (mean, variance)
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+    }
+
+    #[test]
+    fn empty_stream_should_have_no_statistics() {
+        let stats = RunningStats::new();
+
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.sample_variance(), None);
+    }
+
+    #[test]
+    fn statistics_should_match_two_pass_computation() {
+        let mut rng = rand::thread_rng();
+        let values: Vec<f64> = (0..1000).map(|_| rng.gen_range(-100.0..100.0)).collect();
+        let mut stats = RunningStats::new();
+
+        for &value in &values {
+            stats.update(value);
+        }
+
+        let (mean, variance) = two_pass(&values);
+        assert!(close(stats.mean().unwrap(), mean));
+        assert!(close(stats.variance().unwrap(), variance));
+        assert!(close(stats.sample_variance().unwrap(), variance * 1000.0 / 999.0));
+    }
+
+    #[test]
+    fn large_offset_should_not_lose_precision() {
+        let mut stats = RunningStats::new();
+
+        for value in [4.0, 7.0, 13.0, 16.0] {
+            stats.update(1e9 + value);
+        }
+
+        assert_eq!(stats.mean(), Some(1e9 + 10.0));
+        assert_eq!(stats.sample_variance(), Some(30.0));
+    }
+
+    #[test]
+    fn merged_streams_should_match_one_stream() {
+        let mut rng = rand::thread_rng();
+        let values: Vec<f64> = (0..500).map(|_| rng.gen_range(0.0..10.0)).collect();
+        let (mut left, mut right, mut whole) = (RunningStats::new(), RunningStats::new(), RunningStats::new());
+
+        for (index, &value) in values.iter().enumerate() {
+            if index < 123 { left.update(value) } else { right.update(value) }
+            whole.update(value);
+        }
+        left.merge(&right);
+
+        assert_eq!(left.count(), whole.count());
+        assert!(close(left.mean().unwrap(), whole.mean().unwrap()));
+        assert!(close(left.variance().unwrap(), whole.variance().unwrap()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn standard_deviation_should_be_root_of_variance() {
+        let mut stats = RunningStats::new();
+
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.update(value);
+        }
+
+        assert_eq!(stats.std_dev(), Some(2.0));
+    }
+}
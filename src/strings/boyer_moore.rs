@@ -0,0 +1,243 @@
+//! This module contains the Boyer–Moore substring search and its Horspool simplification.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+const ALPHABET_SIZE: usize = 256;
+
+/// Finds all occurrences of the pattern in the text.
+///
+/// Note:
+///     This function uses the Boyer–Moore algorithm with both the bad-character and the
+///     good-suffix rules. On natural-language text it usually inspects only a fraction
+///     of the symbols, its worst case is O(n * m) when many occurrences overlap.
+///
+/// Returns:
+///
+/// Start positions of every (possibly overlapping) occurrence, in increasing order.
+pub fn boyer_moore(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let m = pattern.len();
+
+    if m == 0 {
+        return (0..=n).collect();
+    }
+
+    let mut positions = Vec::new();
+    if m > n {
+        return positions;
+    }
+
+    let last_occurrence = last_occurrences(pattern);
+    let good_suffix = good_suffix_shifts(pattern);
+
+    let mut shift = 0;
+    while shift <= n - m {
+        // Compare the pattern right to left, j is the length of the unmatched prefix.
+        let mut j = m;
+        while j > 0 && pattern[j - 1] == text[shift + j - 1] {
+            j -= 1;
+        }
+
+        if j == 0 {
+            positions.push(shift);
+            shift += good_suffix[0];
+        } else {
+            let bad_character = (j - 1) as isize - last_occurrence[text[shift + j - 1] as usize];
+            shift += good_suffix[j].max(bad_character.max(1) as usize);
+        }
+    }
+
+    positions
+}
+
+/// Finds all occurrences of the pattern in the text.
+///
+/// Note:
+///     This function uses the Boyer–Moore–Horspool algorithm which keeps only the
+///     bad-character rule, keyed by the text symbol aligned with the last pattern symbol.
+///
+/// Returns:
+///
+/// Start positions of every (possibly overlapping) occurrence, in increasing order.
+pub fn horspool(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let m = pattern.len();
+
+    if m == 0 {
+        return (0..=n).collect();
+    }
+
+    let mut positions = Vec::new();
+    if m > n {
+        return positions;
+    }
+
+    let mut shifts = [m; ALPHABET_SIZE];
+    for (i, &symbol) in pattern[..m - 1].iter().enumerate() {
+        shifts[symbol as usize] = m - 1 - i;
+    }
+
+    let mut shift = 0;
+    while shift <= n - m {
+        if text[shift..shift + m] == *pattern {
+            positions.push(shift);
+        }
+
+        shift += shifts[text[shift + m - 1] as usize];
+    }
+
+    positions
+}
+
+/// Index of the last occurrence of every symbol in the pattern, -1 for absent symbols.
+fn last_occurrences(pattern: &[u8]) -> [isize; ALPHABET_SIZE] {
+    let mut last = [-1; ALPHABET_SIZE];
+    for (i, &symbol) in pattern.iter().enumerate() {
+        last[symbol as usize] = i as isize;
+    }
+
+    last
+}
+
+/// Shifts of the strong good-suffix rule.
+///
+/// Note: the j-th value is the shift to apply when the mismatch happened at `pattern[j - 1]`,
+/// the zeroth value is the shift after a full match.
+fn good_suffix_shifts(pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    let mut shifts = vec![0; m + 1];
+
+    // borders[i] is the start of the widest border of pattern[i..].
+    let mut borders = vec![0; m + 1];
+
+    let mut i = m;
+    let mut j = m + 1;
+    borders[i] = j;
+    while i > 0 {
+        while j <= m && pattern[i - 1] != pattern[j - 1] {
+            if shifts[j] == 0 {
+                shifts[j] = j - i;
+            }
+
+            j = borders[j];
+        }
+
+        i -= 1;
+        j -= 1;
+        borders[i] = j;
+    }
+
+    // Suffixes that do not reoccur are aligned with the widest border of the whole pattern.
+    let mut j = borders[0];
+    for (i, shift) in shifts.iter_mut().enumerate() {
+        if *shift == 0 {
+            *shift = j;
+        }
+
+        if i == j {
+            j = borders[j];
+        }
+    }
+
+    shifts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn naive_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..=text.len()).filter(|&i| text[i..].starts_with(pattern)).collect()
+    }
+
+    #[test]
+    fn boyer_moore_should_find_occurrences_in_sentence() {
+        let text = "here is a simple example of a simple search".as_bytes();
+
+        assert_eq!(boyer_moore(text, "simple".as_bytes()), vec![10, 30]);
+        assert_eq!(boyer_moore(text, "absent".as_bytes()), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn horspool_should_find_occurrences_in_sentence() {
+        let text = "here is a simple example of a simple search".as_bytes();
+
+        assert_eq!(horspool(text, "simple".as_bytes()), vec![10, 30]);
+        assert_eq!(horspool(text, "absent".as_bytes()), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn overlapping_occurrences_should_be_found() {
+        assert_eq!(boyer_moore("aaaaa".as_bytes(), "aaa".as_bytes()), vec![0, 1, 2]);
+        assert_eq!(horspool("aaaaa".as_bytes(), "aaa".as_bytes()), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn searches_should_match_naive_search_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let text: Vec<u8> = (0..rng.gen_range(0..80)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+            let pattern: Vec<u8> = (0..rng.gen_range(0..6)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+            let expected = naive_search(&text, &pattern);
+
+            assert_eq!(boyer_moore(&text, &pattern), expected, "Boyer–Moore differs for {:?} in {:?}", pattern, text);
+            assert_eq!(horspool(&text, &pattern), expected, "Horspool differs for {:?} in {:?}", pattern, text);
+        }
+    }
+
+    // Text of random words, most of them lowercase letters, like prose.
+    fn english_like_text(rng: &mut impl Rng, length: usize) -> Vec<u8> {
+        const WORDS: [&str; 16] = [
+            "the", "of", "and", "to", "in", "a", "is", "that", "for", "it", "as", "was", "with", "be", "by", "on",
+        ];
+
+        let mut text = Vec::with_capacity(length + 8);
+        while text.len() < length {
+            text.extend_from_slice(WORDS[rng.gen_range(0..WORDS.len())].as_bytes());
+            text.push(if rng.gen_ratio(1, 12) { b'.' } else { b' ' });
+        }
+        text
+    }
+
+    #[test]
+    #[ignore = "benchmark, run with --release -- --ignored --nocapture"]
+    fn searches_should_beat_kmp_on_english_like_text() {
+        use crate::strings::kmp::kmp_search;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use std::time::{Duration, Instant};
+
+        fn time(search: impl Fn() -> Vec<usize>) -> (Duration, usize) {
+            let start = Instant::now();
+            let count = (0..20).map(|_| search().len()).sum();
+            (start.elapsed(), count)
+        }
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let text = english_like_text(&mut rng, 1 << 22);
+
+        for pattern in ["that", "with the", "it was to be", "the end of the chapter that was"] {
+            let pattern = pattern.as_bytes();
+
+            let (kmp, expected) = time(|| kmp_search(&text, pattern));
+            let (boyer_moore, found) = time(|| boyer_moore(&text, pattern));
+            assert_eq!(found, expected);
+            let (horspool, found) = time(|| horspool(&text, pattern));
+            assert_eq!(found, expected);
+
+            std::println!(
+                "{:>32}: KMP {:>8.2?}, Boyer–Moore {:>8.2?}, Horspool {:>8.2?}",
+                core::str::from_utf8(pattern).unwrap(),
+                kmp,
+                boyer_moore,
+                horspool
+            );
+            if pattern.len() >= 8 {
+                assert!(boyer_moore < kmp && horspool < kmp, "Skipping searches should be faster on long patterns");
+            }
+        }
+    }
+}
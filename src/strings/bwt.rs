@@ -0,0 +1,115 @@
+//! This module contains the Burrows–Wheeler transform and its inverse.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::strings::suffix_array::suffix_array;
+
+/// Computes the Burrows–Wheeler transform of a sequence.
+///
+/// Note:
+///     This function sorts the suffixes of `s$`, where `$` is an end marker smaller
+///     than every symbol, with the suffix array. The marker itself is not stored,
+///     only its position is returned.
+///
+/// Returns:
+///
+/// The last column of the sorted rotations without the end marker, and the
+/// index the end marker would have in that column.
+pub fn bwt<T: Ord + Clone>(s: &[T]) -> (Vec<T>, usize) {
+    let terminated: Vec<Option<&T>> = s.iter().map(Some).chain(core::iter::once(None)).collect();
+
+    let mut last_column = Vec::with_capacity(s.len());
+    let mut end_marker_index = 0;
+    for (row, suffix) in suffix_array(&terminated).into_iter().enumerate() {
+        if suffix == 0 {
+            end_marker_index = row;
+        } else {
+            last_column.push(s[suffix - 1].clone());
+        }
+    }
+
+    (last_column, end_marker_index)
+}
+
+/// Restores a sequence from its Burrows–Wheeler transform.
+///
+/// Arguments:
+///
+/// * `last_column`: The transformed sequence, as returned by `bwt`.
+/// * `end_marker_index`: The index of the end marker, as returned by `bwt`.
+///
+/// Returns:
+///
+/// The original sequence.
+pub fn inverse_bwt<T: Ord + Clone>(last_column: &[T], end_marker_index: usize) -> Vec<T> {
+    assert!(end_marker_index <= last_column.len(), "End marker index {} is out of bounds", end_marker_index);
+
+    let column: Vec<Option<&T>> = last_column[..end_marker_index].iter().map(Some)
+        .chain(core::iter::once(None))
+        .chain(last_column[end_marker_index..].iter().map(Some))
+        .collect();
+
+    // Stable sorting of the last column gives the first one, and maps every row to the
+    // row starting with the same occurrence of its last symbol (the LF mapping).
+    let mut first_column_order: Vec<usize> = (0..column.len()).collect();
+    first_column_order.sort_by_key(|&row| column[row]);
+
+    let mut last_to_first = vec![0; column.len()];
+    for (first_row, &last_row) in first_column_order.iter().enumerate() {
+        last_to_first[last_row] = first_row;
+    }
+
+    // Row 0 starts with the end marker, so its last symbol ends the original sequence.
+    let mut restored = Vec::with_capacity(last_column.len());
+    let mut row = 0;
+    while let Some(symbol) = column[row] {
+        restored.push(symbol.clone());
+        row = last_to_first[row];
+    }
+
+    restored.reverse();
+    restored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn bwt_of_banana_should_be_computed_correctly() {
+        let (last_column, end_marker_index) = bwt("banana".as_bytes());
+
+        assert_eq!(last_column, "annbaa".as_bytes());
+        assert_eq!(end_marker_index, 4);
+    }
+
+    #[test]
+    fn inverse_bwt_of_banana_should_restore_it() {
+        let restored = inverse_bwt("annbaa".as_bytes(), 4);
+
+        assert_eq!(restored, "banana".as_bytes());
+    }
+
+    #[test]
+    fn bwt_of_empty_sequence_should_be_empty() {
+        let empty: [u8; 0] = [];
+
+        assert_eq!(bwt(&empty), (vec![], 0));
+        assert!(inverse_bwt(&empty, 0).is_empty());
+    }
+
+    #[test]
+    fn inverse_bwt_should_restore_random_sequences() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let s: Vec<u8> = (0..rng.gen_range(0..100)).map(|_| rng.gen()).collect();
+
+            let (last_column, end_marker_index) = bwt(&s);
+
+            assert_eq!(inverse_bwt(&last_column, end_marker_index), s);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! This module contains edit distances between sequences and the edit scripts behind them.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A single step of an edit script turning one sequence into another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOperation {
+    /// Insert the symbol `b[to]` right before `a[at]`.
+    Insert { at: usize, to: usize },
+    /// Delete the symbol `a[from]`.
+    Delete { from: usize },
+    /// Replace the symbol `a[from]` with `b[to]`.
+    Substitute { from: usize, to: usize },
+}
+
+/// Computes the Levenshtein distance between two sequences.
+///
+/// Note:
+///     This function keeps only one row of the dynamic programming table, sized by
+///     the shorter sequence, so it uses O(min(n, m)) memory and O(n * m) time.
+pub fn levenshtein<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+    let mut row: Vec<usize> = (0..=short.len()).collect();
+    for (i, long_symbol) in long.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, short_symbol) in short.iter().enumerate() {
+            let substitution = diagonal + usize::from(long_symbol != short_symbol);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[short.len()]
+}
+
+/// Computes a shortest edit script turning `a` into `b`.
+///
+/// Note:
+///     This function keeps the whole O(n * m) table to trace the script back.
+///
+/// Returns:
+///
+/// Operations ordered by their position in `a`, insertions before the same position come first. Their count equals the Levenshtein distance.
+pub fn levenshtein_ops<T: Eq>(a: &[T], b: &[T]) -> Vec<EditOperation> {
+    let n = a.len();
+    let m = b.len();
+
+    let mut table = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, value) in table[0].iter_mut().enumerate() {
+        *value = j;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let substitution = table[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            table[i][j] = substitution.min(table[i - 1][j] + 1).min(table[i][j - 1] + 1);
+        }
+    }
+
+    let mut operations = Vec::with_capacity(table[n][m]);
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && table[i][j] == table[i - 1][j - 1] {
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && table[i][j] == table[i - 1][j - 1] + 1 {
+            operations.push(EditOperation::Substitute { from: i - 1, to: j - 1 });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && table[i][j] == table[i - 1][j] + 1 {
+            operations.push(EditOperation::Delete { from: i - 1 });
+            i -= 1;
+        } else {
+            operations.push(EditOperation::Insert { at: i, to: j - 1 });
+            j -= 1;
+        }
+    }
+
+    operations.reverse();
+    operations
+}
+
+/// Computes the Damerau–Levenshtein distance between two sequences.
+///
+/// Note:
+///     Besides insertions, deletions and substitutions a transposition of two adjacent
+///     symbols costs one edit. This is the unrestricted variant (Lowrance–Wagner), so
+///     a substring may be edited again after a transposition. Runs in O(n * m * log s)
+///     for s distinct symbols.
+pub fn damerau_levenshtein<T: Ord>(a: &[T], b: &[T]) -> usize {
+    let n = a.len();
+    let m = b.len();
+    let infinity = n + m;
+
+    // The table is shifted by one so the row and the column at index 0 act as sentinels.
+    let mut table = vec![vec![0; m + 2]; n + 2];
+    table[0][0] = infinity;
+    for i in 0..=n {
+        table[i + 1][0] = infinity;
+        table[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        table[0][j + 1] = infinity;
+        table[1][j + 1] = j;
+    }
+
+    // The last row of `a` where each symbol was seen.
+    let mut last_row: BTreeMap<&T, usize> = BTreeMap::new();
+    for i in 1..=n {
+        // The last column of `b` in the current row where the symbols matched.
+        let mut last_matching_column = 0;
+
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_matching_column;
+
+            let cost = if a[i - 1] == b[j - 1] {
+                last_matching_column = j;
+                0
+            } else {
+                1
+            };
+
+            table[i + 1][j + 1] = (table[i][j] + cost)
+                .min(table[i + 1][j] + 1)
+                .min(table[i][j + 1] + 1)
+                .min(table[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+
+        last_row.insert(&a[i - 1], i);
+    }
+
+    table[n + 1][m + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn apply(a: &[u8], b: &[u8], operations: &[EditOperation]) -> Vec<u8> {
+        let mut result = Vec::new();
+        let mut next = 0;
+        for operation in operations {
+            match *operation {
+                EditOperation::Insert { at, to } => {
+                    result.extend_from_slice(&a[next..at]);
+                    result.push(b[to]);
+                    next = at;
+                }
+                EditOperation::Delete { from } => {
+                    result.extend_from_slice(&a[next..from]);
+                    next = from + 1;
+                }
+                EditOperation::Substitute { from, to } => {
+                    result.extend_from_slice(&a[next..from]);
+                    result.push(b[to]);
+                    next = from + 1;
+                }
+            }
+        }
+
+        result.extend_from_slice(&a[next..]);
+        result
+    }
+
+    #[test]
+    fn levenshtein_distance_between_kitten_and_sitting_should_be_equal_3() {
+        assert_eq!(levenshtein("kitten".as_bytes(), "sitting".as_bytes()), 3);
+        assert_eq!(levenshtein("sitting".as_bytes(), "kitten".as_bytes()), 3);
+    }
+
+    #[test]
+    fn levenshtein_distance_to_empty_sequence_should_be_its_length() {
+        assert_eq!(levenshtein("abc".as_bytes(), "".as_bytes()), 3);
+        assert_eq!(levenshtein("".as_bytes(), "".as_bytes()), 0);
+    }
+
+    #[test]
+    fn edit_script_from_kitten_to_sitting_should_be_computed_correctly() {
+        let operations = levenshtein_ops("kitten".as_bytes(), "sitting".as_bytes());
+
+        assert_eq!(
+            operations,
+            vec![
+                EditOperation::Substitute { from: 0, to: 0 },
+                EditOperation::Substitute { from: 4, to: 4 },
+                EditOperation::Insert { at: 6, to: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_scripts_should_transform_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..15)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..15)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+
+            let operations = levenshtein_ops(&a, &b);
+
+            assert_eq!(operations.len(), levenshtein(&a, &b));
+            assert_eq!(apply(&a, &b, &operations), b, "Script {:?} does not turn {:?} into {:?}", operations, a, b);
+        }
+    }
+
+    #[test]
+    fn damerau_levenshtein_should_count_transpositions_as_one_edit() {
+        assert_eq!(damerau_levenshtein("ca".as_bytes(), "ac".as_bytes()), 1);
+        assert_eq!(levenshtein("ca".as_bytes(), "ac".as_bytes()), 2);
+    }
+
+    #[test]
+    fn damerau_levenshtein_should_allow_editing_transposed_symbols() {
+        // The restricted variant would report 3 here.
+        assert_eq!(damerau_levenshtein("ca".as_bytes(), "abc".as_bytes()), 2);
+    }
+
+    #[test]
+    fn damerau_levenshtein_should_never_exceed_levenshtein() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..10)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..10)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+
+            assert!(damerau_levenshtein(&a, &b) <= levenshtein(&a, &b));
+        }
+    }
+}
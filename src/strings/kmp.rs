@@ -0,0 +1,100 @@
+//! This module contains the prefix function and the Knuth–Morris–Pratt substring search.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Computes the prefix function of a sequence.
+///
+/// Note:
+///     The i-th value is the length of the longest proper prefix of `s[..=i]` that is also
+///     its suffix. This function runs in O(n).
+pub fn prefix_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+
+    pi
+}
+
+/// Finds all occurrences of the pattern in the text.
+///
+/// Note:
+///     This function uses the Knuth–Morris–Pratt algorithm, which reads every text symbol
+///     once and falls back along the prefix function of the pattern on a mismatch, so it
+///     runs in O(n + m) regardless of the input.
+///
+/// Returns:
+///
+/// Start positions of every (possibly overlapping) occurrence, in increasing order.
+pub fn kmp_search<T: Eq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+
+    let pi = prefix_function(pattern);
+    let mut positions = Vec::new();
+
+    // k is the length of the pattern prefix matched so far.
+    let mut k = 0;
+    for (i, symbol) in text.iter().enumerate() {
+        while k > 0 && *symbol != pattern[k] {
+            k = pi[k - 1];
+        }
+
+        if *symbol == pattern[k] {
+            k += 1;
+        }
+
+        if k == m {
+            positions.push(i + 1 - m);
+            k = pi[k - 1];
+        }
+    }
+
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn naive_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..=text.len()).filter(|&i| text[i..].starts_with(pattern)).collect()
+    }
+
+    #[test]
+    fn prefix_function_of_abacaba_should_be_computed_correctly() {
+        assert_eq!(prefix_function("abacaba".as_bytes()), vec![0, 0, 1, 0, 1, 2, 3]);
+        assert!(prefix_function::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn occurrences_should_be_found() {
+        assert_eq!(kmp_search("abababa".as_bytes(), "aba".as_bytes()), vec![0, 2, 4]);
+        assert_eq!(kmp_search("abc".as_bytes(), "".as_bytes()), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn kmp_search_should_match_naive_search_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let text: Vec<u8> = (0..rng.gen_range(0..80)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+            let pattern: Vec<u8> = (0..rng.gen_range(0..6)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+
+            assert_eq!(kmp_search(&text, &pattern), naive_search(&text, &pattern), "Search differs for {:?} in {:?}", pattern, text);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! This module contains the longest common subsequence and substring of two sequences.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::strings::suffix_array::{lcp_array, suffix_array};
+
+/// Computes a longest common subsequence of two sequences.
+///
+/// Note:
+///     This function uses Hirschberg's algorithm: O(n * m) time and O(n + m) memory.
+///
+/// Returns:
+///
+/// The length of the longest common subsequence and one such subsequence.
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> (usize, Vec<T>) {
+    let mut subsequence = Vec::new();
+    hirschberg(a, b, &mut subsequence);
+
+    (subsequence.len(), subsequence)
+}
+
+/// Computes only the length of a longest common subsequence in O(min(n, m)) memory.
+pub fn lcs_length<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+    *last_row(long.iter(), short.iter().collect::<Vec<_>>().as_slice()).last().unwrap()
+}
+
+fn hirschberg<T: Eq + Clone>(a: &[T], b: &[T], subsequence: &mut Vec<T>) {
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+
+    if a.len() == 1 {
+        if b.contains(&a[0]) {
+            subsequence.push(a[0].clone());
+        }
+
+        return;
+    }
+
+    let middle = a.len() / 2;
+    let b_forward: Vec<&T> = b.iter().collect();
+    let b_backward: Vec<&T> = b.iter().rev().collect();
+
+    let prefix_lengths = last_row(a[..middle].iter(), &b_forward);
+    let suffix_lengths = last_row(a[middle..].iter().rev(), &b_backward);
+
+    // Split b where the halves of a together keep the longest common subsequence.
+    let split = (0..=b.len()).max_by_key(|&j| (prefix_lengths[j] + suffix_lengths[b.len() - j], core::cmp::Reverse(j))).unwrap();
+
+    hirschberg(&a[..middle], &b[..split], subsequence);
+    hirschberg(&a[middle..], &b[split..], subsequence);
+}
+
+/// Last row of the LCS length table: the j-th value is the LCS length of `a` and `b[..j]`.
+fn last_row<'a, T: Eq + 'a>(a: impl Iterator<Item = &'a T>, b: &[&T]) -> Vec<usize> {
+    let mut row = vec![0; b.len() + 1];
+    for symbol in a {
+        let mut diagonal = 0;
+        for (j, &other) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if symbol == other { diagonal + 1 } else { above.max(row[j]) };
+            diagonal = above;
+        }
+    }
+
+    row
+}
+
+/// Finds a longest common substring (contiguous) of two sequences.
+///
+/// Note:
+///     This function builds the suffix and LCP arrays of `a$b` where `$` is a separator
+///     that differs from every symbol. The answer is the largest LCP of two adjacent
+///     suffixes that start in different sequences.
+///
+/// Returns:
+///
+/// A longest common substring as a subslice of `a`.
+pub fn longest_common_substring<'a, T: Ord>(a: &'a [T], b: &[T]) -> &'a [T] {
+    let concatenation: Vec<Option<&T>> = a.iter().map(Some).chain(core::iter::once(None)).chain(b.iter().map(Some)).collect();
+
+    let suffixes = suffix_array(&concatenation);
+    let lcp = lcp_array(&concatenation, &suffixes);
+
+    let mut best = 0..0;
+    for i in 1..suffixes.len() {
+        let (first, second) = (suffixes[i - 1], suffixes[i]);
+        if (first < a.len()) == (second < a.len()) {
+            continue;
+        }
+
+        let start = first.min(second);
+        if lcp[i] > best.len() {
+            best = start..start + lcp[i];
+        }
+    }
+
+    &a[best]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn is_subsequence(subsequence: &[u8], s: &[u8]) -> bool {
+        let mut symbols = s.iter();
+        subsequence.iter().all(|symbol| symbols.any(|other| other == symbol))
+    }
+
+    fn naive_lcs_length(a: &[u8], b: &[u8]) -> usize {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                table[i][j] = if a[i - 1] == b[j - 1] { table[i - 1][j - 1] + 1 } else { table[i - 1][j].max(table[i][j - 1]) };
+            }
+        }
+
+        table[a.len()][b.len()]
+    }
+
+    #[test]
+    fn lcs_of_abcbdab_and_bdcaba_should_have_length_4() {
+        let a = "ABCBDAB".as_bytes();
+        let b = "BDCABA".as_bytes();
+
+        let (length, subsequence) = lcs(a, b);
+
+        assert_eq!(length, 4);
+        assert!(is_subsequence(&subsequence, a) && is_subsequence(&subsequence, b));
+    }
+
+    #[test]
+    fn lcs_should_match_naive_dynamic_programming_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+
+            let (length, subsequence) = lcs(&a, &b);
+
+            assert_eq!(length, naive_lcs_length(&a, &b), "Wrong length for {:?} and {:?}", a, b);
+            assert_eq!(lcs_length(&a, &b), length);
+            assert!(is_subsequence(&subsequence, &a) && is_subsequence(&subsequence, &b));
+        }
+    }
+
+    #[test]
+    fn longest_common_substring_should_be_found() {
+        let substring = longest_common_substring("xabcdey".as_bytes(), "zzbcdezz".as_bytes());
+
+        assert_eq!(substring, "bcde".as_bytes());
+    }
+
+    #[test]
+    fn longest_common_substring_of_disjoint_sequences_should_be_empty() {
+        assert!(longest_common_substring("abc".as_bytes(), "xyz".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn longest_common_substring_should_match_brute_force_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let a: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..=b'b')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..=b'b')).collect();
+
+            let expected = (0..=a.len())
+                .flat_map(|i| (i..=a.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| i == j || b.windows(j - i).any(|window| window == &a[i..j]))
+                .map(|(i, j)| j - i)
+                .max()
+                .unwrap();
+            let substring = longest_common_substring(&a, &b);
+
+            assert_eq!(substring.len(), expected, "Wrong length for {:?} and {:?}", a, b);
+            assert!(substring.is_empty() || b.windows(substring.len()).any(|window| window == substring));
+        }
+    }
+}
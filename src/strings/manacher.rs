@@ -0,0 +1,155 @@
+//! This module contains Manacher's algorithm for finding palindromes.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Palindrome radii at every center of a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalindromeRadii {
+    /// `odd[i]` is the radius of the longest odd palindrome centered at symbol i,
+    /// counting the center itself, so its length is `2 * odd[i] - 1`.
+    pub odd: Vec<usize>,
+    /// `even[i]` is the radius of the longest even palindrome centered between
+    /// symbols i - 1 and i, so its length is `2 * even[i]`.
+    pub even: Vec<usize>,
+}
+
+/// Computes palindrome radii at every center of the sequence.
+///
+/// Note: This function uses Manacher's algorithm and runs in O(n).
+pub fn manacher<T: Eq>(s: &[T]) -> PalindromeRadii {
+    PalindromeRadii {
+        odd: odd_radii(s),
+        even: even_radii(s),
+    }
+}
+
+fn odd_radii<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut radii = vec![0; n];
+
+    // [left, right] is the rightmost palindrome found so far.
+    let mut left = 0;
+    let mut right = 0;
+    for i in 0..n {
+        let mut k = if i < right { radii[left + right - 1 - i].min(right - i) } else { 1 };
+
+        while i >= k && i + k < n && s[i - k] == s[i + k] {
+            k += 1;
+        }
+
+        radii[i] = k;
+        if i + k > right {
+            left = i + 1 - k;
+            right = i + k;
+        }
+    }
+
+    radii
+}
+
+fn even_radii<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut radii = vec![0; n];
+
+    // [left, right) is the rightmost palindrome found so far.
+    let mut left = 0;
+    let mut right = 0;
+    for i in 0..n {
+        let mut k = if i < right { radii[left + right - i].min(right - i) } else { 0 };
+
+        while i > k && i + k < n && s[i - k - 1] == s[i + k] {
+            k += 1;
+        }
+
+        radii[i] = k;
+        if i + k > right {
+            left = i - k;
+            right = i + k;
+        }
+    }
+
+    radii
+}
+
+/// Finds the longest palindromic substring.
+///
+/// Returns:
+///
+/// The range of the leftmost longest palindrome, empty only for an empty sequence.
+pub fn longest_palindromic_substring<T: Eq>(s: &[T]) -> Range<usize> {
+    let radii = manacher(s);
+
+    let odd = radii.odd.iter().enumerate().map(|(i, &k)| (i + 1 - k)..(i + k));
+    let even = radii.even.iter().enumerate().map(|(i, &k)| (i - k)..(i + k));
+
+    odd.chain(even).fold(0..0, |best, range| {
+        if range.len() > best.len() || (range.len() == best.len() && range.start < best.start) {
+            range
+        } else {
+            best
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    #[test]
+    fn radii_of_abacaba_should_be_computed_correctly() {
+        let radii = manacher("abacaba".as_bytes());
+
+        assert_eq!(radii.odd, vec![1, 2, 1, 4, 1, 2, 1]);
+        assert_eq!(radii.even, vec![0; 7]);
+    }
+
+    #[test]
+    fn radii_of_abba_should_be_computed_correctly() {
+        let radii = manacher("abba".as_bytes());
+
+        assert_eq!(radii.odd, vec![1, 1, 1, 1]);
+        assert_eq!(radii.even, vec![0, 0, 2, 0]);
+    }
+
+    #[test]
+    fn longest_palindrome_should_be_found() {
+        let s = "forgeeksskeegfor".as_bytes();
+
+        let range = longest_palindromic_substring(s);
+
+        assert_eq!(&s[range], "geeksskeeg".as_bytes());
+    }
+
+    #[test]
+    fn longest_palindrome_of_empty_sequence_should_be_empty() {
+        let empty: [u8; 0] = [];
+
+        assert!(longest_palindromic_substring(&empty).is_empty());
+    }
+
+    #[test]
+    fn longest_palindrome_should_match_brute_force_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let s: Vec<u8> = (0..rng.gen_range(1..40)).map(|_| rng.gen_range(b'a'..=b'b')).collect();
+
+            let expected = (0..s.len())
+                .flat_map(|i| (i + 1..=s.len()).map(move |j| j - i))
+                .filter(|&length| (0..=s.len() - length).any(|i| is_palindrome(&s[i..i + length])))
+                .max()
+                .unwrap();
+            let range = longest_palindromic_substring(&s);
+
+            assert_eq!(range.len(), expected, "Wrong length for {:?}", s);
+            assert!(is_palindrome(&s[range]));
+        }
+    }
+}
@@ -0,0 +1,23 @@
+pub mod rolling_hash;
+pub mod rabin_karp;
+pub mod z_function;
+pub mod boyer_moore;
+pub mod kmp;
+pub mod manacher;
+pub mod edit_distance;
+pub mod suffix_array;
+pub mod lcs;
+pub mod bwt;
+pub mod move_to_front;
+
+pub use rolling_hash::RollingHash;
+pub use rabin_karp::rabin_karp_search;
+pub use z_function::{z_function, z_search};
+pub use boyer_moore::{boyer_moore, horspool};
+pub use kmp::{kmp_search, prefix_function};
+pub use manacher::{manacher, longest_palindromic_substring};
+pub use edit_distance::{damerau_levenshtein, levenshtein, levenshtein_ops, EditOperation};
+pub use suffix_array::{suffix_array, lcp_array};
+pub use lcs::{lcs, lcs_length, longest_common_substring};
+pub use bwt::{bwt, inverse_bwt};
+pub use move_to_front::{move_to_front_encode, move_to_front_decode};
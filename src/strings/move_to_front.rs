@@ -0,0 +1,70 @@
+//! This module contains move-to-front coding of bytes.
+
+use alloc::vec::Vec;
+
+/// Encodes bytes with move-to-front coding.
+///
+/// Note:
+///     Every byte is replaced with its index in a list of all byte values, after which it
+///     is moved to the front of the list. Runs of equal bytes, such as those left by the
+///     Burrows–Wheeler transform, turn into runs of zeros.
+pub fn move_to_front_encode(data: &[u8]) -> Vec<u8> {
+    let mut alphabet: Vec<u8> = (0..=u8::MAX).collect();
+
+    data.iter()
+        .map(|&byte| {
+            let index = alphabet.iter().position(|&symbol| symbol == byte).unwrap();
+            alphabet[..=index].rotate_right(1);
+            index as u8
+        })
+        .collect()
+}
+
+/// Decodes bytes encoded with `move_to_front_encode`.
+pub fn move_to_front_decode(data: &[u8]) -> Vec<u8> {
+    let mut alphabet: Vec<u8> = (0..=u8::MAX).collect();
+
+    data.iter()
+        .map(|&index| {
+            let byte = alphabet[index as usize];
+            alphabet[..=index as usize].rotate_right(1);
+            byte
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strings::bwt::bwt;
+    use rand::Rng;
+
+    #[test]
+    fn bananaaa_should_be_encoded_correctly() {
+        let encoded = move_to_front_encode("bananaaa".as_bytes());
+
+        assert_eq!(encoded, vec![98, 98, 110, 1, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn decoding_should_restore_random_data() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let data: Vec<u8> = (0..rng.gen_range(0..200)).map(|_| rng.gen()).collect();
+
+            assert_eq!(move_to_front_decode(&move_to_front_encode(&data)), data);
+        }
+    }
+
+    #[test]
+    fn bwt_output_should_be_encoded_mostly_with_zeros() {
+        let text = "the quick brown fox jumps over the lazy dog. ".repeat(20);
+
+        let (last_column, _) = bwt(text.as_bytes());
+        let encoded = move_to_front_encode(&last_column);
+
+        let zeros = encoded.iter().filter(|&&index| index == 0).count();
+        assert!(zeros * 2 > encoded.len(), "Only {} zeros out of {}", zeros, encoded.len());
+    }
+}
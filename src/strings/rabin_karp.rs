@@ -0,0 +1,67 @@
+//! This module contains the Rabin–Karp substring search.
+
+use alloc::vec::Vec;
+
+use crate::strings::RollingHash;
+
+/// Finds all occurrences of the pattern in the text.
+///
+/// Note:
+///     This function uses the Rabin–Karp algorithm. Every hash match is verified
+///     by a direct comparison, so collisions never produce false positives.
+///
+/// Arguments:
+///
+/// * `text`: The sequence to search in.
+/// * `pattern`: The sequence to search for.
+///
+/// Returns:
+///
+/// Start positions of every (possibly overlapping) occurrence, in increasing order.
+pub fn rabin_karp_search<T: Copy + Eq + Into<u64>>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    if pattern.len() > text.len() {
+        return Vec::new();
+    }
+
+    let text_hash = RollingHash::new(text);
+    let pattern_hash = text_hash.hash_of(pattern);
+
+    (0..=(text.len() - pattern.len()))
+        .filter(|&start| {
+            let end = start + pattern.len();
+            text_hash.hash(start..end) == pattern_hash && text[start..end] == *pattern
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_overlapping_occurrences_should_be_found() {
+        let positions = rabin_karp_search("aaaa".as_bytes(), "aa".as_bytes());
+
+        assert_eq!(positions, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn occurrences_in_sentence_should_be_found() {
+        let positions = rabin_karp_search("the cat sat on the mat".as_bytes(), "at".as_bytes());
+
+        assert_eq!(positions, vec![5, 9, 20]);
+    }
+
+    #[test]
+    fn missing_pattern_should_yield_no_occurrences() {
+        assert!(rabin_karp_search("abc".as_bytes(), "abd".as_bytes()).is_empty());
+        assert!(rabin_karp_search("ab".as_bytes(), "abc".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn empty_pattern_should_match_at_every_position() {
+        let positions = rabin_karp_search("abc".as_bytes(), "".as_bytes());
+
+        assert_eq!(positions, vec![0, 1, 2, 3]);
+    }
+}
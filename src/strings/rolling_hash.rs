@@ -0,0 +1,166 @@
+//! This module contains a polynomial rolling hash over sequences of symbols.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Base used by the default constructor.
+pub const DEFAULT_BASE: u64 = 131;
+
+/// First modulus used by the default constructor.
+pub const DEFAULT_FIRST_MODULUS: u64 = 1_000_000_007;
+
+/// Second modulus used by the default constructor.
+pub const DEFAULT_SECOND_MODULUS: u64 = 998_244_353;
+
+/// Prefix hashes and base powers for a single modulus.
+#[derive(Debug, Clone)]
+struct Layer {
+    modulus: u64,
+    prefixes: Vec<u64>,
+    powers: Vec<u64>,
+}
+
+impl Layer {
+    fn build<T: Copy + Into<u64>>(s: &[T], base: u64, modulus: u64) -> Self {
+        let mut prefixes = Vec::with_capacity(s.len() + 1);
+        let mut powers = Vec::with_capacity(s.len() + 1);
+
+        prefixes.push(0);
+        powers.push(1);
+
+        for (i, &symbol) in s.iter().enumerate() {
+            prefixes.push(Self::push(prefixes[i], symbol.into(), base, modulus));
+            powers.push(mul_mod(powers[i], base, modulus));
+        }
+
+        Layer { modulus, prefixes, powers }
+    }
+
+    fn push(hash: u64, symbol: u64, base: u64, modulus: u64) -> u64 {
+        (mul_mod(hash, base, modulus) + symbol % modulus) % modulus
+    }
+
+    fn hash(&self, range: Range<usize>) -> u64 {
+        let removed = mul_mod(self.prefixes[range.start], self.powers[range.len()], self.modulus);
+        (self.prefixes[range.end] + self.modulus - removed) % self.modulus
+    }
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+/// Polynomial hash of a sequence that answers substring hash queries in O(1).
+///
+/// Note:
+///     The prefix hashes are built once in O(n). Using two moduli makes accidental
+///     collisions much less likely at the cost of doubling the work.
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    base: u64,
+    layers: Vec<Layer>,
+}
+
+impl RollingHash {
+    /// Builds a rolling hash of the given sequence using two default moduli.
+    pub fn new<T: Copy + Into<u64>>(s: &[T]) -> Self {
+        Self::with_moduli(s, DEFAULT_BASE, &[DEFAULT_FIRST_MODULUS, DEFAULT_SECOND_MODULUS])
+    }
+
+    /// Builds a rolling hash of the given sequence.
+    ///
+    /// Arguments:
+    ///
+    /// * `s`: The sequence to hash.
+    /// * `base`: The base of the polynomial, should be greater than any symbol.
+    /// * `moduli`: One or two moduli, each of them smaller than 2^63.
+    ///
+    /// Panics when `moduli` does not contain one or two values.
+    pub fn with_moduli<T: Copy + Into<u64>>(s: &[T], base: u64, moduli: &[u64]) -> Self {
+        assert!(
+            (1..=2).contains(&moduli.len()),
+            "Rolling hash supports one or two moduli, got {}",
+            moduli.len()
+        );
+
+        let layers = moduli.iter().map(|&modulus| Layer::build(s, base, modulus)).collect();
+
+        RollingHash { base, layers }
+    }
+
+    /// Returns the length of the hashed sequence.
+    pub fn len(&self) -> usize {
+        self.layers[0].prefixes.len() - 1
+    }
+
+    /// Returns true when the hashed sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the hash of the subsequence in the given range.
+    ///
+    /// Note: when two moduli are used, the first one occupies the upper 64 bits.
+    pub fn hash(&self, range: Range<usize>) -> u128 {
+        assert!(range.start <= range.end && range.end <= self.len(), "Range {:?} is out of bounds", range);
+
+        self.layers.iter().fold(0u128, |acc, layer| (acc << 64) | layer.hash(range.clone()) as u128)
+    }
+
+    /// Returns the hash of the whole sequence.
+    pub fn full_hash(&self) -> u128 {
+        self.hash(0..self.len())
+    }
+
+    /// Hashes an unrelated sequence with the same base and moduli,
+    /// so the result can be compared against `hash` of this one.
+    pub fn hash_of<T: Copy + Into<u64>>(&self, s: &[T]) -> u128 {
+        self.layers.iter().fold(0u128, |acc, layer| {
+            let hash = s.iter().fold(0, |hash, &symbol| Layer::push(hash, symbol.into(), self.base, layer.modulus));
+            (acc << 64) | hash as u128
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_substrings_should_have_equal_hashes() {
+        let hash = RollingHash::new("abcabc".as_bytes());
+
+        assert_eq!(hash.hash(0..3), hash.hash(3..6));
+        assert_eq!(hash.hash(1..2), hash.hash(4..5));
+    }
+
+    #[test]
+    fn different_substrings_should_have_different_hashes() {
+        let hash = RollingHash::new("abcabd".as_bytes());
+
+        assert_ne!(hash.hash(0..3), hash.hash(3..6));
+    }
+
+    #[test]
+    fn hash_of_should_match_hash_of_equal_substring() {
+        let hash = RollingHash::new("hello world".as_bytes());
+
+        assert_eq!(hash.hash_of("world".as_bytes()), hash.hash(6..11));
+    }
+
+    #[test]
+    fn single_modulus_hash_should_fit_into_64_bits() {
+        let hash = RollingHash::with_moduli("banana".as_bytes(), DEFAULT_BASE, &[DEFAULT_FIRST_MODULUS]);
+
+        assert!(hash.full_hash() < DEFAULT_FIRST_MODULUS as u128);
+        assert_eq!(hash.hash(1..3), hash.hash(3..5));
+    }
+
+    #[test]
+    fn empty_ranges_should_hash_to_zero() {
+        let hash = RollingHash::new("abc".as_bytes());
+
+        assert_eq!(hash.hash(2..2), 0);
+        assert_eq!(hash.len(), 3);
+    }
+}
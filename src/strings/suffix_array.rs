@@ -0,0 +1,114 @@
+//! This module contains suffix array and LCP array construction.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Builds the suffix array of a sequence.
+///
+/// Note:
+///     This function uses prefix doubling: suffixes are ranked by their first 2^k symbols
+///     for growing k. Runs in O(n log^2 n).
+///
+/// Returns:
+///
+/// Start positions of all suffixes in lexicographic order.
+pub fn suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut suffixes: Vec<usize> = (0..n).collect();
+    suffixes.sort_by(|&i, &j| s[i].cmp(&s[j]));
+
+    let mut ranks = vec![0; n];
+    for i in 1..n {
+        ranks[suffixes[i]] = ranks[suffixes[i - 1]] + usize::from(s[suffixes[i]] != s[suffixes[i - 1]]);
+    }
+
+    let mut length = 1;
+    let mut next_ranks = vec![0; n];
+    while length < n && ranks[suffixes[n - 1]] < n - 1 {
+        // Suffixes shorter than the length sort before longer ones with the same rank.
+        let key = |i: usize| (ranks[i], ranks.get(i + length).map(|&rank| rank + 1).unwrap_or(0));
+
+        suffixes.sort_by_key(|&i| key(i));
+
+        next_ranks[suffixes[0]] = 0;
+        for i in 1..n {
+            next_ranks[suffixes[i]] = next_ranks[suffixes[i - 1]] + usize::from(key(suffixes[i]) != key(suffixes[i - 1]));
+        }
+
+        core::mem::swap(&mut ranks, &mut next_ranks);
+        length *= 2;
+    }
+
+    suffixes
+}
+
+/// Builds the longest-common-prefix array from a suffix array.
+///
+/// Note: This function uses Kasai's algorithm and runs in O(n).
+///
+/// Returns:
+///
+/// A vector where the i-th value is the length of the longest common prefix of
+/// suffixes `suffixes[i - 1]` and `suffixes[i]`, the zeroth value is always 0.
+pub fn lcp_array<T: Eq>(s: &[T], suffixes: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut ranks = vec![0; n];
+    for (rank, &suffix) in suffixes.iter().enumerate() {
+        ranks[suffix] = rank;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut common = 0;
+    for i in 0..n {
+        if ranks[i] == 0 {
+            common = 0;
+            continue;
+        }
+
+        let previous = suffixes[ranks[i] - 1];
+        while i + common < n && previous + common < n && s[i + common] == s[previous + common] {
+            common += 1;
+        }
+
+        lcp[ranks[i]] = common;
+        common = common.saturating_sub(1);
+    }
+
+    lcp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn suffix_array_of_banana_should_be_built_correctly() {
+        let suffixes = suffix_array("banana".as_bytes());
+
+        assert_eq!(suffixes, vec![5, 3, 1, 0, 4, 2]);
+    }
+
+    #[test]
+    fn lcp_array_of_banana_should_be_built_correctly() {
+        let s = "banana".as_bytes();
+
+        let lcp = lcp_array(s, &suffix_array(s));
+
+        assert_eq!(lcp, vec![0, 1, 3, 0, 0, 2]);
+    }
+
+    #[test]
+    fn suffix_array_should_match_naive_sorting_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let s: Vec<u8> = (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+
+            let mut expected: Vec<usize> = (0..s.len()).collect();
+            expected.sort_by_key(|&i| &s[i..]);
+
+            assert_eq!(suffix_array(&s), expected, "Suffix array differs for {:?}", s);
+        }
+    }
+}
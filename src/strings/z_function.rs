@@ -0,0 +1,126 @@
+//! This module contains the Z-algorithm and a substring matcher built on it.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Computes the Z-array of a sequence.
+///
+/// Note:
+///     The i-th value is the length of the longest common prefix of the sequence
+///     and its suffix starting at i. By convention the first value equals the length
+///     of the whole sequence. This function runs in O(n).
+pub fn z_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+
+    if n == 0 {
+        return z;
+    }
+
+    z[0] = n;
+
+    // [left, right) is the rightmost segment known to match a prefix of the sequence.
+    let mut left = 0;
+    let mut right = 0;
+    for i in 1..n {
+        if i < right {
+            z[i] = z[i - left].min(right - i);
+        }
+
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+
+        if i + z[i] > right {
+            left = i;
+            right = i + z[i];
+        }
+    }
+
+    z
+}
+
+/// Finds all occurrences of the pattern in the text.
+///
+/// Note:
+///     This function computes the Z-array of `pattern$text`, where `$` is a separator
+///     that differs from every symbol, so no symbol has to be reserved for it.
+///
+/// Returns:
+///
+/// Start positions of every (possibly overlapping) occurrence, in increasing order.
+pub fn z_search<T: Eq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+
+    let concatenation: Vec<Option<&T>> = pattern.iter().map(Some).chain(core::iter::once(None)).chain(text.iter().map(Some)).collect();
+
+    let offset = pattern.len() + 1;
+    z_function(&concatenation)
+        .into_iter()
+        .enumerate()
+        .skip(offset)
+        .filter(|&(_, length)| length == pattern.len())
+        .map(|(index, _)| index - offset)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn naive_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..=text.len()).filter(|&i| text[i..].starts_with(pattern)).collect()
+    }
+
+    fn naive_z_function(s: &[u8]) -> Vec<usize> {
+        (0..s.len()).map(|i| s.iter().zip(&s[i..]).take_while(|(a, b)| a == b).count()).collect()
+    }
+
+    #[test]
+    fn z_function_of_aabxaab_should_be_computed_correctly() {
+        let z = z_function("aabxaab".as_bytes());
+
+        assert_eq!(z, vec![7, 1, 0, 0, 3, 1, 0]);
+    }
+
+    #[test]
+    fn z_function_of_empty_sequence_should_be_empty() {
+        let empty: [u8; 0] = [];
+
+        assert!(z_function(&empty).is_empty());
+    }
+
+    #[test]
+    fn occurrences_should_be_found() {
+        let positions = z_search("abababa".as_bytes(), "aba".as_bytes());
+
+        assert_eq!(positions, vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn z_function_should_match_naive_implementation_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let length = rng.gen_range(1..50);
+            let s: Vec<u8> = (0..length).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+
+            assert_eq!(z_function(&s), naive_z_function(&s), "Z-function differs for {:?}", s);
+        }
+    }
+
+    #[test]
+    fn z_search_should_match_naive_search_on_random_strings() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let text: Vec<u8> = (0..rng.gen_range(0..60)).map(|_| rng.gen_range(b'a'..=b'b')).collect();
+            let pattern: Vec<u8> = (0..rng.gen_range(0..5)).map(|_| rng.gen_range(b'a'..=b'b')).collect();
+
+            assert_eq!(z_search(&text, &pattern), naive_search(&text, &pattern), "Search differs for {:?} in {:?}", pattern, text);
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! This module contains a set of bits packed into 64-bit words with word-level operations.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, ShlAssign, Shr, ShrAssign};
+
+const WORD_BITS: usize = 64;
+
+/// Fixed number of bits, each set or clear, packed 64 to a word.
+///
+/// Note:
+///     Set operations and shifts work a word at a time, so they take O(n / 64). Both operands
+///     of a set operation must have the same length. Bits past the length are always kept
+///     clear, which keeps counting and comparing exact.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// Creates a set of the given number of clear bits.
+    pub fn new(len: usize) -> Self {
+        BitSet { words: vec![0; len.div_ceil(WORD_BITS)], len }
+    }
+
+    /// Creates a set of the given number of set bits.
+    pub fn full(len: usize) -> Self {
+        let mut bits = BitSet { words: vec![u64::MAX; len.div_ceil(WORD_BITS)], len };
+        bits.trim();
+        bits
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when there are no bits at all.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the words holding the bits, bit i being bit i % 64 of word i / 64.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    // Clears the unused bits of the last word.
+    fn trim(&mut self) {
+        let used = self.len % WORD_BITS;
+        if used > 0 {
+            *self.words.last_mut().unwrap() &= (1 << used) - 1;
+        }
+    }
+
+    fn check_index(&self, index: usize) {
+        assert!(index < self.len, "Bit {} is out of {} bits", index, self.len);
+    }
+
+    /// Tells whether the bit is set.
+    ///
+    /// Panics when the index is not smaller than the length.
+    pub fn test(&self, index: usize) -> bool {
+        self.check_index(index);
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Sets the bit.
+    ///
+    /// Panics when the index is not smaller than the length.
+    pub fn set(&mut self, index: usize) {
+        self.check_index(index);
+        self.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+    }
+
+    /// Clears the bit.
+    ///
+    /// Panics when the index is not smaller than the length.
+    pub fn clear(&mut self, index: usize) {
+        self.check_index(index);
+        self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+    }
+
+    /// Flips the bit.
+    ///
+    /// Panics when the index is not smaller than the length.
+    pub fn toggle(&mut self, index: usize) {
+        self.check_index(index);
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+    }
+
+    /// Clears the bits from the start on, the step apart: start, start + step and so on.
+    ///
+    /// Panics when the step is zero.
+    pub fn clear_step(&mut self, start: usize, step: usize) {
+        assert!(step > 0, "Step must be positive");
+
+        let mut index = start;
+        while index < self.len {
+            self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+            index += step;
+        }
+    }
+
+    /// Sets or clears every bit.
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+        self.trim();
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns the indices of the set bits in increasing order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut rest = word;
+            core::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(index * WORD_BITS + bit)
+            })
+        })
+    }
+
+    fn combine(&mut self, other: &BitSet, operation: impl Fn(u64, u64) -> u64) {
+        assert_eq!(self.len, other.len, "Bit sets must have the same length");
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word = operation(*word, other);
+        }
+    }
+}
+
+macro_rules! impl_set_operation {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $assign_trait<&BitSet> for BitSet {
+            fn $assign_method(&mut self, other: &BitSet) {
+                self.combine(other, |a, b| a $op b);
+            }
+        }
+
+        impl $trait for &BitSet {
+            type Output = BitSet;
+
+            fn $method(self, other: &BitSet) -> BitSet {
+                let mut result = self.clone();
+                result.$assign_method(other);
+                result
+            }
+        }
+    };
+}
+
+impl_set_operation!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_set_operation!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_set_operation!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+/// Moves every bit i to i + shift, dropping the bits moved past the length.
+impl ShlAssign<usize> for BitSet {
+    fn shl_assign(&mut self, shift: usize) {
+        let (word_shift, bit_shift) = (shift / WORD_BITS, shift % WORD_BITS);
+
+        // Words are rewritten from the top, as they are read from below.
+        for i in (0..self.words.len()).rev() {
+            self.words[i] = if i < word_shift {
+                0
+            } else if bit_shift == 0 {
+                self.words[i - word_shift]
+            } else {
+                let carried = if i > word_shift { self.words[i - word_shift - 1] >> (WORD_BITS - bit_shift) } else { 0 };
+                self.words[i - word_shift] << bit_shift | carried
+            };
+        }
+
+        self.trim();
+    }
+}
+
+/// Moves every bit i to i - shift, dropping the bits moved below zero.
+impl ShrAssign<usize> for BitSet {
+    fn shr_assign(&mut self, shift: usize) {
+        let (word_shift, bit_shift) = (shift / WORD_BITS, shift % WORD_BITS);
+        let count = self.words.len();
+
+        for i in 0..count {
+            let source = i.saturating_add(word_shift);
+            self.words[i] = if source >= count {
+                0
+            } else if bit_shift == 0 {
+                self.words[source]
+            } else {
+                let carried = if source + 1 < count { self.words[source + 1] << (WORD_BITS - bit_shift) } else { 0 };
+                self.words[source] >> bit_shift | carried
+            };
+        }
+    }
+}
+
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shl(self, shift: usize) -> BitSet {
+        let mut result = self.clone();
+        result <<= shift;
+        result
+    }
+}
+
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shr(self, shift: usize) -> BitSet {
+        let mut result = self.clone();
+        result >>= shift;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn random_flags(rng: &mut impl Rng, len: usize) -> Vec<bool> {
+        (0..len).map(|_| rng.gen_bool(0.3)).collect()
+    }
+
+    fn from_flags(flags: &[bool]) -> BitSet {
+        let mut bits = BitSet::new(flags.len());
+        for index in (0..flags.len()).filter(|&index| flags[index]) {
+            bits.set(index);
+        }
+        bits
+    }
+
+    fn to_flags(bits: &BitSet) -> Vec<bool> {
+        (0..bits.len()).map(|index| bits.test(index)).collect()
+    }
+
+    #[test]
+    fn single_bits_should_be_changed() {
+        let mut bits = BitSet::new(100);
+
+        bits.set(3);
+        bits.set(64);
+        bits.toggle(99);
+        bits.toggle(3);
+        bits.clear(64);
+        bits.set(70);
+
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), vec![70, 99]);
+        assert_eq!(bits.count_ones(), 2);
+        assert!(bits.test(99) && !bits.test(3));
+    }
+
+    #[test]
+    fn stepped_bits_should_be_cleared() {
+        let mut bits = BitSet::full(200);
+
+        bits.clear_step(5, 64);
+        bits.clear_step(300, 1);
+
+        assert_eq!(bits.count_ones(), 196);
+        assert!(!bits.test(5) && !bits.test(69) && !bits.test(133) && !bits.test(197));
+    }
+
+    #[test]
+    fn full_set_should_keep_bits_past_the_length_clear() {
+        let mut bits = BitSet::full(70);
+
+        assert_eq!(bits.count_ones(), 70);
+        assert_eq!(bits.as_words()[1], (1 << 6) - 1);
+
+        bits.fill(false);
+        assert_eq!(bits, BitSet::new(70));
+    }
+
+    #[test]
+    fn set_operations_should_match_flags() {
+        let mut rng = rand::thread_rng();
+
+        for len in [0, 1, 63, 64, 65, 200] {
+            let (a, b) = (random_flags(&mut rng, len), random_flags(&mut rng, len));
+            let (x, y) = (from_flags(&a), from_flags(&b));
+
+            let expected = |op: fn(bool, bool) -> bool| (0..len).map(|i| op(a[i], b[i])).collect::<Vec<_>>();
+
+            assert_eq!(to_flags(&(&x & &y)), expected(|p, q| p & q));
+            assert_eq!(to_flags(&(&x | &y)), expected(|p, q| p | q));
+            assert_eq!(to_flags(&(&x ^ &y)), expected(|p, q| p ^ q));
+        }
+    }
+
+    #[test]
+    fn shifts_should_move_bits_and_drop_overflow() {
+        let mut rng = rand::thread_rng();
+
+        for len in [1, 63, 64, 65, 200] {
+            let flags = random_flags(&mut rng, len);
+            let bits = from_flags(&flags);
+
+            for shift in [0, 1, 5, 63, 64, 65, 130, 500] {
+                let left: Vec<bool> = (0..len).map(|i| i >= shift && flags[i - shift]).collect();
+                let right: Vec<bool> = (0..len).map(|i| i + shift < len && flags[i + shift]).collect();
+
+                assert_eq!(to_flags(&(&bits << shift)), left, "{} << {}", len, shift);
+                assert_eq!(to_flags(&(&bits >> shift)), right, "{} >> {}", len, shift);
+                assert_eq!((&bits << shift).count_ones(), left.iter().filter(|&&flag| flag).count());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_lengths_should_panic() {
+        let mut bits = BitSet::new(3);
+        bits |= &BitSet::new(4);
+    }
+}
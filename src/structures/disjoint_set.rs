@@ -0,0 +1,126 @@
+//! This module contains a disjoint-set (union–find) structure.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Partition of elements 0..n into disjoint sets.
+///
+/// Note:
+///     Union by size and path compression make every operation run in amortized
+///     O(α(n)), effectively constant time.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    set_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates a partition where every element is alone in its set.
+    pub fn new(len: usize) -> Self {
+        DisjointSet { parents: (0..len).collect(), sizes: vec![1; len], set_count: len }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns true when there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Returns the representative of the set containing the element.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while self.parents[current] != root {
+            current = core::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing the elements.
+    ///
+    /// Returns:
+    ///
+    /// True when the elements were in different sets.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            core::mem::swap(&mut a, &mut b);
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns true when the elements are in the same set.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing the element.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn united_elements_should_be_in_the_same_set() {
+        let mut sets = DisjointSet::new(5);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(sets.union(1, 4));
+
+        assert!(sets.same_set(0, 3));
+        assert!(!sets.same_set(0, 2));
+        assert_eq!(sets.set_size(4), 4);
+        assert_eq!(sets.set_count(), 2);
+    }
+
+    #[test]
+    fn uniting_the_same_set_should_change_nothing() {
+        let mut sets = DisjointSet::new(3);
+        sets.union(0, 1);
+
+        assert!(!sets.union(1, 0));
+        assert_eq!(sets.set_count(), 2);
+    }
+
+    #[test]
+    fn long_chain_should_be_compressed() {
+        let mut sets = DisjointSet::new(10_000);
+        for i in 1..10_000 {
+            sets.union(i - 1, i);
+        }
+
+        let root = sets.find(9_999);
+
+        assert!((0..10_000).all(|i| sets.find(i) == root));
+        assert_eq!(sets.set_count(), 1);
+    }
+}
@@ -0,0 +1,249 @@
+//! This module contains a binary min-heap of indices with changeable priorities.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Binary min-heap over indices 0..capacity, each present at most once.
+///
+/// Note:
+///     Besides the usual push and pop, the priority of an index already in the heap can be
+///     changed in O(log n), which is what Dijkstra's and Prim's algorithms need.
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<P> {
+    heap: Vec<(usize, P)>,
+    // positions[i] is the position of index i in the heap, usize::MAX when absent.
+    positions: Vec<usize>,
+}
+
+const ABSENT: usize = usize::MAX;
+
+impl<P: Ord + Copy> IndexedHeap<P> {
+    /// Creates an empty heap for indices in 0..capacity.
+    pub fn new(capacity: usize) -> Self {
+        IndexedHeap { heap: Vec::new(), positions: vec![ABSENT; capacity] }
+    }
+
+    /// Returns the number of indices in the heap.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true when the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns true when the index is in the heap.
+    pub fn contains(&self, index: usize) -> bool {
+        self.positions[index] != ABSENT
+    }
+
+    /// Returns the priority of the index, if it is in the heap.
+    pub fn priority(&self, index: usize) -> Option<P> {
+        self.contains(index).then(|| self.heap[self.positions[index]].1)
+    }
+
+    /// Returns the index with the smallest priority without removing it.
+    pub fn peek(&self) -> Option<(usize, P)> {
+        self.heap.first().copied()
+    }
+
+    /// Inserts the index, or changes its priority when it is already in the heap.
+    ///
+    /// Panics when the index is not smaller than the capacity.
+    pub fn push(&mut self, index: usize, priority: P) {
+        assert!(index < self.positions.len(), "Index {} is out of the heap capacity {}", index, self.positions.len());
+
+        if self.contains(index) {
+            let position = self.positions[index];
+            let previous = core::mem::replace(&mut self.heap[position].1, priority);
+            if priority < previous {
+                self.sift_up(position);
+            } else {
+                self.sift_down(position);
+            }
+
+            return;
+        }
+
+        self.heap.push((index, priority));
+        self.positions[index] = self.heap.len() - 1;
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    /// Lowers the priority of the index or inserts it.
+    ///
+    /// Returns:
+    ///
+    /// True when the heap changed, false when the index already had a priority not greater than the given one.
+    pub fn push_decrease(&mut self, index: usize, priority: P) -> bool {
+        match self.priority(index) {
+            Some(current) if current <= priority => false,
+            _ => {
+                self.push(index, priority);
+                true
+            }
+        }
+    }
+
+    /// Removes and returns the index with the smallest priority.
+    pub fn pop(&mut self) -> Option<(usize, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (index, priority) = self.heap.pop().unwrap();
+        self.positions[index] = ABSENT;
+
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+
+        Some((index, priority))
+    }
+
+    /// Removes the index from the heap and returns its priority.
+    pub fn remove(&mut self, index: usize) -> Option<P> {
+        if !self.contains(index) {
+            return None;
+        }
+
+        let position = self.positions[index];
+        let last = self.heap.len() - 1;
+        self.swap(position, last);
+        let (_, priority) = self.heap.pop().unwrap();
+        self.positions[index] = ABSENT;
+
+        if position < self.heap.len() {
+            let moved = self.heap[position].0;
+            self.sift_up(position);
+            self.sift_down(self.positions[moved]);
+        }
+
+        Some(priority)
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if self.heap[parent].1 <= self.heap[position].1 {
+                break;
+            }
+
+            self.swap(parent, position);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+
+            let mut smallest = position;
+            if left < self.heap.len() && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+
+            if smallest == position {
+                break;
+            }
+
+            self.swap(smallest, position);
+            position = smallest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a].0] = a;
+        self.positions[self.heap[b].0] = b;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn indices_should_be_popped_in_priority_order() {
+        let mut heap = IndexedHeap::new(4);
+        heap.push(0, 30);
+        heap.push(1, 10);
+        heap.push(2, 20);
+
+        assert_eq!(heap.pop(), Some((1, 10)));
+        assert_eq!(heap.pop(), Some((2, 20)));
+        assert_eq!(heap.pop(), Some((0, 30)));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn priorities_should_be_changeable() {
+        let mut heap = IndexedHeap::new(3);
+        heap.push(0, 5);
+        heap.push(1, 6);
+        heap.push(2, 7);
+
+        heap.push(2, 1);
+        heap.push(0, 9);
+
+        assert!(!heap.push_decrease(1, 8));
+        assert!(heap.push_decrease(1, 4));
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.pop(), Some((2, 1)));
+        assert_eq!(heap.pop(), Some((1, 4)));
+        assert_eq!(heap.pop(), Some((0, 9)));
+    }
+
+    #[test]
+    fn removed_index_should_not_be_popped() {
+        let mut heap = IndexedHeap::new(3);
+        heap.push(0, 1);
+        heap.push(1, 2);
+        heap.push(2, 3);
+
+        assert_eq!(heap.remove(0), Some(1));
+        assert_eq!(heap.remove(0), None);
+        assert!(!heap.contains(0));
+        assert_eq!(heap.peek(), Some((1, 2)));
+    }
+
+    #[test]
+    fn random_operations_should_match_sorting() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let capacity = rng.gen_range(1..50);
+            let mut heap = IndexedHeap::new(capacity);
+            let mut expected = vec![None; capacity];
+
+            for _ in 0..200 {
+                let index = rng.gen_range(0..capacity);
+                if rng.gen_bool(0.2) {
+                    assert_eq!(heap.remove(index), expected[index].take());
+                } else {
+                    let priority = rng.gen_range(0..100);
+                    heap.push(index, priority);
+                    expected[index] = Some(priority);
+                }
+            }
+
+            let mut remaining: Vec<(i32, usize)> = expected.iter().enumerate().filter_map(|(index, priority)| priority.map(|priority| (priority, index))).collect();
+            remaining.sort();
+
+            let mut popped = Vec::new();
+            while let Some((index, priority)) = heap.pop() {
+                popped.push((priority, index));
+            }
+
+            assert_eq!(popped.iter().map(|&(priority, _)| priority).collect::<Vec<_>>(), remaining.iter().map(|&(priority, _)| priority).collect::<Vec<_>>());
+        }
+    }
+}
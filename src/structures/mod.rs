@@ -0,0 +1,13 @@
+pub mod indexed_heap;
+pub mod disjoint_set;
+pub mod open_addressing_map;
+pub mod monotonic;
+pub mod order_statistic_tree;
+pub mod bitset;
+
+pub use indexed_heap::IndexedHeap;
+pub use disjoint_set::DisjointSet;
+pub use open_addressing_map::{OpenAddressingMap, Probing};
+pub use monotonic::{next_greater_element, sliding_window_max, MonotonicQueue};
+pub use order_statistic_tree::{count_inversions, OrderStatisticTree};
+pub use bitset::BitSet;
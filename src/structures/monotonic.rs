@@ -0,0 +1,204 @@
+//! This module contains a queue reporting its maximum and other monotonic stack and queue utilities.
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// First in, first out queue that reports the maximum of its elements.
+///
+/// Note:
+///     Besides the elements, the positions of the ones that can still become the maximum are
+///     kept, in decreasing order of value, so every operation runs in amortized O(1). Wrap the
+///     elements in `core::cmp::Reverse` to get the minimum instead.
+#[derive(Debug, Clone)]
+pub struct MonotonicQueue<T> {
+    elements: VecDeque<T>,
+    // Positions in the queue of the candidates for the maximum, decreasing by value.
+    candidates: VecDeque<usize>,
+    popped: usize,
+}
+
+impl<T: Ord> MonotonicQueue<T> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        MonotonicQueue { elements: VecDeque::new(), candidates: VecDeque::new(), popped: 0 }
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true when the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    fn element(&self, position: usize) -> &T {
+        &self.elements[position - self.popped]
+    }
+
+    /// Adds the element at the back of the queue.
+    pub fn push(&mut self, value: T) {
+        while self.candidates.back().is_some_and(|&last| *self.element(last) <= value) {
+            self.candidates.pop_back();
+        }
+
+        self.candidates.push_back(self.popped + self.elements.len());
+        self.elements.push_back(value);
+    }
+
+    /// Removes the element at the front of the queue.
+    ///
+    /// Returns:
+    ///
+    /// The removed element, none when the queue was empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.elements.pop_front()?;
+        if self.candidates.front() == Some(&self.popped) {
+            self.candidates.pop_front();
+        }
+        self.popped += 1;
+        Some(value)
+    }
+
+    /// Returns the largest element in the queue, the latest one among equal elements.
+    pub fn max(&self) -> Option<&T> {
+        self.candidates.front().map(|&position| self.element(position))
+    }
+}
+
+impl<T: Ord> Default for MonotonicQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Finds the maximum of every window of consecutive values.
+///
+/// Returns:
+///
+/// The maxima of the windows starting at 0, 1 and so on, none when the window is longer than the values.
+///
+/// Panics when the window length is zero.
+pub fn sliding_window_max<T: Ord + Clone>(values: &[T], window: usize) -> Vec<T> {
+    assert!(window > 0, "Window length must be positive");
+
+    let mut queue = MonotonicQueue::new();
+    let mut maxima = Vec::with_capacity((values.len() + 1).saturating_sub(window));
+    for value in values {
+        queue.push(value);
+        if queue.len() > window {
+            queue.pop();
+        }
+
+        if queue.len() == window {
+            maxima.push((*queue.max().unwrap()).clone());
+        }
+    }
+
+    maxima
+}
+
+/// Finds for every value the nearest later value that is greater than it, with a monotonic stack.
+///
+/// Note: runs in O(n), as every index is pushed onto and popped from the stack at most once.
+///
+/// Returns:
+///
+/// For every index, the index of the next greater value, if any.
+pub fn next_greater_element<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    let mut next = vec![None; values.len()];
+
+    // Indices still waiting for a greater value, their values not increasing from bottom to top.
+    let mut stack: Vec<usize> = Vec::new();
+    for (index, value) in values.iter().enumerate() {
+        while let Some(&waiting) = stack.last() {
+            if values[waiting] >= *value {
+                break;
+            }
+
+            next[waiting] = Some(index);
+            stack.pop();
+        }
+        stack.push(index);
+    }
+
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cmp::Reverse;
+    use rand::Rng;
+
+    #[test]
+    fn queue_should_report_maximum_of_remaining_elements() {
+        let mut queue = MonotonicQueue::new();
+        for value in [5, 1, 4, 1, 3] {
+            queue.push(value);
+        }
+
+        assert_eq!(queue.max(), Some(&5));
+        assert_eq!(queue.len(), 5);
+
+        for (expected, front) in [(5, 5), (4, 1), (4, 4), (3, 1), (3, 3)] {
+            assert_eq!(queue.max(), Some(&expected));
+            assert_eq!(queue.pop(), Some(front));
+        }
+        assert_eq!(queue.max(), None);
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn reversed_queue_should_report_minimum() {
+        let mut queue = MonotonicQueue::new();
+        for value in [4, 2, 7, 3] {
+            queue.push(Reverse(value));
+        }
+
+        let minima: Vec<i32> = (0..4)
+            .map(|_| {
+                let minimum = queue.max().unwrap().0;
+                queue.pop().unwrap();
+                minimum
+            })
+            .collect();
+
+        assert_eq!(minima, vec![2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn sliding_window_max_should_match_naive_maxima() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let values: Vec<u8> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(0..10)).collect();
+            let window = rng.gen_range(1..10);
+
+            let expected: Vec<u8> = values.windows(window).map(|w| *w.iter().max().unwrap()).collect();
+
+            assert_eq!(sliding_window_max(&values, window), expected);
+        }
+    }
+
+    #[test]
+    fn next_greater_element_should_match_naive_search() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let values: Vec<u8> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(0..10)).collect();
+
+            let expected: Vec<Option<usize>> =
+                (0..values.len()).map(|i| (i + 1..values.len()).find(|&j| values[j] > values[i])).collect();
+
+            assert_eq!(next_greater_element(&values), expected);
+        }
+    }
+
+    #[test]
+    fn next_greater_element_should_skip_equal_values() {
+        assert_eq!(next_greater_element(&[2, 2, 1, 3]), vec![Some(3), Some(3), Some(3), None]);
+    }
+}
@@ -0,0 +1,396 @@
+//! This module contains a hash map that keeps its entries directly in one array of slots.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, BuildHasherDefault, Hash};
+use core::mem;
+
+use crate::hashing::XxHash64;
+
+/// Way of looking for a free slot when the home slot of a key is taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Probing {
+    /// Tries the following slots in turn and marks removed entries with tombstones.
+    Linear,
+    /// Probes like `Linear`, but an entry farther from its home slot takes the place of one
+    /// closer to its own, which evens out probe lengths and lets lookups stop early.
+    /// Removal shifts the following entries back instead of leaving tombstones.
+    RobinHood,
+}
+
+#[derive(Debug, Clone)]
+enum Slot<K, V> {
+    Empty,
+    Tombstone,
+    Full { hash: u64, key: K, value: V },
+}
+
+const MIN_CAPACITY: usize = 8;
+
+/// Hash map with open addressing.
+///
+/// Note:
+///     The number of slots is a power of two, and the table doubles when entries and tombstones
+///     would fill more than three quarters of it. When mostly tombstones fill it, it is
+///     rebuilt at the same size instead. Operations run in O(1) expected time.
+#[derive(Debug, Clone)]
+pub struct OpenAddressingMap<K, V, S = BuildHasherDefault<XxHash64>> {
+    slots: Vec<Slot<K, V>>,
+    len: usize,
+    tombstones: usize,
+    probing: Probing,
+    hasher: S,
+}
+
+impl<K: Hash + Eq, V> OpenAddressingMap<K, V> {
+    /// Creates an empty map with linear probing.
+    pub fn new() -> Self {
+        Self::with_probing(Probing::Linear)
+    }
+
+    /// Creates an empty map with the given probing.
+    pub fn with_probing(probing: Probing) -> Self {
+        Self::with_probing_and_hasher(probing, BuildHasherDefault::default())
+    }
+}
+
+impl<K: Hash + Eq, V> Default for OpenAddressingMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OpenAddressingMap<K, V, S> {
+    /// Creates an empty map with the given probing, hashing keys with the given hasher.
+    pub fn with_probing_and_hasher(probing: Probing, hasher: S) -> Self {
+        OpenAddressingMap { slots: Vec::new(), len: 0, tombstones: 0, probing, hasher }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of slots.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the probing the map uses.
+    pub fn probing(&self) -> Probing {
+        self.probing
+    }
+
+    fn mask(&self) -> usize {
+        self.slots.len() - 1
+    }
+
+    // Returns how far the slot is from the home slot of the hash.
+    fn displacement(&self, index: usize, hash: u64) -> usize {
+        index.wrapping_sub(hash as usize) & self.mask()
+    }
+
+    fn find<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        if self.slots.is_empty() {
+            return None;
+        }
+
+        let hash = self.hasher.hash_one(key);
+        let mut index = hash as usize & self.mask();
+        for distance in 0..self.slots.len() {
+            match &self.slots[index] {
+                Slot::Empty => return None,
+                Slot::Tombstone => {}
+                Slot::Full { hash: other_hash, key: other, .. } => {
+                    if *other_hash == hash && other.borrow() == key {
+                        return Some(index);
+                    }
+
+                    // The key would have displaced an entry closer to its home slot.
+                    if self.probing == Probing::RobinHood && self.displacement(index, *other_hash) < distance {
+                        return None;
+                    }
+                }
+            }
+
+            index = (index + 1) & self.mask();
+        }
+
+        None
+    }
+
+    // Puts an entry whose key is not in the map into the first slot the probing allows.
+    fn place(&mut self, mut hash: u64, mut key: K, mut value: V) {
+        let mask = self.mask();
+        let mut index = hash as usize & mask;
+        let mut distance = 0;
+        loop {
+            match &mut self.slots[index] {
+                Slot::Empty => break,
+                Slot::Tombstone => {
+                    self.tombstones -= 1;
+                    break;
+                }
+                Slot::Full { hash: other_hash, key: other_key, value: other_value } => {
+                    if self.probing == Probing::RobinHood {
+                        let existing = index.wrapping_sub(*other_hash as usize) & mask;
+                        if existing < distance {
+                            mem::swap(&mut hash, other_hash);
+                            mem::swap(&mut key, other_key);
+                            mem::swap(&mut value, other_value);
+                            distance = existing;
+                        }
+                    }
+                }
+            }
+
+            index = (index + 1) & mask;
+            distance += 1;
+        }
+
+        self.slots[index] = Slot::Full { hash, key, value };
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let slots = mem::replace(&mut self.slots, (0..capacity).map(|_| Slot::Empty).collect());
+        self.tombstones = 0;
+
+        for slot in slots {
+            if let Slot::Full { hash, key, value } = slot {
+                self.place(hash, key, value);
+            }
+        }
+    }
+
+    // Makes room for one more entry.
+    fn reserve_one(&mut self) {
+        let capacity = self.slots.len();
+        if capacity == 0 {
+            self.resize(MIN_CAPACITY);
+        } else if (self.len + self.tombstones + 1) * 4 > capacity * 3 {
+            let grow = (self.len + 1) * 2 > capacity;
+            self.resize(if grow { capacity * 2 } else { capacity });
+        }
+    }
+
+    /// Inserts a value under the key.
+    ///
+    /// Returns:
+    ///
+    /// The value previously stored under the key, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(value_slot) = self.get_mut(&key) {
+            return Some(mem::replace(value_slot, value));
+        }
+
+        self.reserve_one();
+        let hash = self.hasher.hash_one(&key);
+        self.place(hash, key, value);
+        self.len += 1;
+        None
+    }
+
+    /// Returns the value stored under the key.
+    pub fn get<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        match &self.slots[self.find(key)?] {
+            Slot::Full { value, .. } => Some(value),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns a mutable reference to the value stored under the key.
+    pub fn get_mut<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find(key)?;
+        match &mut self.slots[index] {
+            Slot::Full { value, .. } => Some(value),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns true when the map has an entry for the key.
+    pub fn contains_key<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Removes the entry for the key.
+    ///
+    /// Returns:
+    ///
+    /// The value stored under the key, if any.
+    pub fn remove<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let mut index = self.find(key)?;
+        let filler = match self.probing {
+            Probing::Linear => Slot::Tombstone,
+            Probing::RobinHood => Slot::Empty,
+        };
+        let Slot::Full { value, .. } = mem::replace(&mut self.slots[index], filler) else {
+            unreachable!();
+        };
+
+        if self.probing == Probing::Linear {
+            self.tombstones += 1;
+        } else {
+            // Entries after the hole move back one slot until one is at home or the run ends.
+            loop {
+                let next = (index + 1) & self.mask();
+                match &self.slots[next] {
+                    Slot::Full { hash, .. } if self.displacement(next, *hash) > 0 => {
+                        self.slots.swap(index, next);
+                        index = next;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns every entry in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slots.iter().filter_map(|slot| match slot {
+            Slot::Full { key, value, .. } => Some((key, value)),
+            _ => None,
+        })
+    }
+
+    /// Removes every entry, keeping the slots.
+    pub fn clear(&mut self) {
+        for slot in &mut self.slots {
+            *slot = Slot::Empty;
+        }
+        self.len = 0;
+        self.tombstones = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use std::collections::HashMap;
+
+    const PROBINGS: [Probing; 2] = [Probing::Linear, Probing::RobinHood];
+
+    #[test]
+    fn inserted_values_should_be_found() {
+        for probing in PROBINGS {
+            let mut map = OpenAddressingMap::with_probing(probing);
+
+            assert_eq!(map.insert("one".to_string(), 1), None);
+            assert_eq!(map.insert("two".to_string(), 2), None);
+            assert_eq!(map.insert("one".to_string(), 11), Some(1));
+
+            assert_eq!(map.len(), 2);
+            assert_eq!(map.get("one"), Some(&11));
+            assert_eq!(map.get("three"), None);
+            assert!(map.contains_key("two"));
+        }
+    }
+
+    #[test]
+    fn removed_entries_should_be_gone() {
+        for probing in PROBINGS {
+            let mut map = OpenAddressingMap::with_probing(probing);
+            for i in 0..100 {
+                map.insert(i, i * i);
+            }
+
+            for i in (0..100).step_by(2) {
+                assert_eq!(map.remove(&i), Some(i * i));
+            }
+
+            assert_eq!(map.len(), 50);
+            assert_eq!(map.remove(&0), None);
+            assert!((0..100).all(|i| map.get(&i) == (i % 2 == 1).then_some(&(i * i))));
+        }
+    }
+
+    #[test]
+    fn table_should_grow_with_entries() {
+        let mut map = OpenAddressingMap::new();
+
+        for i in 0..1000 {
+            map.insert(i, ());
+        }
+
+        assert!(map.capacity().is_power_of_two());
+        assert!(map.capacity() * 3 >= map.len() * 4);
+        assert!(map.capacity() <= 4 * 1024);
+    }
+
+    #[test]
+    fn tombstones_should_not_grow_the_table() {
+        let mut map = OpenAddressingMap::with_probing(Probing::Linear);
+
+        for i in 0..10000 {
+            map.insert(i, i);
+            map.remove(&i);
+        }
+
+        assert!(map.is_empty());
+        assert_eq!(map.capacity(), MIN_CAPACITY);
+    }
+
+    #[test]
+    fn cleared_map_should_be_empty() {
+        let mut map = OpenAddressingMap::new();
+        map.insert(1, 2);
+
+        map.clear();
+
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.iter().count(), 0);
+    }
+
+    #[test]
+    fn random_operations_should_match_std_hash_map() {
+        let mut rng = rand::thread_rng();
+
+        for probing in PROBINGS {
+            let mut map = OpenAddressingMap::with_probing(probing);
+            let mut expected = HashMap::new();
+
+            for _ in 0..20000 {
+                let key = rng.gen_range(0..500u32);
+                match rng.gen_range(0..4) {
+                    0 | 1 => {
+                        let value = rng.gen::<u16>();
+                        assert_eq!(map.insert(key, value), expected.insert(key, value));
+                    }
+                    2 => assert_eq!(map.remove(&key), expected.remove(&key)),
+                    _ => assert_eq!(map.get(&key), expected.get(&key)),
+                }
+                assert_eq!(map.len(), expected.len());
+            }
+
+            let mut entries: Vec<_> = map.iter().map(|(&key, &value)| (key, value)).collect();
+            let mut expected_entries: Vec<_> = expected.into_iter().collect();
+            entries.sort_unstable();
+            expected_entries.sort_unstable();
+            assert_eq!(entries, expected_entries);
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! This module contains a sorted multiset answering rank queries, built as a treap.
+
+use alloc::boxed::Box;
+use core::cmp::Ordering;
+
+use rand::Rng;
+
+type Tree<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    // Copies of the value in the multiset.
+    count: usize,
+    // Copies of all values in the subtree.
+    size: usize,
+    priority: u64,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+fn size<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.size)
+}
+
+fn update<T>(node: &mut Node<T>) {
+    node.size = size(&node.left) + node.count + size(&node.right);
+}
+
+fn rotate_right<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    update(&mut node);
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+fn rotate_left<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    update(&mut node);
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+// Inserts the value and rotates the new node up while its priority is higher than its parent's.
+fn insert<T: Ord>(tree: Tree<T>, value: T, priority: u64) -> Box<Node<T>> {
+    let Some(mut node) = tree else {
+        return Box::new(Node { value, count: 1, size: 1, priority, left: None, right: None });
+    };
+
+    match value.cmp(&node.value) {
+        Ordering::Equal => {
+            node.count += 1;
+            node.size += 1;
+            node
+        }
+        Ordering::Less => {
+            let left = insert(node.left.take(), value, priority);
+            let rotate = left.priority > node.priority;
+            node.left = Some(left);
+            update(&mut node);
+            if rotate { rotate_right(node) } else { node }
+        }
+        Ordering::Greater => {
+            let right = insert(node.right.take(), value, priority);
+            let rotate = right.priority > node.priority;
+            node.right = Some(right);
+            update(&mut node);
+            if rotate { rotate_left(node) } else { node }
+        }
+    }
+}
+
+// Joins two trees when every value of the first one is smaller than every value of the second one.
+fn merge<T>(first: Tree<T>, second: Tree<T>) -> Tree<T> {
+    match (first, second) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut first), Some(mut second)) => {
+            if first.priority > second.priority {
+                first.right = merge(first.right.take(), Some(second));
+                update(&mut first);
+                Some(first)
+            } else {
+                second.left = merge(Some(first), second.left.take());
+                update(&mut second);
+                Some(second)
+            }
+        }
+    }
+}
+
+fn erase<T: Ord>(tree: &mut Tree<T>, value: &T) -> bool {
+    let Some(node) = tree.as_mut() else {
+        return false;
+    };
+
+    let removed = match value.cmp(&node.value) {
+        Ordering::Less => erase(&mut node.left, value),
+        Ordering::Greater => erase(&mut node.right, value),
+        Ordering::Equal if node.count > 1 => {
+            node.count -= 1;
+            true
+        }
+        Ordering::Equal => {
+            let (left, right) = (node.left.take(), node.right.take());
+            *tree = merge(left, right);
+            return true;
+        }
+    };
+
+    if removed {
+        node.size -= 1;
+    }
+    removed
+}
+
+// Scrambles the bits of a value with the output function of splitmix64, for treap priorities.
+pub(crate) fn splitmix64(value: u64) -> u64 {
+    let mut z = value;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Sorted multiset that finds the k-th smallest value and the rank of a value.
+///
+/// Note:
+///     The values are kept in a treap, a binary search tree whose nodes also form a heap over
+///     random priorities, so the tree stays balanced with high probability. Every node knows
+///     the size of its subtree, which makes all operations run in O(log n) expected time.
+///     Priorities come from a splitmix64 sequence seeded by the constructor: `new` always uses
+///     the same seed, so runs are reproducible, and `with_rng` draws the seed from a generator,
+///     so inputs cannot be tailored to unbalance every instance.
+#[derive(Debug, Clone)]
+pub struct OrderStatisticTree<T> {
+    root: Tree<T>,
+    // State of the splitmix64 sequence of priorities.
+    state: u64,
+}
+
+impl<T: Ord> OrderStatisticTree<T> {
+    /// Creates an empty multiset with the default seed of priorities.
+    pub fn new() -> Self {
+        OrderStatisticTree { root: None, state: 0 }
+    }
+
+    /// Creates an empty multiset whose priorities are seeded from the given generator.
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        OrderStatisticTree { root: None, state: rng.gen() }
+    }
+
+    /// Returns the number of values, counting copies.
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns true when the multiset is empty.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        splitmix64(self.state)
+    }
+
+    /// Adds a copy of the value.
+    pub fn insert(&mut self, value: T) {
+        let priority = self.next_priority();
+        self.root = Some(insert(self.root.take(), value, priority));
+    }
+
+    /// Removes one copy of the value.
+    ///
+    /// Returns:
+    ///
+    /// False when the value was not in the multiset.
+    pub fn erase(&mut self, value: &T) -> bool {
+        erase(&mut self.root, value)
+    }
+
+    /// Returns the number of copies of the value.
+    pub fn count(&self, value: &T) -> usize {
+        let mut tree = &self.root;
+        while let Some(node) = tree {
+            tree = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return node.count,
+            };
+        }
+
+        0
+    }
+
+    /// Returns true when the multiset has a copy of the value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.count(value) > 0
+    }
+
+    /// Returns the value at the given position in sorted order, counting copies and starting from 0.
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> {
+        let mut k = k;
+        let mut tree = &self.root;
+        while let Some(node) = tree {
+            let left = size(&node.left);
+            if k < left {
+                tree = &node.left;
+            } else if k < left + node.count {
+                return Some(&node.value);
+            } else {
+                k -= left + node.count;
+                tree = &node.right;
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of values smaller than the given value, which need not be in the multiset.
+    pub fn rank(&self, value: &T) -> usize {
+        let mut rank = 0;
+        let mut tree = &self.root;
+        while let Some(node) = tree {
+            match value.cmp(&node.value) {
+                Ordering::Less => tree = &node.left,
+                Ordering::Equal => return rank + size(&node.left),
+                Ordering::Greater => {
+                    rank += size(&node.left) + node.count;
+                    tree = &node.right;
+                }
+            }
+        }
+
+        rank
+    }
+}
+
+impl<T: Ord> Default for OrderStatisticTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counts the pairs of positions whose values are out of order, the larger value coming first.
+///
+/// Note: scans the values from the back, adding the rank of every value among the ones after it.
+/// Runs in O(n log n).
+pub fn count_inversions<T: Ord + Clone>(values: &[T]) -> u64 {
+    let mut later = OrderStatisticTree::new();
+    let mut inversions = 0;
+    for value in values.iter().rev() {
+        inversions += later.rank(value) as u64;
+        later.insert(value.clone());
+    }
+
+    inversions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand::Rng;
+
+    #[test]
+    fn values_should_be_found_by_position_and_rank() {
+        let mut tree = OrderStatisticTree::new();
+        for value in [5, 1, 4, 1, 3] {
+            tree.insert(value);
+        }
+
+        let sorted: Vec<i32> = (0..tree.len()).map(|k| *tree.kth_smallest(k).unwrap()).collect();
+
+        assert_eq!(sorted, vec![1, 1, 3, 4, 5]);
+        assert_eq!(tree.kth_smallest(5), None);
+        assert_eq!(tree.rank(&1), 0);
+        assert_eq!(tree.rank(&2), 2);
+        assert_eq!(tree.rank(&6), 5);
+        assert_eq!(tree.count(&1), 2);
+    }
+
+    #[test]
+    fn erased_copies_should_be_gone() {
+        let mut tree = OrderStatisticTree::new();
+        tree.insert(2);
+        tree.insert(2);
+
+        assert!(tree.erase(&2));
+        assert!(tree.contains(&2));
+        assert!(tree.erase(&2));
+        assert!(!tree.erase(&2));
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn random_operations_should_match_sorted_vector() {
+        let mut rng = rand::thread_rng();
+        let mut tree = OrderStatisticTree::new();
+        let mut expected: Vec<u16> = Vec::new();
+
+        for _ in 0..5000 {
+            let value = rng.gen_range(0..300);
+            if rng.gen_bool(0.6) {
+                tree.insert(value);
+                let position = expected.partition_point(|&other| other < value);
+                expected.insert(position, value);
+            } else {
+                let position = expected.binary_search(&value);
+                assert_eq!(tree.erase(&value), position.is_ok());
+                if let Ok(position) = position {
+                    expected.remove(position);
+                }
+            }
+
+            assert_eq!(tree.len(), expected.len());
+            assert_eq!(tree.rank(&value), expected.partition_point(|&other| other < value));
+            let k = rng.gen_range(0..=expected.len());
+            assert_eq!(tree.kth_smallest(k), expected.get(k));
+        }
+    }
+
+    #[test]
+    fn sorted_insertions_should_keep_the_tree_shallow() {
+        fn depth<T>(tree: &Tree<T>) -> usize {
+            tree.as_ref().map_or(0, |node| 1 + depth(&node.left).max(depth(&node.right)))
+        }
+        let mut tree = OrderStatisticTree::new();
+
+        for value in 0..100_000 {
+            tree.insert(value);
+        }
+
+        assert!(depth(&tree.root) < 60);
+        assert_eq!(tree.kth_smallest(12345), Some(&12345));
+    }
+
+    #[test]
+    fn seeded_generator_should_give_reproducible_priorities() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        fn priorities(tree: &OrderStatisticTree<i32>) -> Vec<u64> {
+            let mut tree = tree.clone();
+            (0..5).map(|_| tree.next_priority()).collect()
+        }
+
+        let first = OrderStatisticTree::with_rng(&mut StdRng::seed_from_u64(7));
+        let second = OrderStatisticTree::with_rng(&mut StdRng::seed_from_u64(7));
+        let other = OrderStatisticTree::with_rng(&mut StdRng::seed_from_u64(8));
+
+        assert_eq!(priorities(&first), priorities(&second));
+        assert_ne!(priorities(&first), priorities(&other));
+        assert_ne!(priorities(&first), priorities(&OrderStatisticTree::new()));
+    }
+
+    #[test]
+    fn inversions_should_match_naive_count() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let values: Vec<u8> = (0..rng.gen_range(0..40)).map(|_| rng.gen_range(0..10)).collect();
+
+            let expected = (0..values.len())
+                .flat_map(|i| (i + 1..values.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| values[i] > values[j])
+                .count() as u64;
+
+            assert_eq!(count_inversions(&values), expected);
+        }
+        assert_eq!(count_inversions(&[3, 2, 1]), 3);
+    }
+}